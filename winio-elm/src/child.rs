@@ -11,7 +11,7 @@ use winio_handle::{
     AsContainer, AsWidget, AsWindow, BorrowedContainer, BorrowedWidget, BorrowedWindow,
 };
 #[cfg(feature = "primitive")]
-use winio_primitive::{Failable, Layoutable, Point, Rect, Size};
+use winio_primitive::{Failable, Layoutable, Point, Rect, Size, next_layout_stamp};
 
 use super::ComponentMessage;
 use crate::{BoxComponent, Component, ComponentSender, Root};
@@ -97,6 +97,8 @@ pub struct Child<T: Component> {
     model: T,
     sender: ComponentSender<T>,
     msg_cache: SmallVec<[T::Message; 1]>,
+    #[cfg(feature = "primitive")]
+    stamp: u64,
 }
 
 impl<T: Component> Child<T> {
@@ -112,6 +114,17 @@ impl<T: Component> Child<T> {
             model,
             sender,
             msg_cache: SmallVec::new(),
+            #[cfg(feature = "primitive")]
+            stamp: next_layout_stamp(),
+        }
+    }
+
+    /// The child component may be changed, so the size hints should be
+    /// queried again by the layout containers.
+    fn touch(&mut self) {
+        #[cfg(feature = "primitive")]
+        {
+            self.stamp = next_layout_stamp();
         }
     }
 
@@ -185,6 +198,7 @@ impl<T: Component> Child<T> {
 
     /// Emit message to the child component.
     pub async fn emit(&mut self, message: T::Message) -> Result<bool, T::Error> {
+        self.touch();
         self.model.update(message, &self.sender).await
     }
 
@@ -194,11 +208,15 @@ impl<T: Component> Child<T> {
         for message in self.msg_cache.drain(..) {
             need_render |= self.model.update(message, &self.sender).await?;
         }
+        if need_render {
+            self.touch();
+        }
         Ok(need_render)
     }
 
     /// Render the child component.
     pub fn render(&mut self) -> Result<(), T::Error> {
+        self.touch();
         self.model.render(&self.sender)?;
         self.model.render_children()
     }
@@ -228,6 +246,8 @@ impl<T: Component + 'static> Child<T> {
             model: BoxComponent::new(self.model),
             sender,
             msg_cache: self.msg_cache,
+            #[cfg(feature = "primitive")]
+            stamp: next_layout_stamp(),
         }
     }
 }
@@ -242,6 +262,7 @@ impl<T: Component> Deref for Child<T> {
 
 impl<T: Component> DerefMut for Child<T> {
     fn deref_mut(&mut self) -> &mut Self::Target {
+        self.touch();
         &mut self.model
     }
 }
@@ -296,4 +317,14 @@ impl<T: Component + Layoutable> Layoutable for Child<T> {
     fn preferred_size(&self) -> Result<Size, Self::Error>;
 
     fn min_size(&self) -> Result<Size, Self::Error>;
+
+    fn is_layout_visible(&self) -> Result<bool, Self::Error>;
+
+    fn baseline(&self) -> Result<Option<f64>, Self::Error>;
+
+    // The stamp of the model is ignored, because the model could only be
+    // changed through the child.
+    fn layout_stamp(&self) -> Option<u64> {
+        Some(self.stamp)
+    }
 }
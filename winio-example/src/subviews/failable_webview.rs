@@ -158,6 +158,8 @@ impl Layoutable for FailableWebView {
             FailableWebView::ErrLabel(lbl) => lbl.set_size(v),
         }
     }
+
+    impl_layout_visible!();
 }
 
 impl Component for FailableWebView {
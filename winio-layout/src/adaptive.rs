@@ -0,0 +1,109 @@
+use std::cell::Cell;
+
+use winio_callback::Callback;
+
+use crate::{Grid, LayoutError, Rect, Size};
+
+/// A persistent helper which switches the [`Grid`] templates by the
+/// available size.
+///
+/// The breakpoints are indexed from 1 in the order they are added, and the
+/// breakpoint 0 is the default one for the sizes smaller than all the
+/// breakpoints. The selected breakpoint is the last one whose minimum size is
+/// satisfied, so the breakpoints are usually added from small to large.
+///
+/// ```ignore
+/// # use winio::prelude::*;
+/// # struct MainModel {
+/// #     window: Child<Window>,
+/// #     list: Child<ListBox>,
+/// #     canvas: Child<Canvas>,
+/// #     adaptive: AdaptiveLayout,
+/// # }
+/// # impl MainModel { fn foo(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+/// // In `init`:
+/// // let adaptive = AdaptiveLayout::new().breakpoint(600.0, 0.0);
+/// let csize = self.window.client_size()?;
+/// self.adaptive.set_size(csize, |breakpoint| match breakpoint {
+///     0 => layout! {
+///         Grid::from_str("1*", "1*,2*").unwrap(),
+///         self.list   => { column: 0, row: 0 },
+///         self.canvas => { column: 0, row: 1 },
+///     },
+///     _ => layout! {
+///         Grid::from_str("1*,2*", "1*").unwrap(),
+///         self.list   => { column: 0, row: 0 },
+///         self.canvas => { column: 1, row: 0 },
+///     },
+/// })?;
+/// # } }
+/// ```
+#[derive(Debug, Default)]
+pub struct AdaptiveLayout {
+    breakpoints: Vec<Size>,
+    current: Cell<Option<usize>>,
+    change: Callback<usize>,
+}
+
+impl AdaptiveLayout {
+    /// Create [`AdaptiveLayout`] with only the default breakpoint.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a breakpoint with the minimum width and height.
+    pub fn breakpoint(mut self, min_width: f64, min_height: f64) -> Self {
+        self.breakpoints.push(Size::new(min_width, min_height));
+        self
+    }
+
+    /// The breakpoint selected by the last layout. It is [`None`] if no size
+    /// has been selected yet.
+    pub fn current(&self) -> Option<usize> {
+        self.current.get()
+    }
+
+    /// Select the breakpoint for the size. The change event is signaled if
+    /// the breakpoint differs from the previous one, including the first
+    /// selection.
+    pub fn select(&self, size: Size) -> usize {
+        let breakpoint = self
+            .breakpoints
+            .iter()
+            .rposition(|min| size.width >= min.width && size.height >= min.height)
+            .map_or(0, |i| i + 1);
+        if self.current.replace(Some(breakpoint)) != Some(breakpoint) {
+            self.change.signal::<()>(breakpoint);
+        }
+        breakpoint
+    }
+
+    /// Wait for the selected breakpoint to change. It is usually awaited in
+    /// `Component::start` to swap the visibility or the content of the
+    /// widgets.
+    pub async fn wait_change(&self) -> usize {
+        self.change.wait().await
+    }
+
+    /// Select the breakpoint for the rectangle, and lay out the grid created
+    /// for it.
+    pub fn set_rect<'a, E: 'a>(
+        &self,
+        r: Rect,
+        f: impl FnOnce(usize) -> Grid<'a, E>,
+    ) -> Result<(), LayoutError<E>> {
+        let mut grid = f(self.select(r.size));
+        grid.set_rect(r)
+    }
+
+    /// Select the breakpoint for the size, and lay out the grid created for
+    /// it at the origin.
+    pub fn set_size<'a, E: 'a>(
+        &self,
+        s: Size,
+        f: impl FnOnce(usize) -> Grid<'a, E>,
+    ) -> Result<(), LayoutError<E>> {
+        let mut grid = f(self.select(s));
+        grid.set_size(s)
+    }
+}
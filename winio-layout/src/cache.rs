@@ -0,0 +1,225 @@
+use std::{cell::RefCell, collections::HashMap};
+
+use taffy::{AvailableSpace, Layout, NodeId, Style, TaffyError, TaffyTree};
+use winio_primitive::Size;
+
+/// A persistent layout state which can be stored in a component.
+///
+/// The layout containers are created on every render, and build a new layout
+/// tree each time by default. Pass a [`LayoutCache`] to the container with its
+/// `cache` method to reuse the tree between renders. Only the nodes whose
+/// styles changed are updated, and the layout is recomputed only when the
+/// size hints of the children or the available size change.
+///
+/// The size hints of the child widgets are native calls on most backends, so
+/// they are stored in the cache, keyed by the
+/// [`layout_stamp`](winio_primitive::Layoutable::layout_stamp) of the widget.
+/// The stamp of a `Child` changes whenever it is mutably borrowed, updated or
+/// rendered, e.g. after setting the text of a label, so that the hints are
+/// queried again on the next layout. Call [`LayoutCache::invalidate`] when the
+/// hints change otherwise, e.g. when the DPI changes. The hints of the nested
+/// containers are always computed from their own children.
+///
+/// Each container should have its own cache. If a cache is being used by
+/// another container at the same time, a temporary tree is built instead.
+///
+/// ```ignore
+/// # use winio::prelude::*;
+/// # struct MainModel {
+/// #     window: Child<Window>,
+/// #     canvas: Child<Canvas>,
+/// #     layout: LayoutCache,
+/// # }
+/// # impl MainModel { fn foo(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+/// let csize = self.window.client_size()?;
+/// {
+///     let mut grid = layout! {
+///         Grid::from_str("1*,2*,1*", "1*,2*,1*").unwrap().cache(&self.layout),
+///         self.canvas => { column: 1, row: 1 },
+///     };
+///     grid.set_size(csize)?;
+/// }
+/// # } }
+/// ```
+#[derive(Debug, Default)]
+pub struct LayoutCache {
+    state: RefCell<Option<LayoutState>>,
+    hints: RefCell<HashMap<u64, SizeHints>>,
+}
+
+impl LayoutCache {
+    /// Create an empty [`LayoutCache`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Drop the cached tree and size hints. They will be rebuilt on the next
+    /// layout.
+    pub fn clear(&self) {
+        if let Ok(mut state) = self.state.try_borrow_mut() {
+            state.take();
+        }
+        self.invalidate();
+    }
+
+    /// Drop the cached size hints of the children. They will be queried again
+    /// on the next layout, while the tree is still reused.
+    pub fn invalidate(&self) {
+        if let Ok(mut hints) = self.hints.try_borrow_mut() {
+            hints.clear();
+        }
+    }
+}
+
+/// The size hints of a child widget, without the margin. [`None`] means not
+/// queried yet.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub(crate) struct SizeHints {
+    pub preferred_size: Option<Size>,
+    pub min_size: Option<Size>,
+    pub baseline: Option<Option<f64>>,
+}
+
+/// A child of a container whose size hints could be stored in the cache.
+pub(crate) trait CachedChild {
+    /// The layout stamp of the child widget, or [`None`] if the hints should
+    /// not be cached.
+    fn hints_key(&self) -> Option<u64>;
+
+    fn hints(&self) -> SizeHints;
+
+    fn set_hints(&self, hints: SizeHints);
+}
+
+/// Fill the size hints of the children from the cache. The hints already
+/// queried are kept.
+pub(crate) fn load_hints(cache: Option<&LayoutCache>, children: &[impl CachedChild]) {
+    let Some(hints) = cache.and_then(|cache| cache.hints.try_borrow().ok()) else {
+        return;
+    };
+    for child in children {
+        if let Some(cached) = child.hints_key().and_then(|key| hints.get(&key)) {
+            let current = child.hints();
+            child.set_hints(SizeHints {
+                preferred_size: current.preferred_size.or(cached.preferred_size),
+                min_size: current.min_size.or(cached.min_size),
+                baseline: current.baseline.or(cached.baseline),
+            });
+        }
+    }
+}
+
+/// Store the size hints of the children into the cache. The hints of the
+/// widgets not in the container anymore are dropped.
+pub(crate) fn store_hints(cache: Option<&LayoutCache>, children: &[impl CachedChild]) {
+    let Some(mut hints) = cache.and_then(|cache| cache.hints.try_borrow_mut().ok()) else {
+        return;
+    };
+    hints.clear();
+    hints.extend(
+        children
+            .iter()
+            .filter_map(|child| Some((child.hints_key()?, child.hints()))),
+    );
+}
+
+/// The computed layouts kept for a few recent available sizes, e.g. the
+/// max-content and min-content sizes of the size hints, and the actual size.
+const RESULTS_CAPACITY: usize = 4;
+
+type LayoutResult = (Layout, Vec<Layout>);
+
+#[derive(Debug)]
+struct LayoutState {
+    tree: TaffyTree,
+    root: NodeId,
+    nodes: Vec<NodeId>,
+    results: Vec<(taffy::Size<AvailableSpace>, LayoutResult)>,
+}
+
+impl LayoutState {
+    fn new(root: Style, children: Vec<Style>) -> Result<Self, TaffyError> {
+        let mut tree = TaffyTree::new();
+        let nodes = children
+            .into_iter()
+            .map(|style| tree.new_leaf(style))
+            .collect::<Result<Vec<_>, _>>()?;
+        let root = tree.new_with_children(root, &nodes)?;
+        Ok(Self {
+            tree,
+            root,
+            nodes,
+            results: vec![],
+        })
+    }
+
+    fn update(&mut self, root: Style, children: Vec<Style>) -> Result<(), TaffyError> {
+        if self.nodes.len() != children.len() {
+            *self = Self::new(root, children)?;
+            return Ok(());
+        }
+        // Setting the style marks the node dirty, so only set the changed ones
+        // to keep the cached layout of the others.
+        let mut changed = false;
+        for (node, style) in self.nodes.iter().zip(children) {
+            if self.tree.style(*node)? != &style {
+                self.tree.set_style(*node, style)?;
+                changed = true;
+            }
+        }
+        if self.tree.style(self.root)? != &root {
+            self.tree.set_style(self.root, root)?;
+            changed = true;
+        }
+        // The styles are built from the size hints, so the results are still
+        // valid if no style changed.
+        if changed {
+            self.results.clear();
+        }
+        Ok(())
+    }
+
+    fn compute(
+        &mut self,
+        available: taffy::Size<AvailableSpace>,
+    ) -> Result<LayoutResult, TaffyError> {
+        if let Some((_, result)) = self.results.iter().find(|(a, _)| *a == available) {
+            return Ok(result.clone());
+        }
+        self.tree.compute_layout(self.root, available)?;
+        let layouts = self
+            .nodes
+            .iter()
+            .map(|node| self.tree.layout(*node).copied())
+            .collect::<Result<Vec<_>, _>>()?;
+        let result = (*self.tree.layout(self.root)?, layouts);
+        if self.results.len() >= RESULTS_CAPACITY {
+            self.results.remove(0);
+        }
+        self.results.push((available, result.clone()));
+        Ok(result)
+    }
+}
+
+/// Compute the layout of the root and the children, with the cache if
+/// possible.
+pub(crate) fn compute_layout(
+    cache: Option<&LayoutCache>,
+    root: Style,
+    children: Vec<Style>,
+    available: taffy::Size<AvailableSpace>,
+) -> Result<LayoutResult, TaffyError> {
+    match cache.and_then(|cache| cache.state.try_borrow_mut().ok()) {
+        Some(mut state) => {
+            let state = match state.take() {
+                Some(mut s) => {
+                    s.update(root, children)?;
+                    state.insert(s)
+                }
+                None => state.insert(LayoutState::new(root, children)?),
+            };
+            state.compute(available)
+        }
+        None => LayoutState::new(root, children)?.compute(available),
+    }
+}
@@ -0,0 +1,232 @@
+use winio_primitive::{Failable, LayoutDirection, Point, Rect, Size};
+
+use crate::{LayoutChild, LayoutError, align_in_slot, default_direction, flip, layout_child};
+
+/// The side of a [`DockPanel`] that a child is docked to.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Dock {
+    /// Docked to the left side.
+    Left,
+    /// Docked to the top side.
+    Top,
+    /// Docked to the right side.
+    Right,
+    /// Docked to the bottom side.
+    Bottom,
+    /// Fill the remaining space.
+    Fill,
+}
+
+layout_child! {
+    /// Builder of a child for [`DockPanel`].
+    struct DockPanelChild {
+        /// The side to dock.
+        dock: Dock = Dock::Left,
+    }
+}
+
+/// A docked layout container.
+///
+/// The children are docked to the sides of the remaining space, in the order
+/// they are pushed. A child with [`Dock::Fill`] takes all the remaining space,
+/// so it is usually the last one. The left and right sides are swapped in the
+/// right-to-left direction.
+pub struct DockPanel<'a, E> {
+    children: Vec<DockPanelChild<'a, E>>,
+    collapse_when_hidden: bool,
+    direction: Option<LayoutDirection>,
+    loc: Point,
+    size: Size,
+}
+
+impl<'a, E> DockPanel<'a, E> {
+    /// Create [`DockPanel`].
+    pub fn new() -> Self {
+        Self {
+            children: vec![],
+            collapse_when_hidden: false,
+            direction: None,
+            loc: Point::zero(),
+            size: Size::zero(),
+        }
+    }
+
+    /// Layout direction of the panel. The default direction is used if not
+    /// specified, see [`set_default_direction`].
+    pub fn direction(mut self, v: LayoutDirection) -> Self {
+        self.direction = Some(v);
+        self
+    }
+
+    /// Collapse the hidden children, unless specified by the child.
+    pub fn collapse_when_hidden(mut self, v: bool) -> Self {
+        self.collapse_when_hidden = v;
+        self
+    }
+
+    /// Push a child into the panel.
+    pub fn push<'b>(
+        &'b mut self,
+        widget: &'a mut dyn LayoutChild<Error = E>,
+    ) -> DockPanelChildBuilder<'a, 'b, E> {
+        DockPanelChildBuilder {
+            child: DockPanelChild::new(widget),
+            children: &mut self.children,
+        }
+    }
+
+    fn render(&mut self) -> Result<(), LayoutError<E>> {
+        let area = Rect::new(self.loc, self.size);
+        let direction = self.direction.unwrap_or_else(default_direction);
+        let mut remain = area;
+        for child in &mut self.children {
+            if child.collapsed(self.collapse_when_hidden)? {
+                continue;
+            }
+            let preferred_size = child.child_preferred_size()?;
+            let width = child
+                .width
+                .unwrap_or(preferred_size.width)
+                .min(remain.width())
+                .max(0.0);
+            let height = child
+                .height
+                .unwrap_or(preferred_size.height)
+                .min(remain.height())
+                .max(0.0);
+            let slot = match child.dock {
+                Dock::Left => {
+                    let slot = Rect::new(remain.origin, Size::new(width, remain.height()));
+                    remain.origin.x += width;
+                    remain.size.width -= width;
+                    slot
+                }
+                Dock::Top => {
+                    let slot = Rect::new(remain.origin, Size::new(remain.width(), height));
+                    remain.origin.y += height;
+                    remain.size.height -= height;
+                    slot
+                }
+                Dock::Right => {
+                    remain.size.width -= width;
+                    Rect::new(
+                        Point::new(remain.max_x(), remain.origin.y),
+                        Size::new(width, remain.height()),
+                    )
+                }
+                Dock::Bottom => {
+                    remain.size.height -= height;
+                    Rect::new(
+                        Point::new(remain.origin.x, remain.max_y()),
+                        Size::new(remain.width(), height),
+                    )
+                }
+                Dock::Fill => {
+                    let slot = remain;
+                    remain.origin = remain.max();
+                    remain.size = Size::zero();
+                    slot
+                }
+            };
+            let rect = align_in_slot(
+                slot,
+                preferred_size,
+                child.width,
+                child.height,
+                child.halign,
+                child.valign,
+            );
+            child.set_child_rect(flip(rect, area, direction))?;
+        }
+        Ok(())
+    }
+
+    fn measure(
+        &self,
+        f: impl Fn(&DockPanelChild<'a, E>) -> Result<Size, LayoutError<E>>,
+    ) -> Result<Size, LayoutError<E>> {
+        let mut acc = Size::zero();
+        let mut parent = Size::zero();
+        for child in &self.children {
+            if child.collapsed(self.collapse_when_hidden)? {
+                continue;
+            }
+            let mut size = f(child)?;
+            if let Some(w) = child.width {
+                size.width = w;
+            }
+            if let Some(h) = child.height {
+                size.height = h;
+            }
+            match child.dock {
+                Dock::Left | Dock::Right => {
+                    parent.height = parent.height.max(acc.height + size.height);
+                    acc.width += size.width;
+                }
+                Dock::Top | Dock::Bottom => {
+                    parent.width = parent.width.max(acc.width + size.width);
+                    acc.height += size.height;
+                }
+                Dock::Fill => {
+                    parent.width = parent.width.max(acc.width + size.width);
+                    parent.height = parent.height.max(acc.height + size.height);
+                }
+            }
+        }
+        Ok(Size::new(
+            parent.width.max(acc.width),
+            parent.height.max(acc.height),
+        ))
+    }
+
+    /// Move the location.
+    pub fn set_loc(&mut self, p: Point) -> Result<(), LayoutError<E>> {
+        LayoutChild::set_child_loc(self, p)
+    }
+
+    /// Resize.
+    pub fn set_size(&mut self, s: Size) -> Result<(), LayoutError<E>> {
+        LayoutChild::set_child_size(self, s)
+    }
+
+    /// Set the location and size.
+    pub fn set_rect(&mut self, r: Rect) -> Result<(), LayoutError<E>> {
+        LayoutChild::set_child_rect(self, r)
+    }
+}
+
+impl<E> Default for DockPanel<'_, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<E> Failable for DockPanel<'_, E> {
+    type Error = E;
+}
+
+impl<E> LayoutChild for DockPanel<'_, E> {
+    fn set_child_loc(&mut self, p: Point) -> Result<(), LayoutError<Self::Error>> {
+        self.loc = p;
+        self.render()
+    }
+
+    fn set_child_size(&mut self, s: Size) -> Result<(), LayoutError<Self::Error>> {
+        self.size = s;
+        self.render()
+    }
+
+    fn set_child_rect(&mut self, r: Rect) -> Result<(), LayoutError<Self::Error>> {
+        self.loc = r.origin;
+        self.size = r.size;
+        self.render()
+    }
+
+    fn child_preferred_size(&self) -> Result<Size, LayoutError<Self::Error>> {
+        self.measure(|child| child.child_preferred_size())
+    }
+
+    fn child_min_size(&self) -> Result<Size, LayoutError<Self::Error>> {
+        self.measure(|child| child.child_min_size())
+    }
+}
@@ -0,0 +1,599 @@
+use std::{collections::HashMap, path::Path, str::FromStr};
+
+use thiserror::Error;
+use winio_primitive::{HAlign, Margin, VAlign};
+
+use crate::{Grid, GridLength, LayoutChild, ParseGridLengthError};
+
+/// Errors can be returned when loading or applying a [`LayoutFile`].
+#[derive(Debug, Error)]
+#[non_exhaustive]
+pub enum LayoutFileError {
+    /// IO error when reading the file.
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+    /// Syntax error.
+    #[error("syntax error at line {0}: {1}")]
+    Syntax(usize, String),
+    /// Unknown node.
+    #[error("unknown node at line {0}: {1}")]
+    UnknownNode(usize, String),
+    /// Unknown property.
+    #[error("unknown property at line {0}: {1}")]
+    UnknownProperty(usize, String),
+    /// Invalid value of a property.
+    #[error("invalid value of {1} at line {0}")]
+    InvalidValue(usize, String),
+    /// Invalid grid length.
+    #[error("invalid grid length at line {0}: {1}")]
+    GridLength(usize, ParseGridLengthError),
+    /// The child is placed out of the grid.
+    #[error("child out of the grid at line {0}: {1}")]
+    OutOfRange(usize, String),
+    /// The name is defined more than once.
+    #[error("duplicate name at line {0}: {1}")]
+    Duplicate(usize, String),
+    /// The grid is not defined in the file.
+    #[error("unknown grid: {0}")]
+    UnknownGrid(String),
+    /// The child is not defined in the grid.
+    #[error("unknown child: {0}")]
+    UnknownChild(String),
+}
+
+/// A layout file loaded at runtime.
+///
+/// The file is written in a subset of [KDL](https://kdl.dev). Each top-level
+/// `grid` node defines a named [`GridTemplate`], and its children are the
+/// named widgets:
+///
+/// ```kdl
+/// // The login form.
+/// grid "login" columns="auto,1*" rows="auto,auto" gap=4 padding=8 {
+///     ulabel column=0 row=0 valign=center
+///     uentry column=1 row=0
+///     plabel column=0 row=1 valign=center
+///     pentry column=1 row=1 margin="4,0,4,0"
+/// }
+/// ```
+///
+/// The properties of `grid` are `columns`, `rows` (see [`Grid::from_str`]),
+/// `column_gap`, `row_gap`, `gap`, `padding` and `collapse_when_hidden`. The
+/// properties of a child are `column`, `row`, `column_span`, `row_span`,
+/// `width`, `height`, `margin`, `halign`, `valign` and
+/// `collapse_when_hidden`. A margin or padding is either a number for all
+/// sides, or a string of four numbers as top, right, bottom and left.
+///
+/// ## Syntax
+/// The supported subset of KDL is:
+/// * nodes separated by newlines or `;`, with arguments, `key=value` properties
+///   and a `{ ... }` children block;
+/// * quoted strings with the escapes `\n`, `\t`, `\"`, `\\` and `\/`, and bare
+///   identifiers as strings;
+/// * decimal numbers, optionally with `_` separators;
+/// * `true`, `false`, `#true` and `#false`;
+/// * `//` line comments.
+///
+/// Block comments (`/* */`), slashdash comments (`/-`), raw and multi-line
+/// strings, type annotations, line continuations, `#null` and the other
+/// keywords are not supported, and are reported as
+/// [`LayoutFileError::Syntax`].
+#[derive(Debug, Clone, Default)]
+pub struct LayoutFile {
+    grids: HashMap<String, GridTemplate>,
+}
+
+impl LayoutFile {
+    /// Load the layout file from the path.
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, LayoutFileError> {
+        std::fs::read_to_string(path)?.parse()
+    }
+
+    /// Get the grid template by name.
+    pub fn grid(&self, name: &str) -> Result<&GridTemplate, LayoutFileError> {
+        self.grids
+            .get(name)
+            .ok_or_else(|| LayoutFileError::UnknownGrid(name.to_string()))
+    }
+
+    /// Names of the grid templates.
+    pub fn grids(&self) -> impl Iterator<Item = &str> {
+        self.grids.keys().map(|s| s.as_str())
+    }
+}
+
+impl FromStr for LayoutFile {
+    type Err = LayoutFileError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let nodes = Parser::new(s).parse_nodes(false)?;
+        let mut grids = HashMap::new();
+        for node in nodes {
+            if node.name != "grid" {
+                return Err(LayoutFileError::UnknownNode(node.line, node.name));
+            }
+            let name = match node.args.as_slice() {
+                [Value::Str(name)] => name.clone(),
+                _ => return Err(LayoutFileError::InvalidValue(node.line, node.name)),
+            };
+            if grids.contains_key(&name) {
+                return Err(LayoutFileError::Duplicate(node.line, name));
+            }
+            grids.insert(name, GridTemplate::from_node(node)?);
+        }
+        Ok(Self { grids })
+    }
+}
+
+/// A [`Grid`] defined in a [`LayoutFile`].
+///
+/// Use [`layout_file!`](crate::layout_file) to create the grid with named
+/// widgets.
+#[derive(Debug, Clone)]
+pub struct GridTemplate {
+    columns: Vec<GridLength>,
+    rows: Vec<GridLength>,
+    column_gap: f64,
+    row_gap: f64,
+    padding: Margin,
+    collapse_when_hidden: bool,
+    children: HashMap<String, GridChildTemplate>,
+}
+
+#[derive(Debug, Clone)]
+struct GridChildTemplate {
+    column: Option<usize>,
+    row: Option<usize>,
+    column_span: usize,
+    row_span: usize,
+    width: Option<f64>,
+    height: Option<f64>,
+    margin: Margin,
+    halign: HAlign,
+    valign: VAlign,
+    collapse_when_hidden: Option<bool>,
+}
+
+impl GridTemplate {
+    fn from_node(node: Node) -> Result<Self, LayoutFileError> {
+        let mut template = Self {
+            columns: vec![],
+            rows: vec![],
+            column_gap: 0.0,
+            row_gap: 0.0,
+            padding: Margin::zero(),
+            collapse_when_hidden: false,
+            children: HashMap::new(),
+        };
+        for (key, value) in &node.props {
+            let line = node.line;
+            match key.as_str() {
+                "columns" => {
+                    template.columns = Grid::<()>::parse_grid_lengths(value.as_str(line, key)?)
+                        .map_err(|e| LayoutFileError::GridLength(line, e))?
+                }
+                "rows" => {
+                    template.rows = Grid::<()>::parse_grid_lengths(value.as_str(line, key)?)
+                        .map_err(|e| LayoutFileError::GridLength(line, e))?
+                }
+                "column_gap" => template.column_gap = value.as_f64(line, key)?,
+                "row_gap" => template.row_gap = value.as_f64(line, key)?,
+                "gap" => {
+                    template.column_gap = value.as_f64(line, key)?;
+                    template.row_gap = template.column_gap;
+                }
+                "padding" => template.padding = value.as_margin(line, key)?,
+                "collapse_when_hidden" => {
+                    template.collapse_when_hidden = value.as_bool(line, key)?
+                }
+                _ => return Err(LayoutFileError::UnknownProperty(line, key.clone())),
+            }
+        }
+        for child in node.children {
+            if !child.args.is_empty() || !child.children.is_empty() {
+                return Err(LayoutFileError::Syntax(
+                    child.line,
+                    format!("unexpected values of {}", child.name),
+                ));
+            }
+            if template.children.contains_key(&child.name) {
+                return Err(LayoutFileError::Duplicate(child.line, child.name));
+            }
+            let line = child.line;
+            let t = GridChildTemplate::from_node(&child)?;
+            let out_of_range = |start: Option<usize>, span: usize, tracks: usize| {
+                tracks > 0 && start.is_some_and(|start| start + span > tracks)
+            };
+            if out_of_range(t.column, t.column_span, template.columns.len())
+                || out_of_range(t.row, t.row_span, template.rows.len())
+            {
+                return Err(LayoutFileError::OutOfRange(line, child.name));
+            }
+            template.children.insert(child.name, t);
+        }
+        Ok(template)
+    }
+
+    /// The columns of the grid.
+    pub fn columns(&self) -> &[GridLength] {
+        &self.columns
+    }
+
+    /// The rows of the grid.
+    pub fn rows(&self) -> &[GridLength] {
+        &self.rows
+    }
+
+    /// Names of the children.
+    pub fn children(&self) -> impl Iterator<Item = &str> {
+        self.children.keys().map(|s| s.as_str())
+    }
+
+    /// Create an empty [`Grid`] with the properties of the template.
+    pub fn create<'a, E>(&self) -> Grid<'a, E> {
+        Grid::new(self.columns.clone(), self.rows.clone())
+            .column_gap(self.column_gap)
+            .row_gap(self.row_gap)
+            .padding(self.padding)
+            .collapse_when_hidden(self.collapse_when_hidden)
+    }
+
+    /// Push a named child into the grid.
+    pub fn push<'a, E>(
+        &self,
+        grid: &mut Grid<'a, E>,
+        name: &str,
+        widget: &'a mut dyn LayoutChild<Error = E>,
+    ) -> Result<(), LayoutFileError> {
+        let t = self
+            .children
+            .get(name)
+            .ok_or_else(|| LayoutFileError::UnknownChild(name.to_string()))?;
+        let mut builder = grid
+            .push(widget)
+            .column_span(t.column_span)
+            .row_span(t.row_span)
+            .margin(t.margin)
+            .halign(t.halign)
+            .valign(t.valign);
+        if let Some(v) = t.column {
+            builder = builder.column(v);
+        }
+        if let Some(v) = t.row {
+            builder = builder.row(v);
+        }
+        if let Some(v) = t.width {
+            builder = builder.width(v);
+        }
+        if let Some(v) = t.height {
+            builder = builder.height(v);
+        }
+        if let Some(v) = t.collapse_when_hidden {
+            builder = builder.collapse_when_hidden(v);
+        }
+        builder.finish();
+        Ok(())
+    }
+}
+
+impl GridChildTemplate {
+    fn from_node(node: &Node) -> Result<Self, LayoutFileError> {
+        let mut t = Self {
+            column: None,
+            row: None,
+            column_span: 1,
+            row_span: 1,
+            width: None,
+            height: None,
+            margin: Margin::zero(),
+            halign: HAlign::Stretch,
+            valign: VAlign::Stretch,
+            collapse_when_hidden: None,
+        };
+        let line = node.line;
+        for (key, value) in &node.props {
+            match key.as_str() {
+                "column" => t.column = Some(value.as_usize(line, key)?),
+                "row" => t.row = Some(value.as_usize(line, key)?),
+                "column_span" => t.column_span = value.as_usize(line, key)?,
+                "row_span" => t.row_span = value.as_usize(line, key)?,
+                "width" => t.width = Some(value.as_f64(line, key)?),
+                "height" => t.height = Some(value.as_f64(line, key)?),
+                "margin" => t.margin = value.as_margin(line, key)?,
+                "halign" => {
+                    t.halign = match value.as_str(line, key)?.to_ascii_lowercase().as_str() {
+                        "left" => HAlign::Left,
+                        "center" => HAlign::Center,
+                        "right" => HAlign::Right,
+                        "stretch" => HAlign::Stretch,
+                        _ => return Err(LayoutFileError::InvalidValue(line, key.clone())),
+                    }
+                }
+                "valign" => {
+                    t.valign = match value.as_str(line, key)?.to_ascii_lowercase().as_str() {
+                        "top" => VAlign::Top,
+                        "center" => VAlign::Center,
+                        "bottom" => VAlign::Bottom,
+                        "stretch" => VAlign::Stretch,
+                        "baseline" => VAlign::Baseline,
+                        _ => return Err(LayoutFileError::InvalidValue(line, key.clone())),
+                    }
+                }
+                "collapse_when_hidden" => t.collapse_when_hidden = Some(value.as_bool(line, key)?),
+                _ => return Err(LayoutFileError::UnknownProperty(line, key.clone())),
+            }
+        }
+        Ok(t)
+    }
+}
+
+/// Helper macro to create a [`Grid`] from a [`GridTemplate`].
+///
+/// It is similar to [`layout!`](crate::layout), but the layout hints come
+/// from the template, and each widget is bound to a child name in the file.
+/// It returns `Result<Grid, LayoutFileError>`. The children in the template
+/// without a bound widget are ignored.
+///
+/// ```ignore
+/// # use winio::prelude::*;
+/// # struct MainModel {
+/// #     window: Child<Window>,
+/// #     layout: LayoutFile,
+/// #     ulabel: Child<Label>,
+/// #     uentry: Child<Edit>,
+/// # }
+/// # impl MainModel { fn foo(&mut self) -> Result<(), Box<dyn std::error::Error>> {
+/// let csize = self.window.client_size()?;
+/// {
+///     let mut grid = layout_file! {
+///         self.layout.grid("login")?,
+///         ulabel => self.ulabel,
+///         uentry => self.uentry,
+///     }?;
+///     grid.set_size(csize)?;
+/// }
+/// # } }
+/// ```
+#[macro_export]
+macro_rules! layout_file {
+    ($template:expr, $($name:ident => $e:expr),+$(,)?) => {{
+        let template: &$crate::GridTemplate = $template;
+        let mut root = template.create();
+        let mut result: Result<(), $crate::LayoutFileError> = Ok(());
+        $(
+            if result.is_ok() {
+                result = template.push(&mut root, stringify!($name), &mut $e);
+            }
+        )+
+        result.map(|_| root)
+    }};
+}
+
+#[derive(Debug, Clone, PartialEq)]
+enum Value {
+    Str(String),
+    Number(f64),
+    Bool(bool),
+}
+
+impl Value {
+    fn as_str(&self, line: usize, key: &str) -> Result<&str, LayoutFileError> {
+        match self {
+            Self::Str(s) => Ok(s),
+            _ => Err(LayoutFileError::InvalidValue(line, key.to_string())),
+        }
+    }
+
+    fn as_f64(&self, line: usize, key: &str) -> Result<f64, LayoutFileError> {
+        match self {
+            Self::Number(v) => Ok(*v),
+            _ => Err(LayoutFileError::InvalidValue(line, key.to_string())),
+        }
+    }
+
+    fn as_usize(&self, line: usize, key: &str) -> Result<usize, LayoutFileError> {
+        match self {
+            Self::Number(v) if *v >= 0.0 && v.fract() == 0.0 => Ok(*v as usize),
+            _ => Err(LayoutFileError::InvalidValue(line, key.to_string())),
+        }
+    }
+
+    fn as_bool(&self, line: usize, key: &str) -> Result<bool, LayoutFileError> {
+        match self {
+            Self::Bool(v) => Ok(*v),
+            _ => Err(LayoutFileError::InvalidValue(line, key.to_string())),
+        }
+    }
+
+    fn as_margin(&self, line: usize, key: &str) -> Result<Margin, LayoutFileError> {
+        let invalid = || LayoutFileError::InvalidValue(line, key.to_string());
+        match self {
+            Self::Number(v) => Ok(Margin::new_all_same(*v)),
+            Self::Str(s) => {
+                let values = s
+                    .split(',')
+                    .map(|s| s.trim().parse::<f64>())
+                    .collect::<Result<Vec<_>, _>>()
+                    .map_err(|_| invalid())?;
+                match values.as_slice() {
+                    [v] => Ok(Margin::new_all_same(*v)),
+                    [top, right, bottom, left] => Ok(Margin::new(*top, *right, *bottom, *left)),
+                    _ => Err(invalid()),
+                }
+            }
+            _ => Err(invalid()),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Node {
+    line: usize,
+    name: String,
+    args: Vec<Value>,
+    props: Vec<(String, Value)>,
+    children: Vec<Node>,
+}
+
+/// Parser of a KDL subset: nodes with arguments, properties and children,
+/// quoted or bare strings, numbers, booleans and line comments.
+struct Parser<'a> {
+    chars: std::iter::Peekable<std::str::Chars<'a>>,
+    line: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(s: &'a str) -> Self {
+        Self {
+            chars: s.chars().peekable(),
+            line: 1,
+        }
+    }
+
+    fn error(&self, msg: impl Into<String>) -> LayoutFileError {
+        LayoutFileError::Syntax(self.line, msg.into())
+    }
+
+    fn next(&mut self) -> Option<char> {
+        let c = self.chars.next();
+        if c == Some('\n') {
+            self.line += 1;
+        }
+        c
+    }
+
+    /// Skip the spaces and comments in a line.
+    fn skip_inline(&mut self) {
+        while let Some(&c) = self.chars.peek() {
+            if c == '/' {
+                let mut ahead = self.chars.clone();
+                ahead.next();
+                if ahead.peek() != Some(&'/') {
+                    break;
+                }
+                while self.chars.peek().is_some_and(|c| *c != '\n') {
+                    self.next();
+                }
+            } else if c != '\n' && c.is_whitespace() {
+                self.next();
+            } else {
+                break;
+            }
+        }
+    }
+
+    fn parse_nodes(&mut self, nested: bool) -> Result<Vec<Node>, LayoutFileError> {
+        let mut nodes = vec![];
+        loop {
+            self.skip_inline();
+            match self.chars.peek() {
+                None if nested => return Err(self.error("unclosed children block")),
+                None => return Ok(nodes),
+                Some('\n' | ';') => {
+                    self.next();
+                }
+                Some('}') if nested => {
+                    self.next();
+                    return Ok(nodes);
+                }
+                Some('}') => return Err(self.error("unexpected `}`")),
+                Some(_) => nodes.push(self.parse_node()?),
+            }
+        }
+    }
+
+    fn parse_node(&mut self) -> Result<Node, LayoutFileError> {
+        let line = self.line;
+        let name = match self.parse_value()? {
+            Value::Str(name) => name,
+            _ => return Err(self.error("invalid node name")),
+        };
+        let mut node = Node {
+            line,
+            name,
+            args: vec![],
+            props: vec![],
+            children: vec![],
+        };
+        loop {
+            self.skip_inline();
+            match self.chars.peek() {
+                None | Some('\n' | ';' | '}') => return Ok(node),
+                Some('{') => {
+                    self.next();
+                    node.children = self.parse_nodes(true)?;
+                    return Ok(node);
+                }
+                Some(_) => {
+                    let value = self.parse_value()?;
+                    if self.chars.peek() == Some(&'=') {
+                        self.next();
+                        let key = match value {
+                            Value::Str(key) => key,
+                            _ => return Err(self.error("invalid property name")),
+                        };
+                        let value = self.parse_value()?;
+                        node.props.push((key, value));
+                    } else {
+                        node.args.push(value);
+                    }
+                }
+            }
+        }
+    }
+
+    fn parse_value(&mut self) -> Result<Value, LayoutFileError> {
+        match self.chars.peek() {
+            Some('"') => {
+                self.next();
+                let mut s = String::new();
+                loop {
+                    match self.next() {
+                        None => return Err(self.error("unclosed string")),
+                        // Multi-line strings start with `"""`.
+                        Some('"') if self.chars.peek() == Some(&'"') => {
+                            return Err(self.error("unsupported multi-line string"));
+                        }
+                        Some('"') => return Ok(Value::Str(s)),
+                        Some('\\') => match self.next() {
+                            Some('n') => s.push('\n'),
+                            Some('t') => s.push('\t'),
+                            Some(c @ ('"' | '\\' | '/')) => s.push(c),
+                            _ => return Err(self.error("invalid escape")),
+                        },
+                        Some(c) => s.push(c),
+                    }
+                }
+            }
+            Some(_) => {
+                let mut s = String::new();
+                while let Some(&c) = self.chars.peek() {
+                    if c.is_whitespace() || "=;{}\"()[]/\\".contains(c) {
+                        break;
+                    }
+                    s.push(c);
+                    self.next();
+                }
+                if s.is_empty() {
+                    return Err(self.error("unexpected character"));
+                }
+                // Raw strings like `r#"..."#` or `#"..."#`.
+                if self.chars.peek() == Some(&'"') {
+                    return Err(self.error(format!("unsupported string {s}\"")));
+                }
+                match s.as_str() {
+                    "#true" | "true" => Ok(Value::Bool(true)),
+                    "#false" | "false" => Ok(Value::Bool(false)),
+                    _ if s.starts_with('#') => Err(self.error(format!("unsupported keyword {s}"))),
+                    _ if s.starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '+') => s
+                        .replace('_', "")
+                        .parse()
+                        .map(Value::Number)
+                        .map_err(|_| self.error(format!("invalid number {s}"))),
+                    _ => Ok(Value::Str(s)),
+                }
+            }
+            None => Err(self.error("unexpected end of file")),
+        }
+    }
+}
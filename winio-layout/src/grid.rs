@@ -1,13 +1,17 @@
-use std::{fmt::Display, num::ParseFloatError, str::FromStr};
+use std::{collections::HashMap, fmt::Display, num::ParseFloatError, str::FromStr};
 
 use taffy::{
-    GridTemplateComponent, NodeId, Style, TaffyTree, TrackSizingFunction,
-    prelude::{auto, fr, length, line, span},
+    GridTemplateComponent, MaxTrackSizingFunction, MinTrackSizingFunction, Style,
+    TrackSizingFunction,
+    prelude::{auto, fit_content, fr, length, line, max_content, min_content, minmax, span},
 };
-use winio_primitive::Failable;
+use winio_primitive::{Failable, LayoutDirection};
 
-use super::{layout_child, rect_t2e, render};
-use crate::{HAlign, LayoutChild, LayoutError, Point, Rect, Size, VAlign};
+use super::{
+    child_baseline, compute_layout, default_direction, layout_child, load_hints, padding_e2t,
+    rect_t2e, render, store_hints,
+};
+use crate::{HAlign, LayoutCache, LayoutChild, LayoutError, Margin, Point, Rect, Size, VAlign};
 
 /// Error can be returned when parsing [`GridLength`].
 #[derive(Debug)]
@@ -15,18 +19,115 @@ use crate::{HAlign, LayoutChild, LayoutError, Point, Rect, Size, VAlign};
 pub enum ParseGridLengthError {
     /// Invalid length value.
     InvalidLength(ParseFloatError),
+    /// Invalid syntax of a track function.
+    InvalidSyntax(String),
 }
 
 impl Display for ParseGridLengthError {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
             Self::InvalidLength(e) => write!(f, "invalid length value: {e}"),
+            Self::InvalidSyntax(s) => write!(f, "invalid grid length syntax: {s}"),
         }
     }
 }
 
 impl std::error::Error for ParseGridLengthError {}
 
+/// Error can be returned when parsing grid template areas.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum ParseGridAreasError {
+    /// Invalid grid length of columns or rows.
+    InvalidLength(ParseGridLengthError),
+    /// The areas are not a list of quoted strings.
+    InvalidSyntax(String),
+    /// The rows don't have the same number of columns.
+    InconsistentColumns,
+    /// The named area is not a rectangle.
+    NonRectangular(String),
+}
+
+impl Display for ParseGridAreasError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidLength(e) => write!(f, "{e}"),
+            Self::InvalidSyntax(s) => write!(f, "invalid grid areas syntax: {s}"),
+            Self::InconsistentColumns => write!(f, "inconsistent column count of grid areas"),
+            Self::NonRectangular(name) => write!(f, "grid area is not rectangular: {name}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseGridAreasError {}
+
+impl From<ParseGridLengthError> for ParseGridAreasError {
+    fn from(value: ParseGridLengthError) -> Self {
+        Self::InvalidLength(value)
+    }
+}
+
+/// A bound of a [`GridLength::MinMax`] track.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GridBound {
+    /// The bound is determined automatically.
+    Auto,
+    /// The min-content size of the children.
+    MinContent,
+    /// The max-content size of the children.
+    MaxContent,
+    /// Represents a relative ratio. Only valid as a maximum bound, and treated
+    /// as [`GridBound::Auto`] as a minimum bound.
+    Stretch(f64),
+    /// Fixed length.
+    Length(f64),
+}
+
+impl FromStr for GridBound {
+    type Err = ParseGridLengthError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("auto") {
+            Ok(Self::Auto)
+        } else if s.eq_ignore_ascii_case("min-content") {
+            Ok(Self::MinContent)
+        } else if s.eq_ignore_ascii_case("max-content") {
+            Ok(Self::MaxContent)
+        } else if let Some(s) = s.strip_suffix('*') {
+            s.parse::<f64>()
+                .map(Self::Stretch)
+                .map_err(ParseGridLengthError::InvalidLength)
+        } else {
+            s.parse::<f64>()
+                .map(Self::Length)
+                .map_err(ParseGridLengthError::InvalidLength)
+        }
+    }
+}
+
+impl From<GridBound> for MinTrackSizingFunction {
+    fn from(value: GridBound) -> Self {
+        match value {
+            GridBound::Auto | GridBound::Stretch(_) => auto(),
+            GridBound::MinContent => min_content(),
+            GridBound::MaxContent => max_content(),
+            GridBound::Length(v) => length(v as f32),
+        }
+    }
+}
+
+impl From<GridBound> for MaxTrackSizingFunction {
+    fn from(value: GridBound) -> Self {
+        match value {
+            GridBound::Auto => auto(),
+            GridBound::MinContent => min_content(),
+            GridBound::MaxContent => max_content(),
+            GridBound::Stretch(v) => fr(v as f32),
+            GridBound::Length(v) => length(v as f32),
+        }
+    }
+}
+
 /// The width or height of a grid cell.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub enum GridLength {
@@ -36,6 +137,10 @@ pub enum GridLength {
     Stretch(f64),
     /// Fixed length.
     Length(f64),
+    /// The length is between a minimum and a maximum bound.
+    MinMax(GridBound, GridBound),
+    /// The length is determined automatically, but no larger than the value.
+    FitContent(f64),
 }
 
 impl FromStr for GridLength {
@@ -44,6 +149,20 @@ impl FromStr for GridLength {
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         if s.eq_ignore_ascii_case("auto") {
             Ok(Self::Auto)
+        } else if let Some(args) = strip_function(s, "minmax") {
+            let (min, max) = args
+                .split_once(',')
+                .ok_or_else(|| ParseGridLengthError::InvalidSyntax(s.to_string()))?;
+            let min = min.trim().parse::<GridBound>()?;
+            if let GridBound::Stretch(_) = min {
+                return Err(ParseGridLengthError::InvalidSyntax(s.to_string()));
+            }
+            Ok(Self::MinMax(min, max.trim().parse()?))
+        } else if let Some(arg) = strip_function(s, "fit-content") {
+            arg.trim()
+                .parse::<f64>()
+                .map(Self::FitContent)
+                .map_err(ParseGridLengthError::InvalidLength)
         } else if let Some(s) = s.strip_suffix('*') {
             s.parse::<f64>()
                 .map(Self::Stretch)
@@ -56,12 +175,26 @@ impl FromStr for GridLength {
     }
 }
 
+/// Get the arguments of a function like `name(args)`.
+fn strip_function<'a>(s: &'a str, name: &str) -> Option<&'a str> {
+    let prefix = s.get(..name.len())?;
+    if !prefix.eq_ignore_ascii_case(name) {
+        return None;
+    }
+    s[name.len()..]
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+}
+
 impl From<GridLength> for TrackSizingFunction {
     fn from(value: GridLength) -> Self {
         match value {
             GridLength::Auto => auto(),
             GridLength::Length(v) => length(v as f32),
             GridLength::Stretch(v) => fr(v as f32),
+            GridLength::MinMax(min, max) => minmax(min.into(), max.into()),
+            GridLength::FitContent(v) => fit_content(length(v as f32)),
         }
     }
 }
@@ -72,18 +205,51 @@ impl From<&GridLength> for TrackSizingFunction {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct GridArea {
+    column: usize,
+    row: usize,
+    column_span: usize,
+    row_span: usize,
+}
+
+/// The column, row and their spans. The position is [`None`] if auto-placed.
+type Placement = (Option<usize>, Option<usize>, usize, usize);
+
 layout_child! {
     /// Builder of a child for [`Grid`].
     struct GridChild {
-        /// The column index in the grid.
-        column: usize = 0,
-        /// The row index in the grid.
-        row: usize = 0,
         /// The column span in the grid.
         column_span: usize = 1,
         /// The row span in the grid.
         row_span: usize = 1,
     }
+    private {
+        column: Option<usize> = None,
+        row: Option<usize> = None,
+        area: Option<String> = None,
+    }
+}
+
+impl<E> GridChildBuilder<'_, '_, E> {
+    /// The column index in the grid.
+    pub fn column(mut self, v: usize) -> Self {
+        self.child.column = Some(v);
+        self
+    }
+
+    /// The row index in the grid.
+    pub fn row(mut self, v: usize) -> Self {
+        self.child.row = Some(v);
+        self
+    }
+
+    /// The named area in the grid, defined by [`Grid::from_areas`]. It
+    /// overrides the column, row and spans of the child.
+    pub fn area(mut self, name: impl Into<String>) -> Self {
+        self.child.area = Some(name.into());
+        self
+    }
 }
 
 /// A grid layout container.
@@ -91,6 +257,13 @@ pub struct Grid<'a, E> {
     children: Vec<GridChild<'a, E>>,
     columns: Vec<GridLength>,
     rows: Vec<GridLength>,
+    areas: HashMap<String, GridArea>,
+    column_gap: f64,
+    row_gap: f64,
+    padding: Margin,
+    collapse_when_hidden: bool,
+    direction: Option<LayoutDirection>,
+    cache: Option<&'a LayoutCache>,
     loc: Point,
     size: Size,
 }
@@ -102,6 +275,13 @@ impl<'a, E> Grid<'a, E> {
             children: vec![],
             columns,
             rows,
+            areas: HashMap::new(),
+            column_gap: 0.0,
+            row_gap: 0.0,
+            padding: Margin::zero(),
+            collapse_when_hidden: false,
+            direction: None,
+            cache: None,
             loc: Point::zero(),
             size: Size::zero(),
         }
@@ -116,7 +296,14 @@ impl<'a, E> Grid<'a, E> {
     /// * `<number>*`: a stretch track, sized proportionally to the remaining
     ///   space. For example, `1*,2*,1*` divides the available space into three
     ///   tracks of ratios 1:2:1;
-    /// * `<number>`: a fixed length track.
+    /// * `<number>`: a fixed length track;
+    /// * `minmax(<min>, <max>)`: a track between the bounds, where each bound
+    ///   can be `auto`, `min-content`, `max-content`, or a length. The maximum
+    ///   bound could also be a stretch `<number>*`;
+    /// * `fit-content(<number>)`: an auto track, but no larger than the length.
+    ///
+    /// An empty string means no explicit tracks, and the children are placed
+    /// into implicit `auto` tracks.
     pub fn from_str(
         columns: impl AsRef<str>,
         rows: impl AsRef<str>,
@@ -127,15 +314,147 @@ impl<'a, E> Grid<'a, E> {
         ))
     }
 
-    fn parse_grid_lengths(s: &str) -> Result<Vec<GridLength>, ParseGridLengthError> {
+    /// Create [`Grid`] with named template areas.
+    ///
+    /// The `areas` is a list of quoted strings, each of which is a row of
+    /// whitespace-separated area names. A `.` represents an empty cell. Each
+    /// named area must be a rectangle. For example:
+    ///
+    /// ```text
+    /// "header header"
+    /// "nav    main"
+    /// "footer footer"
+    /// ```
+    ///
+    /// The `columns` and `rows` are the same as [`Grid::from_str`]. Children
+    /// are placed into the areas with the `area` property.
+    pub fn from_areas(
+        areas: impl AsRef<str>,
+        columns: impl AsRef<str>,
+        rows: impl AsRef<str>,
+    ) -> Result<Self, ParseGridAreasError> {
+        let mut grid = Self::from_str(columns, rows)?;
+        grid.areas = Self::parse_grid_areas(areas.as_ref())?;
+        Ok(grid)
+    }
+
+    /// Spacing between the columns.
+    pub fn column_gap(mut self, v: f64) -> Self {
+        self.column_gap = v;
+        self
+    }
+
+    /// Spacing between the rows.
+    pub fn row_gap(mut self, v: f64) -> Self {
+        self.row_gap = v;
+        self
+    }
+
+    /// Spacing between both the columns and the rows.
+    pub fn gap(self, v: f64) -> Self {
+        self.column_gap(v).row_gap(v)
+    }
+
+    /// Inner padding of the grid.
+    pub fn padding(mut self, m: Margin) -> Self {
+        self.padding = m;
+        self
+    }
+
+    pub(crate) fn parse_grid_lengths(s: &str) -> Result<Vec<GridLength>, ParseGridLengthError> {
         let mut lengths = vec![];
-        for s in s.split(',') {
-            let s = s.trim();
-            lengths.push(s.parse()?);
+        if s.trim().is_empty() {
+            return Ok(lengths);
         }
+        // Split by the commas outside the parentheses.
+        let mut depth = 0usize;
+        let mut start = 0;
+        for (i, c) in s.char_indices() {
+            match c {
+                '(' => depth += 1,
+                ')' => {
+                    depth = depth
+                        .checked_sub(1)
+                        .ok_or_else(|| ParseGridLengthError::InvalidSyntax(s.to_string()))?
+                }
+                ',' if depth == 0 => {
+                    lengths.push(s[start..i].trim().parse()?);
+                    start = i + 1;
+                }
+                _ => {}
+            }
+        }
+        lengths.push(s[start..].trim().parse()?);
         Ok(lengths)
     }
 
+    fn parse_grid_areas(s: &str) -> Result<HashMap<String, GridArea>, ParseGridAreasError> {
+        let mut rows = vec![];
+        let mut rest = s.trim_start();
+        while !rest.is_empty() {
+            let row = rest
+                .strip_prefix('"')
+                .and_then(|r| r.split_once('"'))
+                .ok_or_else(|| ParseGridAreasError::InvalidSyntax(s.to_string()))?;
+            rows.push(row.0.split_whitespace().collect::<Vec<_>>());
+            rest = row.1.trim_start();
+        }
+        let columns = rows.first().map(|r| r.len()).unwrap_or_default();
+        if rows.iter().any(|r| r.len() != columns) {
+            return Err(ParseGridAreasError::InconsistentColumns);
+        }
+        // The bounds and the cell count of each area.
+        let mut bounds = HashMap::<&str, (usize, usize, usize, usize, usize)>::new();
+        for (row, names) in rows.iter().enumerate() {
+            for (column, name) in names.iter().enumerate() {
+                if name.chars().all(|c| c == '.') {
+                    continue;
+                }
+                let (left, _, right, bottom, cells) =
+                    bounds.entry(name).or_insert((column, row, column, row, 0));
+                *left = (*left).min(column);
+                *right = (*right).max(column);
+                *bottom = row;
+                *cells += 1;
+            }
+        }
+        bounds
+            .into_iter()
+            .map(|(name, (left, top, right, bottom, cells))| {
+                let area = GridArea {
+                    column: left,
+                    row: top,
+                    column_span: right - left + 1,
+                    row_span: bottom - top + 1,
+                };
+                if area.column_span * area.row_span == cells {
+                    Ok((name.to_string(), area))
+                } else {
+                    Err(ParseGridAreasError::NonRectangular(name.to_string()))
+                }
+            })
+            .collect()
+    }
+
+    /// Layout direction of the panel. The default direction is used if not
+    /// specified, see [`set_default_direction`].
+    pub fn direction(mut self, v: LayoutDirection) -> Self {
+        self.direction = Some(v);
+        self
+    }
+
+    /// Collapse the hidden children, unless specified by the child.
+    pub fn collapse_when_hidden(mut self, v: bool) -> Self {
+        self.collapse_when_hidden = v;
+        self
+    }
+
+    /// Reuse the layout tree in the cache between renders.
+    pub fn cache(mut self, cache: &'a LayoutCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     /// Push a child into the panel.
     pub fn push<'b>(
         &'b mut self,
@@ -147,10 +466,59 @@ impl<'a, E> Grid<'a, E> {
         }
     }
 
-    fn tree(&self) -> Result<(TaffyTree, NodeId, Vec<NodeId>), LayoutError<E>> {
-        let mut tree: TaffyTree<()> = TaffyTree::new();
-        let mut nodes = vec![];
+    fn placement(&self, child: &GridChild<'a, E>) -> Result<Placement, LayoutError<E>> {
+        match &child.area {
+            Some(name) => {
+                let area = self
+                    .areas
+                    .get(name)
+                    .ok_or_else(|| LayoutError::UnknownArea(name.clone()))?;
+                Ok((
+                    Some(area.column),
+                    Some(area.row),
+                    area.column_span,
+                    area.row_span,
+                ))
+            }
+            // A child without any position is auto-placed.
+            None => match (child.column, child.row) {
+                (None, None) => Ok((None, None, child.column_span, child.row_span)),
+                (column, row) => Ok((
+                    Some(column.unwrap_or_default()),
+                    Some(row.unwrap_or_default()),
+                    child.column_span,
+                    child.row_span,
+                )),
+            },
+        }
+    }
+
+    fn styles(&self) -> Result<(Style, Vec<Style>), LayoutError<E>> {
+        load_hints(self.cache, &self.children);
+        // The baseline-aligned children are aligned to the lowest baseline in
+        // the row they start from. Auto-placed children are aligned to top.
+        let mut baselines = HashMap::<usize, f64>::new();
         for child in &self.children {
+            if child.valign != VAlign::Baseline || child.collapsed(self.collapse_when_hidden)? {
+                continue;
+            }
+            if let (_, Some(row), ..) = self.placement(child)? {
+                let b = child_baseline(child)?;
+                baselines
+                    .entry(row)
+                    .and_modify(|max| *max = max.max(b))
+                    .or_insert(b);
+            }
+        }
+        let mut styles = vec![];
+        for child in &self.children {
+            if child.collapsed(self.collapse_when_hidden)? {
+                styles.push(Style {
+                    display: taffy::Display::None,
+                    ..Default::default()
+                });
+                continue;
+            }
             let preferred_size = child.child_preferred_size()?;
             let mut style = Style::default();
             style.size.width = match child.width {
@@ -172,12 +540,22 @@ impl<'a, E> Grid<'a, E> {
                 width: length(min_size.width as f32),
                 height: length(min_size.height as f32),
             };
-            if matches!(child.valign, VAlign::Top | VAlign::Center) {
+            let (column, row, cspan, rspan) = self.placement(child)?;
+
+            if matches!(
+                child.valign,
+                VAlign::Top | VAlign::Center | VAlign::Baseline
+            ) {
                 style.margin.bottom = auto();
             }
             if matches!(child.valign, VAlign::Bottom | VAlign::Center) {
                 style.margin.top = auto();
             }
+            if child.valign == VAlign::Baseline
+                && let Some(baseline) = row.and_then(|row| baselines.get(&row))
+            {
+                style.margin.top = length((baseline - child_baseline(child)?) as f32);
+            }
             if matches!(child.halign, HAlign::Left | HAlign::Center) {
                 style.margin.right = auto();
             }
@@ -185,48 +563,72 @@ impl<'a, E> Grid<'a, E> {
                 style.margin.left = auto();
             }
 
-            style.grid_column.start = line(child.column as i16 + 1);
-            style.grid_row.start = line(child.row as i16 + 1);
-
-            let cspan = child.column_span;
-            if cspan > 1 {
-                style.grid_column.end = span(cspan as u16);
+            match column {
+                Some(column) => {
+                    style.grid_column.start = line(column as i16 + 1);
+                    if cspan > 1 {
+                        style.grid_column.end = span(cspan as u16);
+                    }
+                }
+                None => {
+                    if cspan > 1 {
+                        style.grid_column.start = span(cspan as u16);
+                    }
+                }
             }
 
-            let rspan = child.row_span;
-            if rspan > 1 {
-                style.grid_row.end = span(rspan as u16);
+            match row {
+                Some(row) => {
+                    style.grid_row.start = line(row as i16 + 1);
+                    if rspan > 1 {
+                        style.grid_row.end = span(rspan as u16);
+                    }
+                }
+                None => {
+                    if rspan > 1 {
+                        style.grid_row.start = span(rspan as u16);
+                    }
+                }
             }
 
-            let node = tree.new_leaf(style)?;
-            nodes.push(node);
+            styles.push(style);
         }
-        let root = tree.new_with_children(
-            Style {
-                display: taffy::Display::Grid,
-                size: taffy::Size::from_percent(1.0, 1.0),
-                grid_template_columns: self
-                    .columns
-                    .iter()
-                    .map(TrackSizingFunction::from)
-                    .map(GridTemplateComponent::Single)
-                    .collect(),
-                grid_template_rows: self
-                    .rows
-                    .iter()
-                    .map(TrackSizingFunction::from)
-                    .map(GridTemplateComponent::Single)
-                    .collect(),
-                ..Default::default()
+        let root = Style {
+            display: taffy::Display::Grid,
+            size: taffy::Size::from_percent(1.0, 1.0),
+            gap: taffy::Size {
+                width: length(self.column_gap as f32),
+                height: length(self.row_gap as f32),
             },
-            &nodes,
-        )?;
-        Ok((tree, root, nodes))
+            padding: padding_e2t(self.padding),
+            grid_template_columns: self
+                .columns
+                .iter()
+                .map(TrackSizingFunction::from)
+                .map(GridTemplateComponent::Single)
+                .collect(),
+            grid_template_rows: self
+                .rows
+                .iter()
+                .map(TrackSizingFunction::from)
+                .map(GridTemplateComponent::Single)
+                .collect(),
+            ..Default::default()
+        };
+        store_hints(self.cache, &self.children);
+        Ok((root, styles))
     }
 
     fn render(&mut self) -> Result<(), LayoutError<E>> {
-        let (tree, root, nodes) = self.tree()?;
-        render(tree, root, nodes, self.loc, self.size, &mut self.children)
+        let (root, styles) = self.styles()?;
+        render(
+            self.cache,
+            root,
+            styles,
+            Rect::new(self.loc, self.size),
+            self.direction.unwrap_or_else(default_direction),
+            &mut self.children,
+        )
     }
 
     /// Move the location.
@@ -267,14 +669,14 @@ impl<E> LayoutChild for Grid<'_, E> {
     }
 
     fn child_preferred_size(&self) -> Result<Size, LayoutError<Self::Error>> {
-        let (mut tree, root, _) = self.tree()?;
-        tree.compute_layout(root, taffy::Size::max_content())?;
-        Ok(rect_t2e(tree.layout(root)?).size)
+        let (root, styles) = self.styles()?;
+        let (layout, _) = compute_layout(self.cache, root, styles, taffy::Size::max_content())?;
+        Ok(rect_t2e(&layout).size)
     }
 
     fn child_min_size(&self) -> Result<Size, LayoutError<Self::Error>> {
-        let (mut tree, root, _) = self.tree()?;
-        tree.compute_layout(root, taffy::Size::min_content())?;
-        Ok(rect_t2e(tree.layout(root)?).size)
+        let (root, styles) = self.styles()?;
+        let (layout, _) = compute_layout(self.cache, root, styles, taffy::Size::min_content())?;
+        Ok(rect_t2e(&layout).size)
     }
 }
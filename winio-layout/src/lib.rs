@@ -3,14 +3,16 @@
 #![cfg_attr(docsrs, feature(doc_cfg))]
 #![warn(missing_docs)]
 
+use std::sync::OnceLock;
+
 #[doc(hidden)]
 pub use paste::paste as __paste;
+use taffy::Style;
 pub use taffy::TaffyError;
-use taffy::{Layout, NodeId, TaffyTree};
 use thiserror::Error;
 #[doc(hidden)]
 pub use winio_primitive::{Failable, HAlign, Layoutable, VAlign};
-use winio_primitive::{Margin, Point, Rect, Size};
+use winio_primitive::{LayoutDirection, Margin, Point, Rect, Size};
 
 fn approx_eq(f1: f64, f2: f64) -> bool {
     (f1 - f2).abs() < 1.0
@@ -40,8 +42,18 @@ pub trait LayoutChild: Failable {
 
     fn set_child_rect(&mut self, r: Rect) -> Result<(), LayoutError<Self::Error>>;
 
-    fn layout(&mut self, layout: &Layout, loc: Point) -> Result<(), LayoutError<Self::Error>> {
-        self.set_child_rect(offset(rect_t2e(layout), loc))
+    fn child_visible(&self) -> Result<bool, LayoutError<Self::Error>> {
+        Ok(true)
+    }
+
+    fn child_baseline(&self) -> Result<Option<f64>, LayoutError<Self::Error>> {
+        Ok(None)
+    }
+
+    // The size hints of the widgets are native calls and could be stored in
+    // the cache, while the containers compute them from their own children.
+    fn child_layout_stamp(&self) -> Option<u64> {
+        None
     }
 }
 
@@ -77,14 +89,41 @@ impl<T: Layoutable> LayoutChild for T {
         }
         Ok(())
     }
+
+    fn child_visible(&self) -> Result<bool, LayoutError<Self::Error>> {
+        self.is_layout_visible().map_err(LayoutError::Child)
+    }
+
+    fn child_baseline(&self) -> Result<Option<f64>, LayoutError<Self::Error>> {
+        self.baseline().map_err(LayoutError::Child)
+    }
+
+    fn child_layout_stamp(&self) -> Option<u64> {
+        self.layout_stamp()
+    }
 }
 
+mod cache;
+pub use cache::*;
+
 mod grid;
 pub use grid::*;
 
 mod stack_panel;
 pub use stack_panel::*;
 
+mod wrap_panel;
+pub use wrap_panel::*;
+
+mod dock_panel;
+pub use dock_panel::*;
+
+mod file;
+pub use file::*;
+
+mod adaptive;
+pub use adaptive::*;
+
 #[cfg(test)]
 mod test;
 
@@ -95,11 +134,97 @@ fn rect_t2e(rect: &taffy::Layout) -> Rect {
     )
 }
 
+fn available_size(size: Size) -> taffy::Size<taffy::AvailableSpace> {
+    taffy::Size {
+        width: taffy::AvailableSpace::Definite(size.width as _),
+        height: taffy::AvailableSpace::Definite(size.height as _),
+    }
+}
+
+fn padding_e2t(padding: Margin) -> taffy::Rect<taffy::LengthPercentage> {
+    taffy::Rect {
+        left: taffy::prelude::length(padding.left as f32),
+        right: taffy::prelude::length(padding.right as f32),
+        top: taffy::prelude::length(padding.top as f32),
+        bottom: taffy::prelude::length(padding.bottom as f32),
+    }
+}
+
 fn offset(mut a: Rect, offset: Point) -> Rect {
     a.origin += offset.to_vector();
     a
 }
 
+/// Mirror the rectangle horizontally inside the area for the right-to-left
+/// direction.
+fn flip(mut rect: Rect, area: Rect, direction: LayoutDirection) -> Rect {
+    if direction == LayoutDirection::RightToLeft {
+        rect.origin.x = area.min_x() + area.max_x() - rect.max_x();
+    }
+    rect
+}
+
+static DEFAULT_DIRECTION: OnceLock<LayoutDirection> = OnceLock::new();
+
+/// Set the default layout direction of the containers, which is used when the
+/// direction of a container is not specified. It is usually set by the app on
+/// startup.
+///
+/// The default direction can only be set once in the process. It returns the
+/// direction back as an error if it has already been set. Use the `direction`
+/// method of the containers to lay out with another direction, e.g. in tests.
+pub fn set_default_direction(direction: LayoutDirection) -> Result<(), LayoutDirection> {
+    DEFAULT_DIRECTION.set(direction)
+}
+
+/// The default layout direction of the containers. It is
+/// [`LayoutDirection::LeftToRight`] if not set.
+pub fn default_direction() -> LayoutDirection {
+    DEFAULT_DIRECTION.get().copied().unwrap_or_default()
+}
+
+/// Place a child with the alignment inside the slot. The explicit size takes
+/// priority over the preferred size.
+fn align_in_slot(
+    slot: Rect,
+    preferred_size: Size,
+    width: Option<f64>,
+    height: Option<f64>,
+    halign: HAlign,
+    valign: VAlign,
+) -> Rect {
+    let width = match (width, halign) {
+        (Some(w), _) => w,
+        (None, HAlign::Stretch) => slot.width(),
+        (None, _) => preferred_size.width.min(slot.width()),
+    };
+    let height = match (height, valign) {
+        (Some(h), _) => h,
+        (None, VAlign::Stretch) => slot.height(),
+        (None, _) => preferred_size.height.min(slot.height()),
+    };
+    let x = match halign {
+        HAlign::Center => slot.origin.x + (slot.width() - width) / 2.0,
+        HAlign::Right => slot.origin.x + slot.width() - width,
+        _ => slot.origin.x,
+    };
+    let y = match valign {
+        VAlign::Center => slot.origin.y + (slot.height() - height) / 2.0,
+        VAlign::Bottom => slot.origin.y + slot.height() - height,
+        _ => slot.origin.y,
+    };
+    Rect::new(Point::new(x, y), Size::new(width, height))
+}
+
+/// The baseline of a child for [`VAlign::Baseline`]. The bottom edge is used
+/// if the child doesn't have a baseline.
+fn child_baseline<E>(child: &impl LayoutChild<Error = E>) -> Result<f64, LayoutError<E>> {
+    match child.child_baseline()? {
+        Some(b) => Ok(b),
+        None => Ok(child.child_preferred_size()?.height),
+    }
+}
+
 fn size_add_margin(size: Size, margin: Margin) -> Size {
     Size::new(
         size.width + margin.horizontal(),
@@ -126,7 +251,10 @@ fn rect_sub_margin(rect: Rect, margin: Margin) -> Rect {
 }
 
 macro_rules! __layout_child {
-    ($(#[$sm:meta])* struct $name:ident { $($(#[$m:meta])* $f:ident: $t:ty = $e:expr),*$(,)? }) => {
+    (
+        $(#[$sm:meta])* struct $name:ident { $($(#[$m:meta])* $f:ident: $t:ty = $e:expr),*$(,)? }
+        $(private { $($(#[$pm:meta])* $pf:ident: $pt:ty = $pe:expr),*$(,)? })?
+    ) => {
         struct $name<'a, E> {
             widget: &'a mut dyn $crate::LayoutChild<Error = E>,
             width: Option<f64>,
@@ -134,10 +262,17 @@ macro_rules! __layout_child {
             margin: $crate::Margin,
             halign: $crate::HAlign,
             valign: $crate::VAlign,
+            collapse_when_hidden: Option<bool>,
+            visible: ::std::cell::Cell<Option<bool>>,
+            hints: ::std::cell::Cell<$crate::SizeHints>,
             $(
                 $(#[$m])*
                 $f: $t,
             )*
+            $($(
+                $(#[$pm])*
+                $pf: $pt,
+            )*)?
         }
         impl<'a, E> $name<'a, E> {
             #[allow(unused_doc_comments)]
@@ -149,23 +284,75 @@ macro_rules! __layout_child {
                     margin: $crate::Margin::zero(),
                     halign: $crate::HAlign::Stretch,
                     valign: $crate::VAlign::Stretch,
+                    collapse_when_hidden: None,
+                    visible: ::std::cell::Cell::new(None),
+                    hints: ::std::cell::Cell::new($crate::SizeHints::default()),
                     $(
                         $(#[$m])*
                         $f: $e,
                     )*
+                    $($(
+                        $(#[$pm])*
+                        $pf: $pe,
+                    )*)?
+                }
+            }
+
+            /// If the child should be collapsed, with the default option of
+            /// the container.
+            pub fn collapsed(&self, default: bool) -> Result<bool, LayoutError<E>> {
+                if self.collapse_when_hidden.unwrap_or(default) {
+                    Ok(!$crate::LayoutChild::child_visible(self)?)
+                } else {
+                    Ok(false)
                 }
             }
         }
         impl<E> $crate::Failable for $name<'_, E> {
             type Error = E;
         }
+        impl<E> $crate::CachedChild for $name<'_, E> {
+            fn hints_key(&self) -> Option<u64> {
+                self.widget.child_layout_stamp()
+            }
+
+            fn hints(&self) -> $crate::SizeHints {
+                self.hints.get()
+            }
+
+            fn set_hints(&self, hints: $crate::SizeHints) {
+                self.hints.set(hints)
+            }
+        }
         impl<E> $crate::LayoutChild for $name<'_, E> {
+            // The size hints are queried several times during a layout, and the
+            // widget cannot be changed while it is borrowed by the container.
             fn child_preferred_size(&self) -> Result<$crate::Size, LayoutError<Self::Error>> {
-                Ok($crate::size_add_margin(self.widget.child_preferred_size()?, self.margin))
+                let mut hints = self.hints.get();
+                let s = match hints.preferred_size {
+                    Some(s) => s,
+                    None => {
+                        let s = self.widget.child_preferred_size()?;
+                        hints.preferred_size = Some(s);
+                        self.hints.set(hints);
+                        s
+                    }
+                };
+                Ok($crate::size_add_margin(s, self.margin))
             }
 
             fn child_min_size(&self) -> Result<$crate::Size, LayoutError<Self::Error>> {
-                Ok($crate::size_add_margin(self.widget.child_min_size()?, self.margin))
+                let mut hints = self.hints.get();
+                let s = match hints.min_size {
+                    Some(s) => s,
+                    None => {
+                        let s = self.widget.child_min_size()?;
+                        hints.min_size = Some(s);
+                        self.hints.set(hints);
+                        s
+                    }
+                };
+                Ok($crate::size_add_margin(s, self.margin))
             }
 
             fn set_child_loc(&mut self, p: Point) -> Result<(), LayoutError<Self::Error>> {
@@ -179,6 +366,29 @@ macro_rules! __layout_child {
             fn set_child_rect(&mut self, r: Rect) -> Result<(), LayoutError<Self::Error>> {
                 self.widget.set_child_rect($crate::rect_sub_margin(r, self.margin))
             }
+
+            fn child_visible(&self) -> Result<bool, LayoutError<Self::Error>> {
+                if let Some(v) = self.visible.get() {
+                    return Ok(v);
+                }
+                let v = self.widget.child_visible()?;
+                self.visible.set(Some(v));
+                Ok(v)
+            }
+
+            fn child_baseline(&self) -> Result<Option<f64>, LayoutError<Self::Error>> {
+                let mut hints = self.hints.get();
+                let b = match hints.baseline {
+                    Some(b) => b,
+                    None => {
+                        let b = self.widget.child_baseline()?;
+                        hints.baseline = Some(b);
+                        self.hints.set(hints);
+                        b
+                    }
+                };
+                Ok(b.map(|b| b + self.margin.top))
+            }
         }
         $crate::__paste! {
             $(#[$sm])*
@@ -222,6 +432,13 @@ macro_rules! __layout_child {
                     self
                 }
 
+                /// Collapse the child when it is hidden, overriding the option
+                /// of the container.
+                pub fn collapse_when_hidden(mut self, v: bool) -> Self {
+                    self.child.collapse_when_hidden = Some(v);
+                    self
+                }
+
                 $(
                     $(#[$m])*
                     pub fn $f(mut self, v: $t) -> Self {
@@ -250,26 +467,29 @@ pub enum LayoutError<E> {
     /// Child layout error.
     #[error("Child layout error: {0}")]
     Child(E),
+    /// The grid area of a child is not defined.
+    #[error("Unknown grid area: {0}")]
+    UnknownArea(String),
 }
 
 fn render<E>(
-    mut tree: TaffyTree,
-    root: NodeId,
-    nodes: Vec<NodeId>,
-    loc: Point,
-    size: Size,
+    cache: Option<&LayoutCache>,
+    root: Style,
+    styles: Vec<Style>,
+    area: Rect,
+    direction: LayoutDirection,
     children: &mut [impl LayoutChild<Error = E>],
 ) -> Result<(), LayoutError<E>> {
-    tree.compute_layout(
-        root,
-        taffy::Size {
-            width: taffy::AvailableSpace::Definite(size.width as _),
-            height: taffy::AvailableSpace::Definite(size.height as _),
-        },
-    )?;
-    for (id, child) in nodes.iter().zip(children) {
-        let layout = tree.layout(*id)?;
-        child.layout(layout, loc)?;
+    let collapsed = styles
+        .iter()
+        .map(|style| style.display == taffy::Display::None)
+        .collect::<Vec<_>>();
+    let (_, layouts) = compute_layout(cache, root, styles, available_size(area.size))?;
+    for ((layout, child), collapsed) in layouts.iter().zip(children).zip(collapsed) {
+        if !collapsed {
+            let rect = offset(rect_t2e(layout), area.origin);
+            child.set_child_rect(flip(rect, area, direction))?;
+        }
     }
     Ok(())
 }
@@ -289,12 +509,13 @@ fn render<E>(
 /// ```
 ///
 /// * `container`: the layout container, such as [`Grid`] created with
-///   [`Grid::from_str`];
+///   [`Grid::from_str`] or [`Grid::from_areas`];
 /// * `self.widget`: the child widget to be laid out. It can be written alone as
 ///   a shorthand, in which case it is laid out with the default hints;
 /// * `property: value`: the layout hints of the child, e.g. `column`, `row`,
-///   `margin`, `halign`, `valign`. The available properties depend on the
-///   container (see [`Grid`]).
+///   `area`, `margin`, `halign`, `valign`. The available properties depend on
+///   the container (see [`Grid`], [`StackPanel`], [`WrapPanel`] and
+///   [`DockPanel`]).
 ///
 /// The widgets are pushed into the container in order, and the container is
 /// returned. You should then set the size of the container to the available
@@ -1,10 +1,15 @@
 use taffy::{
-    NodeId, Style, TaffyTree,
+    Style,
     prelude::{auto, length, percent},
 };
-use winio_primitive::{Failable, HAlign, Orient, Point, Rect, Size, VAlign};
+use winio_primitive::{
+    Failable, HAlign, LayoutDirection, Margin, Orient, Point, Rect, Size, VAlign,
+};
 
-use crate::{LayoutChild, LayoutError, layout_child, rect_t2e, render};
+use crate::{
+    LayoutCache, LayoutChild, LayoutError, child_baseline, compute_layout, default_direction,
+    layout_child, load_hints, padding_e2t, rect_t2e, render, store_hints,
+};
 
 layout_child! {
     /// Builder of a child for [`StackPanel`].
@@ -18,6 +23,11 @@ layout_child! {
 pub struct StackPanel<'a, E> {
     children: Vec<StackPanelChild<'a, E>>,
     orient: Orient,
+    spacing: f64,
+    padding: Margin,
+    collapse_when_hidden: bool,
+    direction: Option<LayoutDirection>,
+    cache: Option<&'a LayoutCache>,
     loc: Point,
     size: Size,
 }
@@ -28,11 +38,47 @@ impl<'a, E> StackPanel<'a, E> {
         Self {
             children: vec![],
             orient,
+            spacing: 0.0,
+            padding: Margin::zero(),
+            collapse_when_hidden: false,
+            direction: None,
+            cache: None,
             loc: Point::zero(),
             size: Size::zero(),
         }
     }
 
+    /// Spacing between the children.
+    pub fn spacing(mut self, v: f64) -> Self {
+        self.spacing = v;
+        self
+    }
+
+    /// Inner padding of the panel.
+    pub fn padding(mut self, m: Margin) -> Self {
+        self.padding = m;
+        self
+    }
+
+    /// Layout direction of the panel. The default direction is used if not
+    /// specified, see [`set_default_direction`].
+    pub fn direction(mut self, v: LayoutDirection) -> Self {
+        self.direction = Some(v);
+        self
+    }
+
+    /// Collapse the hidden children, unless specified by the child.
+    pub fn collapse_when_hidden(mut self, v: bool) -> Self {
+        self.collapse_when_hidden = v;
+        self
+    }
+
+    /// Reuse the layout tree in the cache between renders.
+    pub fn cache(mut self, cache: &'a LayoutCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
     /// Push a child into the panel.
     pub fn push<'b>(
         &'b mut self,
@@ -44,10 +90,28 @@ impl<'a, E> StackPanel<'a, E> {
         }
     }
 
-    fn tree(&self) -> Result<(TaffyTree, NodeId, Vec<NodeId>), LayoutError<E>> {
-        let mut tree: TaffyTree<()> = TaffyTree::new();
-        let mut nodes = vec![];
+    fn styles(&self) -> Result<(Style, Vec<Style>), LayoutError<E>> {
+        load_hints(self.cache, &self.children);
+        // The baseline-aligned children are aligned to the lowest baseline.
+        let mut baseline = 0.0f64;
+        if self.orient == Orient::Horizontal {
+            for child in &self.children {
+                if child.valign == VAlign::Baseline
+                    && !child.collapsed(self.collapse_when_hidden)?
+                {
+                    baseline = baseline.max(child_baseline(child)?);
+                }
+            }
+        }
+        let mut styles = vec![];
         for child in &self.children {
+            if child.collapsed(self.collapse_when_hidden)? {
+                styles.push(Style {
+                    display: taffy::Display::None,
+                    ..Default::default()
+                });
+                continue;
+            }
             let preferred_size = child.child_preferred_size()?;
             let mut style = Style::default();
             style.size.width = match child.width {
@@ -73,12 +137,18 @@ impl<'a, E> StackPanel<'a, E> {
             };
             match self.orient {
                 Orient::Horizontal => {
-                    if matches!(child.valign, VAlign::Top | VAlign::Center) {
+                    if matches!(
+                        child.valign,
+                        VAlign::Top | VAlign::Center | VAlign::Baseline
+                    ) {
                         style.margin.bottom = auto();
                     }
                     if matches!(child.valign, VAlign::Bottom | VAlign::Center) {
                         style.margin.top = auto();
                     }
+                    if child.valign == VAlign::Baseline {
+                        style.margin.top = length((baseline - child_baseline(child)?) as f32);
+                    }
                 }
                 Orient::Vertical => {
                     if matches!(child.halign, HAlign::Left | HAlign::Center) {
@@ -92,26 +162,35 @@ impl<'a, E> StackPanel<'a, E> {
             if child.grow {
                 style.flex_grow = 1.0f32
             }
-            let node = tree.new_leaf(style)?;
-            nodes.push(node);
+            styles.push(style);
         }
-        let root = tree.new_with_children(
-            Style {
-                size: taffy::Size::from_percent(1.0f32, 1.0f32),
-                flex_direction: match self.orient {
-                    Orient::Horizontal => taffy::FlexDirection::Row,
-                    Orient::Vertical => taffy::FlexDirection::Column,
-                },
-                ..Default::default()
+        let root = Style {
+            size: taffy::Size::from_percent(1.0f32, 1.0f32),
+            flex_direction: match self.orient {
+                Orient::Horizontal => taffy::FlexDirection::Row,
+                Orient::Vertical => taffy::FlexDirection::Column,
+            },
+            gap: taffy::Size {
+                width: length(self.spacing as f32),
+                height: length(self.spacing as f32),
             },
-            &nodes,
-        )?;
-        Ok((tree, root, nodes))
+            padding: padding_e2t(self.padding),
+            ..Default::default()
+        };
+        store_hints(self.cache, &self.children);
+        Ok((root, styles))
     }
 
     fn render(&mut self) -> Result<(), LayoutError<E>> {
-        let (tree, root, nodes) = self.tree()?;
-        render(tree, root, nodes, self.loc, self.size, &mut self.children)
+        let (root, styles) = self.styles()?;
+        render(
+            self.cache,
+            root,
+            styles,
+            Rect::new(self.loc, self.size),
+            self.direction.unwrap_or_else(default_direction),
+            &mut self.children,
+        )
     }
 
     /// Move the location.
@@ -152,14 +231,14 @@ impl<E> LayoutChild for StackPanel<'_, E> {
     }
 
     fn child_preferred_size(&self) -> Result<Size, LayoutError<Self::Error>> {
-        let (mut tree, root, _) = self.tree()?;
-        tree.compute_layout(root, taffy::Size::max_content())?;
-        Ok(rect_t2e(tree.layout(root)?).size)
+        let (root, styles) = self.styles()?;
+        let (layout, _) = compute_layout(self.cache, root, styles, taffy::Size::max_content())?;
+        Ok(rect_t2e(&layout).size)
     }
 
     fn child_min_size(&self) -> Result<Size, LayoutError<Self::Error>> {
-        let (mut tree, root, _) = self.tree()?;
-        tree.compute_layout(root, taffy::Size::min_content())?;
-        Ok(rect_t2e(tree.layout(root)?).size)
+        let (root, styles) = self.styles()?;
+        let (layout, _) = compute_layout(self.cache, root, styles, taffy::Size::min_content())?;
+        Ok(rect_t2e(&layout).size)
     }
 }
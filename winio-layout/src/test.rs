@@ -1,13 +1,24 @@
-use winio_primitive::{Failable, HAlign, Orient, Point, Rect, Size, VAlign};
+use std::cell::Cell;
 
-use crate::{Grid, Layoutable, StackPanel, layout};
+use winio_primitive::{
+    Failable, HAlign, LayoutDirection, Margin, Orient, Point, Rect, Size, VAlign, next_layout_stamp,
+};
 
-#[derive(Debug, Clone)]
+use crate::{
+    AdaptiveLayout, Dock, DockPanel, Grid, GridBound, GridLength, LayoutCache, LayoutChild,
+    LayoutFile, LayoutFileError, Layoutable, StackPanel, WrapPanel, layout, layout_file,
+};
+
+#[derive(Debug)]
 struct MockChild {
     preferred_size: Size,
     min_size: Size,
+    visible: bool,
+    baseline: Option<f64>,
     loc: Point,
     size: Size,
+    hint_calls: Cell<usize>,
+    stamp: u64,
 }
 
 impl MockChild {
@@ -15,11 +26,22 @@ impl MockChild {
         Self {
             preferred_size,
             min_size,
+            visible: true,
+            baseline: None,
             loc: Point::zero(),
             size: Size::zero(),
+            hint_calls: Cell::new(0),
+            stamp: next_layout_stamp(),
         }
     }
 
+    /// Change the size hints like changing the text of a label.
+    pub fn set_hints(&mut self, preferred_size: Size, min_size: Size) {
+        self.preferred_size = preferred_size;
+        self.min_size = min_size;
+        self.stamp = next_layout_stamp();
+    }
+
     #[track_caller]
     pub fn assert_loc(&self, x: f64, y: f64) {
         assert_eq!(self.loc, Point::new(x, y))
@@ -31,6 +53,16 @@ impl MockChild {
     }
 }
 
+impl Clone for MockChild {
+    fn clone(&self) -> Self {
+        Self {
+            hint_calls: Cell::new(0),
+            stamp: next_layout_stamp(),
+            ..*self
+        }
+    }
+}
+
 impl Failable for MockChild {
     type Error = ();
 }
@@ -55,12 +87,26 @@ impl Layoutable for MockChild {
     }
 
     fn preferred_size(&self) -> Result<Size, ()> {
+        self.hint_calls.set(self.hint_calls.get() + 1);
         Ok(self.preferred_size)
     }
 
     fn min_size(&self) -> Result<Size, ()> {
+        self.hint_calls.set(self.hint_calls.get() + 1);
         Ok(self.min_size)
     }
+
+    fn is_layout_visible(&self) -> Result<bool, ()> {
+        Ok(self.visible)
+    }
+
+    fn baseline(&self) -> Result<Option<f64>, ()> {
+        Ok(self.baseline)
+    }
+
+    fn layout_stamp(&self) -> Option<u64> {
+        Some(self.stamp)
+    }
 }
 
 #[test]
@@ -266,3 +312,845 @@ fn grid_sudoku() {
     c8.assert_size(50.0, 50.0);
     c9.assert_size(50.0, 50.0);
 }
+
+#[test]
+fn wrap_panel_horizontal() {
+    let mut c1 = MockChild::new(Size::new(100.0, 50.0), Size::zero());
+    let mut c2 = MockChild::new(Size::new(100.0, 30.0), Size::zero());
+    let mut c3 = MockChild::new(Size::new(100.0, 40.0), Size::zero());
+
+    let mut panel = layout! {
+        WrapPanel::new(Orient::Horizontal),
+        c1, c2, c3
+    };
+    panel
+        .set_rect(Rect::new(Point::zero(), Size::new(250.0, 200.0)))
+        .unwrap();
+    c1.assert_loc(0.0, 0.0);
+    c2.assert_loc(100.0, 0.0);
+    c3.assert_loc(0.0, 50.0);
+    c1.assert_size(100.0, 50.0);
+    c2.assert_size(100.0, 50.0);
+    c3.assert_size(100.0, 40.0);
+
+    let mut panel = layout! {
+        WrapPanel::new(Orient::Horizontal)
+            .item_spacing(10.0)
+            .line_spacing(5.0),
+        c1 => { valign: VAlign::Top },
+        c2 => { valign: VAlign::Bottom },
+        c3 => { valign: VAlign::Center },
+    };
+    panel
+        .set_rect(Rect::new(Point::zero(), Size::new(250.0, 200.0)))
+        .unwrap();
+    c1.assert_loc(0.0, 0.0);
+    c2.assert_loc(110.0, 20.0);
+    c3.assert_loc(0.0, 55.0);
+    c1.assert_size(100.0, 50.0);
+    c2.assert_size(100.0, 30.0);
+    c3.assert_size(100.0, 40.0);
+}
+
+#[test]
+fn wrap_panel_vertical() {
+    let mut c1 = MockChild::new(Size::new(50.0, 100.0), Size::zero());
+    let mut c2 = MockChild::new(Size::new(30.0, 100.0), Size::zero());
+    let mut c3 = MockChild::new(Size::new(40.0, 100.0), Size::zero());
+
+    let mut panel = layout! {
+        WrapPanel::new(Orient::Vertical),
+        c1, c2, c3
+    };
+    panel
+        .set_rect(Rect::new(Point::zero(), Size::new(200.0, 250.0)))
+        .unwrap();
+    c1.assert_loc(0.0, 0.0);
+    c2.assert_loc(0.0, 100.0);
+    c3.assert_loc(50.0, 0.0);
+    c1.assert_size(50.0, 100.0);
+    c2.assert_size(50.0, 100.0);
+    c3.assert_size(40.0, 100.0);
+}
+
+#[test]
+fn wrap_panel_item_size() {
+    let mut c1 = MockChild::new(Size::new(20.0, 20.0), Size::zero());
+    let mut c2 = c1.clone();
+    let mut c3 = c1.clone();
+
+    let mut panel = layout! {
+        WrapPanel::new(Orient::Horizontal).item_size(Size::new(60.0, 40.0)),
+        c1,
+        c2 => { halign: HAlign::Center, valign: VAlign::Center },
+        c3 => { halign: HAlign::Right, valign: VAlign::Bottom },
+    };
+    panel
+        .set_rect(Rect::new(Point::zero(), Size::new(150.0, 200.0)))
+        .unwrap();
+    assert_eq!(
+        panel.child_preferred_size().unwrap(),
+        Size::new(180.0, 40.0)
+    );
+    c1.assert_loc(0.0, 0.0);
+    c2.assert_loc(80.0, 10.0);
+    c3.assert_loc(40.0, 60.0);
+    c1.assert_size(60.0, 40.0);
+    c2.assert_size(20.0, 20.0);
+    c3.assert_size(20.0, 20.0);
+}
+
+#[test]
+fn wrap_panel_item_size_override() {
+    let mut c1 = MockChild::new(Size::new(20.0, 20.0), Size::zero());
+    let mut c2 = c1.clone();
+    let mut c3 = c1.clone();
+
+    let mut panel = layout! {
+        WrapPanel::new(Orient::Horizontal).item_size(Size::new(60.0, 40.0)),
+        c1,
+        c2 => { width: 100.0 },
+        c3 => { height: 50.0 },
+    };
+    panel
+        .set_rect(Rect::new(Point::zero(), Size::new(300.0, 200.0)))
+        .unwrap();
+    c1.assert_loc(0.0, 0.0);
+    c2.assert_loc(60.0, 0.0);
+    c3.assert_loc(160.0, 0.0);
+    c1.assert_size(60.0, 40.0);
+    c2.assert_size(100.0, 40.0);
+    c3.assert_size(60.0, 50.0);
+}
+
+#[test]
+fn dock_panel_shell() {
+    let mut toolbar = MockChild::new(Size::new(100.0, 30.0), Size::zero());
+    let mut status = MockChild::new(Size::new(100.0, 20.0), Size::zero());
+    let mut side = MockChild::new(Size::new(80.0, 100.0), Size::zero());
+    let mut content = MockChild::new(Size::new(200.0, 200.0), Size::zero());
+
+    let mut panel = layout! {
+        DockPanel::new(),
+        toolbar => { dock: Dock::Top },
+        status  => { dock: Dock::Bottom },
+        side    => { dock: Dock::Left },
+        content => { dock: Dock::Fill },
+    };
+    assert_eq!(
+        panel.child_preferred_size().unwrap(),
+        Size::new(280.0, 250.0)
+    );
+    panel
+        .set_rect(Rect::new(Point::zero(), Size::new(400.0, 300.0)))
+        .unwrap();
+    toolbar.assert_loc(0.0, 0.0);
+    status.assert_loc(0.0, 280.0);
+    side.assert_loc(0.0, 30.0);
+    content.assert_loc(80.0, 30.0);
+    toolbar.assert_size(400.0, 30.0);
+    status.assert_size(400.0, 20.0);
+    side.assert_size(80.0, 250.0);
+    content.assert_size(320.0, 250.0);
+}
+
+#[test]
+fn dock_panel_align() {
+    let mut c1 = MockChild::new(Size::new(50.0, 50.0), Size::zero());
+    let mut c2 = c1.clone();
+    let mut c3 = c1.clone();
+
+    let mut panel = layout! {
+        DockPanel::new(),
+        c1 => { dock: Dock::Right, valign: VAlign::Center },
+        c2 => { dock: Dock::Left, width: 100.0, valign: VAlign::Bottom },
+        c3 => { dock: Dock::Fill, halign: HAlign::Center, valign: VAlign::Top },
+    };
+    panel
+        .set_rect(Rect::new(Point::new(10.0, 10.0), Size::new(300.0, 200.0)))
+        .unwrap();
+    c1.assert_loc(260.0, 85.0);
+    c2.assert_loc(10.0, 160.0);
+    c3.assert_loc(160.0, 10.0);
+    c1.assert_size(50.0, 50.0);
+    c2.assert_size(100.0, 50.0);
+    c3.assert_size(50.0, 50.0);
+}
+
+#[test]
+fn stack_panel_spacing_padding() {
+    let mut c1 = MockChild::new(Size::new(50.0, 20.0), Size::new(50.0, 20.0));
+    let mut c2 = c1.clone();
+    let mut c3 = c1.clone();
+
+    let mut panel = layout! {
+        StackPanel::new(Orient::Vertical)
+            .spacing(5.0)
+            .padding(Margin::new(10.0, 20.0, 10.0, 20.0)),
+        c1, c2, c3 => { grow: true }
+    };
+    assert_eq!(panel.child_preferred_size().unwrap(), Size::new(90.0, 90.0));
+    panel
+        .set_rect(Rect::new(Point::zero(), Size::new(200.0, 200.0)))
+        .unwrap();
+    c1.assert_loc(20.0, 10.0);
+    c2.assert_loc(20.0, 35.0);
+    c3.assert_loc(20.0, 60.0);
+    c1.assert_size(160.0, 20.0);
+    c2.assert_size(160.0, 20.0);
+    c3.assert_size(160.0, 130.0);
+}
+
+#[test]
+fn grid_gap_padding() {
+    let mut c1 = MockChild::new(Size::new(50.0, 50.0), Size::new(50.0, 50.0));
+    let mut c2 = c1.clone();
+    let mut c3 = c1.clone();
+    let mut c4 = c1.clone();
+
+    let mut grid = layout! {
+        Grid::from_str("auto,1*", "auto,1*")
+            .unwrap()
+            .column_gap(10.0)
+            .row_gap(20.0)
+            .padding(Margin::new(5.0, 5.0, 5.0, 5.0)),
+        c1 => { column: 0, row: 0 },
+        c2 => { column: 1, row: 0 },
+        c3 => { column: 0, row: 1 },
+        c4 => { column: 1, row: 1 },
+    };
+    assert_eq!(
+        grid.child_preferred_size().unwrap(),
+        Size::new(120.0, 130.0)
+    );
+    grid.set_rect(Rect::new(Point::zero(), Size::new(300.0, 300.0)))
+        .unwrap();
+    c1.assert_loc(5.0, 5.0);
+    c2.assert_loc(65.0, 5.0);
+    c3.assert_loc(5.0, 75.0);
+    c4.assert_loc(65.0, 75.0);
+    c1.assert_size(50.0, 50.0);
+    c2.assert_size(230.0, 50.0);
+    c3.assert_size(50.0, 220.0);
+    c4.assert_size(230.0, 220.0);
+}
+
+#[test]
+fn grid_areas() {
+    let mut header = MockChild::new(Size::new(50.0, 50.0), Size::new(50.0, 50.0));
+    let mut nav = header.clone();
+    let mut main = header.clone();
+
+    let mut grid = layout! {
+        Grid::from_areas(r#""header header" "nav main""#, "100,1*", "50,1*").unwrap(),
+        header => { area: "header" },
+        nav    => { area: "nav" },
+        main   => { area: "main" },
+    };
+    grid.set_rect(Rect::new(Point::zero(), Size::new(300.0, 200.0)))
+        .unwrap();
+    header.assert_loc(0.0, 0.0);
+    nav.assert_loc(0.0, 50.0);
+    main.assert_loc(100.0, 50.0);
+    header.assert_size(300.0, 50.0);
+    nav.assert_size(100.0, 150.0);
+    main.assert_size(200.0, 150.0);
+
+    assert!(Grid::<()>::from_areas(r#""a b" "a""#, "", "").is_err());
+    assert!(Grid::<()>::from_areas(r#""a b" "b a""#, "", "").is_err());
+    assert!(Grid::<()>::from_areas(r#""a . a""#, "", "").is_err());
+    assert!(Grid::<()>::from_areas("a b", "", "").is_err());
+
+    let mut c = header.clone();
+    let mut grid = layout! {
+        Grid::from_areas(r#""a""#, "1*", "1*").unwrap(),
+        c => { area: "b" },
+    };
+    assert!(
+        grid.set_rect(Rect::new(Point::zero(), Size::new(100.0, 100.0)))
+            .is_err()
+    );
+}
+
+#[test]
+fn grid_auto_placement() {
+    let mut c1 = MockChild::new(Size::new(50.0, 50.0), Size::new(50.0, 50.0));
+    let mut c2 = c1.clone();
+    let mut c3 = c1.clone();
+    let mut c4 = c1.clone();
+
+    let mut grid = layout! {
+        Grid::from_str("1*,1*", "").unwrap(),
+        c1, c2,
+        c3 => { column_span: 2 },
+        c4,
+    };
+    assert_eq!(
+        grid.child_preferred_size().unwrap(),
+        Size::new(100.0, 150.0)
+    );
+    grid.set_rect(Rect::new(Point::zero(), Size::new(200.0, 300.0)))
+        .unwrap();
+    c1.assert_loc(0.0, 0.0);
+    c2.assert_loc(100.0, 0.0);
+    c3.assert_loc(0.0, 100.0);
+    c4.assert_loc(0.0, 200.0);
+    c3.assert_size(200.0, 100.0);
+}
+
+#[test]
+fn grid_length_parse() {
+    assert_eq!(
+        "minmax(100, 1*)".parse::<GridLength>().unwrap(),
+        GridLength::MinMax(GridBound::Length(100.0), GridBound::Stretch(1.0))
+    );
+    assert_eq!(
+        "minmax(min-content,max-content)"
+            .parse::<GridLength>()
+            .unwrap(),
+        GridLength::MinMax(GridBound::MinContent, GridBound::MaxContent)
+    );
+    assert_eq!(
+        "fit-content(200)".parse::<GridLength>().unwrap(),
+        GridLength::FitContent(200.0)
+    );
+    assert!("minmax(1*, auto)".parse::<GridLength>().is_err());
+    assert!("minmax(100)".parse::<GridLength>().is_err());
+
+    let mut c1 = MockChild::new(Size::new(50.0, 50.0), Size::new(50.0, 50.0));
+    let mut c2 = MockChild::new(Size::new(300.0, 50.0), Size::new(300.0, 50.0));
+    let mut grid = layout! {
+        Grid::from_str("minmax(100, 1*), fit-content(100), 1*", "auto").unwrap(),
+        c1 => { column: 0 },
+        c2 => { column: 1 },
+    };
+    grid.set_rect(Rect::new(Point::zero(), Size::new(400.0, 100.0)))
+        .unwrap();
+    c1.assert_size(100.0, 100.0);
+    c2.assert_loc(100.0, 0.0);
+}
+
+#[test]
+fn layout_cache() {
+    let cache = LayoutCache::new();
+    let mut c1 = MockChild::new(Size::new(50.0, 50.0), Size::new(50.0, 50.0));
+    let mut c2 = c1.clone();
+    let mut c3 = c1.clone();
+
+    for width in [200.0, 300.0, 300.0] {
+        let mut panel = layout! {
+            StackPanel::new(Orient::Horizontal).cache(&cache),
+            c1,
+            c2 => { grow: true },
+        };
+        panel
+            .set_rect(Rect::new(Point::zero(), Size::new(width, 100.0)))
+            .unwrap();
+        c1.assert_size(50.0, 100.0);
+        c2.assert_loc(50.0, 0.0);
+        c2.assert_size(width - 50.0, 100.0);
+    }
+
+    c1.set_hints(Size::new(100.0, 50.0), Size::new(100.0, 50.0));
+    {
+        let mut panel = layout! {
+            StackPanel::new(Orient::Horizontal).cache(&cache),
+            c1,
+            c2 => { grow: true },
+            c3,
+        };
+        panel
+            .set_rect(Rect::new(Point::zero(), Size::new(300.0, 100.0)))
+            .unwrap();
+    }
+    c1.assert_size(100.0, 100.0);
+    c2.assert_loc(100.0, 0.0);
+    c2.assert_size(150.0, 100.0);
+    c3.assert_loc(250.0, 0.0);
+
+    // Sharing the cache between nested panels only makes the cache less
+    // effective.
+    {
+        let mut inner = layout! {
+            StackPanel::new(Orient::Vertical).cache(&cache),
+            c1, c2,
+        };
+        let mut panel = layout! {
+            StackPanel::new(Orient::Horizontal).cache(&cache),
+            inner,
+            c3 => { grow: true },
+        };
+        panel
+            .set_rect(Rect::new(Point::zero(), Size::new(300.0, 100.0)))
+            .unwrap();
+    }
+    c1.assert_loc(0.0, 0.0);
+    c2.assert_loc(0.0, 50.0);
+    c3.assert_loc(100.0, 0.0);
+}
+
+#[test]
+fn layout_cache_hints() {
+    let cache = LayoutCache::new();
+    let mut c1 = MockChild::new(Size::new(50.0, 50.0), Size::new(50.0, 50.0));
+    let mut c2 = c1.clone();
+
+    for width in [200.0, 300.0] {
+        let mut grid = layout! {
+            Grid::from_str("auto,1*", "1*").unwrap().cache(&cache),
+            c1 => { column: 0, halign: HAlign::Left },
+            c2 => { column: 1 },
+        };
+        grid.set_rect(Rect::new(Point::zero(), Size::new(width, 100.0)))
+            .unwrap();
+        assert_eq!(c1.hint_calls.get(), 2);
+        assert_eq!(c2.hint_calls.get(), 2);
+        c2.assert_loc(50.0, 0.0);
+        c2.assert_size(width - 50.0, 100.0);
+    }
+
+    cache.invalidate();
+    {
+        let mut grid = layout! {
+            Grid::from_str("auto,1*", "1*").unwrap().cache(&cache),
+            c1 => { column: 0, halign: HAlign::Left },
+            c2 => { column: 1 },
+        };
+        grid.set_rect(Rect::new(Point::zero(), Size::new(300.0, 100.0)))
+            .unwrap();
+    }
+    assert_eq!(c1.hint_calls.get(), 4);
+    assert_eq!(c2.hint_calls.get(), 4);
+}
+
+#[test]
+fn layout_cache_stamp() {
+    let cache = LayoutCache::new();
+    let mut c1 = MockChild::new(Size::new(50.0, 20.0), Size::new(50.0, 20.0));
+    let mut c2 = MockChild::new(Size::new(50.0, 20.0), Size::new(50.0, 20.0));
+
+    {
+        let mut panel = layout! {
+            StackPanel::new(Orient::Horizontal).cache(&cache),
+            c1, c2,
+        };
+        panel
+            .set_rect(Rect::new(Point::zero(), Size::new(300.0, 100.0)))
+            .unwrap();
+    }
+    c1.assert_size(50.0, 100.0);
+    c2.assert_loc(50.0, 0.0);
+    assert_eq!(c1.hint_calls.get(), 2);
+
+    // The text of the first label becomes longer.
+    c1.set_hints(Size::new(120.0, 20.0), Size::new(120.0, 20.0));
+    {
+        let mut panel = layout! {
+            StackPanel::new(Orient::Horizontal).cache(&cache),
+            c1, c2,
+        };
+        panel
+            .set_rect(Rect::new(Point::zero(), Size::new(300.0, 100.0)))
+            .unwrap();
+    }
+    assert_eq!(c1.hint_calls.get(), 4);
+    assert_eq!(c2.hint_calls.get(), 2);
+    c1.assert_size(120.0, 100.0);
+    c2.assert_loc(120.0, 0.0);
+
+    // Nothing changed, so the hints are reused.
+    {
+        let mut panel = layout! {
+            StackPanel::new(Orient::Horizontal).cache(&cache),
+            c1, c2,
+        };
+        panel
+            .set_rect(Rect::new(Point::zero(), Size::new(300.0, 100.0)))
+            .unwrap();
+    }
+    assert_eq!(c1.hint_calls.get(), 4);
+    c2.assert_loc(120.0, 0.0);
+}
+
+#[test]
+fn collapse_when_hidden() {
+    let mut c1 = MockChild::new(Size::new(50.0, 50.0), Size::new(50.0, 50.0));
+    let mut c2 = c1.clone();
+    let mut c3 = c1.clone();
+    c2.visible = false;
+
+    {
+        let mut panel = layout! {
+            StackPanel::new(Orient::Vertical).collapse_when_hidden(true),
+            c1 => { valign: VAlign::Top },
+            c2 => { valign: VAlign::Top },
+            c3 => { valign: VAlign::Top },
+        };
+        assert_eq!(
+            panel.child_preferred_size().unwrap(),
+            Size::new(50.0, 100.0)
+        );
+        panel
+            .set_rect(Rect::new(Point::zero(), Size::new(100.0, 200.0)))
+            .unwrap();
+    }
+    c1.assert_loc(0.0, 0.0);
+    c2.assert_size(0.0, 0.0);
+    c3.assert_loc(0.0, 50.0);
+
+    {
+        let mut panel = layout! {
+            StackPanel::new(Orient::Vertical).collapse_when_hidden(true),
+            c1 => { valign: VAlign::Top },
+            c2 => { valign: VAlign::Top, collapse_when_hidden: false },
+            c3 => { valign: VAlign::Top },
+        };
+        panel
+            .set_rect(Rect::new(Point::zero(), Size::new(100.0, 200.0)))
+            .unwrap();
+    }
+    c2.assert_loc(0.0, 50.0);
+    c3.assert_loc(0.0, 100.0);
+
+    let mut c4 = c1.clone();
+    {
+        let mut grid = layout! {
+            Grid::from_str("1*,1*", "1*,1*").unwrap(),
+            c1,
+            c2 => { collapse_when_hidden: true },
+            c3, c4,
+        };
+        grid.set_rect(Rect::new(Point::zero(), Size::new(200.0, 200.0)))
+            .unwrap();
+    }
+    c1.assert_loc(0.0, 0.0);
+    c3.assert_loc(100.0, 0.0);
+    c4.assert_loc(0.0, 100.0);
+
+    {
+        let mut panel = layout! {
+            DockPanel::new().collapse_when_hidden(true),
+            c1 => { dock: Dock::Left },
+            c2 => { dock: Dock::Left },
+            c3 => { dock: Dock::Fill },
+        };
+        panel
+            .set_rect(Rect::new(Point::zero(), Size::new(200.0, 100.0)))
+            .unwrap();
+    }
+    c3.assert_loc(50.0, 0.0);
+    c3.assert_size(150.0, 100.0);
+}
+
+#[test]
+fn layout_file() {
+    let file: LayoutFile = r#"
+        // The login form.
+        grid "login" columns="auto, minmax(100, 1*)" rows="auto,auto" gap=4 padding=8 {
+            ulabel column=0 row=0 valign=center
+            uentry column=1 row=0
+            plabel column=0 row=1 valign="Center"; pentry column=1 row=1 margin="4,0,4,0"
+        }
+    "#
+    .parse()
+    .unwrap();
+    let template = file.grid("login").unwrap();
+    assert_eq!(
+        template.columns(),
+        Grid::<()>::parse_grid_lengths("auto, minmax(100, 1*)")
+            .unwrap()
+            .as_slice()
+    );
+    assert_eq!(template.children().count(), 4);
+
+    let mut c1 = MockChild::new(Size::new(50.0, 20.0), Size::new(50.0, 20.0));
+    let mut c2 = c1.clone();
+    let mut c3 = c1.clone();
+    let mut c4 = c1.clone();
+    {
+        let mut grid = layout_file! {
+            template,
+            ulabel => c1,
+            uentry => c2,
+            plabel => c3,
+            pentry => c4,
+        }
+        .unwrap();
+        grid.set_rect(Rect::new(Point::zero(), Size::new(300.0, 200.0)))
+            .unwrap();
+    }
+    let (mut d1, mut d2, mut d3, mut d4) = (c1.clone(), c2.clone(), c3.clone(), c4.clone());
+    {
+        let mut grid = layout! {
+            Grid::from_str("auto, minmax(100, 1*)", "auto,auto")
+                .unwrap()
+                .gap(4.0)
+                .padding(Margin::new_all_same(8.0)),
+            d1 => { column: 0, row: 0, valign: VAlign::Center },
+            d2 => { column: 1, row: 0 },
+            d3 => { column: 0, row: 1, valign: VAlign::Center },
+            d4 => { column: 1, row: 1, margin: Margin::new(4.0, 0.0, 4.0, 0.0) },
+        };
+        grid.set_rect(Rect::new(Point::zero(), Size::new(300.0, 200.0)))
+            .unwrap();
+    }
+    for (c, d) in [(&c1, &d1), (&c2, &d2), (&c3, &d3), (&c4, &d4)] {
+        c.assert_loc(d.loc.x, d.loc.y);
+        c.assert_size(d.size.width, d.size.height);
+    }
+    c2.assert_loc(62.0, 8.0);
+
+    let mut c5 = c1.clone();
+    assert!(matches!(
+        layout_file! { template, other => c5 },
+        Err(LayoutFileError::UnknownChild(_))
+    ));
+    assert!(matches!(
+        file.grid("other"),
+        Err(LayoutFileError::UnknownGrid(_))
+    ));
+    assert!(matches!(
+        r#"grid "a" columns="1*" { w column=1 }"#.parse::<LayoutFile>(),
+        Err(LayoutFileError::OutOfRange(1, _))
+    ));
+    assert!(matches!(
+        "grid \"a\" {\n w columns=1\n}".parse::<LayoutFile>(),
+        Err(LayoutFileError::UnknownProperty(2, _))
+    ));
+    assert!(matches!(
+        r#"grid "a" { w halign=top }"#.parse::<LayoutFile>(),
+        Err(LayoutFileError::InvalidValue(1, _))
+    ));
+    assert!(matches!(
+        r#"grid "a" { w"#.parse::<LayoutFile>(),
+        Err(LayoutFileError::Syntax(1, _))
+    ));
+}
+
+#[test]
+fn layout_file_errors() {
+    let parse = |s: &str| s.parse::<LayoutFile>().unwrap_err();
+
+    assert!(matches!(
+        parse("grid \"a\" {\n w column=0\n}\ngrid \"b\" spacing=4"),
+        LayoutFileError::UnknownProperty(4, p) if p == "spacing"
+    ));
+    assert!(matches!(
+        parse("grid \"a\" {\n w margin=\"4,0\n}"),
+        LayoutFileError::Syntax(3, _)
+    ));
+    assert!(matches!(
+        parse("grid \"a\" {\n w halign=\"left\\q\"\n}"),
+        LayoutFileError::Syntax(2, _)
+    ));
+    assert!(matches!(
+        parse(r#"grid columns="1*" { w }"#),
+        LayoutFileError::InvalidValue(1, n) if n == "grid"
+    ));
+    assert!(matches!(
+        parse(r#"grid "a" "b" { w }"#),
+        LayoutFileError::InvalidValue(1, n) if n == "grid"
+    ));
+    assert!(matches!(
+        parse("grid \"a\" {\n w column=0\n w column=1\n}"),
+        LayoutFileError::Duplicate(3, n) if n == "w"
+    ));
+    assert!(matches!(
+        parse("grid \"a\" {}\ngrid \"a\" {}"),
+        LayoutFileError::Duplicate(2, n) if n == "a"
+    ));
+    assert!(matches!(
+        parse(r#"stack "a" { w }"#),
+        LayoutFileError::UnknownNode(1, n) if n == "stack"
+    ));
+    assert!(matches!(
+        parse(r#"grid "a" { w "arg" }"#),
+        LayoutFileError::Syntax(1, _)
+    ));
+    assert!(matches!(
+        parse(r#"grid "a" { w column=-1 }"#),
+        LayoutFileError::InvalidValue(1, p) if p == "column"
+    ));
+    assert!(matches!(
+        parse(r#"grid "a" columns="1*,x" { }"#),
+        LayoutFileError::GridLength(1, _)
+    ));
+    assert!(matches!(
+        parse("grid \"a\" { }\n}"),
+        LayoutFileError::Syntax(2, _)
+    ));
+
+    // The unsupported KDL syntax is rejected instead of misread.
+    assert!(matches!(
+        parse("grid \"a\" { /* comment */ }"),
+        LayoutFileError::Syntax(1, _)
+    ));
+    assert!(matches!(
+        parse("grid \"a\" {\n /-w column=0\n}"),
+        LayoutFileError::Syntax(2, _)
+    ));
+    assert!(matches!(
+        parse(r##"grid #"a"# { w }"##),
+        LayoutFileError::Syntax(1, _)
+    ));
+    assert!(matches!(
+        parse(r##"grid r#"a"# { w }"##),
+        LayoutFileError::Syntax(1, _)
+    ));
+    assert!(matches!(
+        parse(r#"grid "a" { w width=#null }"#),
+        LayoutFileError::Syntax(1, _)
+    ));
+    assert!(matches!(
+        parse("grid \"\"\"\na\n\"\"\" { w }"),
+        LayoutFileError::Syntax(1, _)
+    ));
+}
+
+#[test]
+fn right_to_left() {
+    let mut c1 = MockChild::new(Size::new(50.0, 50.0), Size::new(50.0, 50.0));
+    let mut c2 = c1.clone();
+    let mut c3 = c1.clone();
+
+    {
+        let mut panel = layout! {
+            StackPanel::new(Orient::Horizontal).direction(LayoutDirection::RightToLeft),
+            c1,
+            c2 => { grow: true },
+        };
+        panel
+            .set_rect(Rect::new(Point::new(10.0, 0.0), Size::new(200.0, 100.0)))
+            .unwrap();
+    }
+    c1.assert_loc(160.0, 0.0);
+    c2.assert_loc(10.0, 0.0);
+    c2.assert_size(150.0, 100.0);
+
+    {
+        let mut grid = layout! {
+            Grid::from_str("100,1*", "1*").unwrap().direction(LayoutDirection::RightToLeft),
+            c1 => { column: 0, halign: HAlign::Left },
+            c2 => { column: 1, halign: HAlign::Left },
+        };
+        grid.set_rect(Rect::new(Point::zero(), Size::new(300.0, 100.0)))
+            .unwrap();
+    }
+    c1.assert_loc(250.0, 0.0);
+    c2.assert_loc(150.0, 0.0);
+
+    {
+        let mut panel = layout! {
+            DockPanel::new().direction(LayoutDirection::RightToLeft),
+            c1 => { dock: Dock::Left },
+            c2 => { dock: Dock::Right },
+            c3 => { dock: Dock::Fill },
+        };
+        panel
+            .set_rect(Rect::new(Point::zero(), Size::new(300.0, 100.0)))
+            .unwrap();
+    }
+    c1.assert_loc(250.0, 0.0);
+    c2.assert_loc(0.0, 0.0);
+    c3.assert_loc(50.0, 0.0);
+}
+
+#[test]
+fn baseline() {
+    let mut c1 = MockChild::new(Size::new(50.0, 20.0), Size::zero());
+    let mut c2 = MockChild::new(Size::new(50.0, 40.0), Size::zero());
+    let mut c3 = MockChild::new(Size::new(50.0, 10.0), Size::zero());
+    c1.baseline = Some(15.0);
+    c2.baseline = Some(25.0);
+
+    {
+        let mut panel = layout! {
+            StackPanel::new(Orient::Horizontal),
+            c1 => { valign: VAlign::Baseline },
+            c2 => { valign: VAlign::Baseline, margin: Margin::new(5.0, 0.0, 0.0, 0.0) },
+            c3 => { valign: VAlign::Baseline },
+        };
+        assert_eq!(
+            panel.child_preferred_size().unwrap(),
+            Size::new(150.0, 45.0)
+        );
+        panel
+            .set_rect(Rect::new(Point::zero(), Size::new(150.0, 100.0)))
+            .unwrap();
+    }
+    c1.assert_loc(0.0, 15.0);
+    c1.assert_size(50.0, 20.0);
+    c2.assert_loc(50.0, 5.0);
+    c2.assert_size(50.0, 40.0);
+    // No baseline, aligned with the bottom edge.
+    c3.assert_loc(100.0, 20.0);
+
+    {
+        let mut grid = layout! {
+            Grid::from_str("1*,1*", "auto,auto").unwrap(),
+            c1 => { column: 0, row: 0, valign: VAlign::Baseline },
+            c2 => { column: 1, row: 0, valign: VAlign::Baseline },
+            c3 => { column: 0, row: 1, valign: VAlign::Baseline },
+        };
+        grid.set_rect(Rect::new(Point::zero(), Size::new(100.0, 50.0)))
+            .unwrap();
+    }
+    c1.assert_loc(0.0, 10.0);
+    c2.assert_loc(50.0, 0.0);
+    c3.assert_loc(0.0, 40.0);
+}
+
+#[test]
+fn adaptive_layout() {
+    let adaptive = AdaptiveLayout::new()
+        .breakpoint(300.0, 0.0)
+        .breakpoint(300.0, 300.0);
+    let mut c1 = MockChild::new(Size::new(50.0, 50.0), Size::zero());
+    let mut c2 = c1.clone();
+
+    let mut render = |size: Size| {
+        adaptive
+            .set_size(size, |breakpoint| match breakpoint {
+                0 => layout! {
+                    Grid::from_str("1*", "1*,1*").unwrap(),
+                    c1 => { column: 0, row: 0 },
+                    c2 => { column: 0, row: 1 },
+                },
+                _ => layout! {
+                    Grid::from_str("1*,1*", "1*").unwrap(),
+                    c1 => { column: 0, row: 0 },
+                    c2 => { column: 1, row: 0 },
+                },
+            })
+            .unwrap();
+    };
+    let waker = std::task::Waker::noop();
+    let mut cx = std::task::Context::from_waker(waker);
+
+    assert_eq!(adaptive.current(), None);
+    render(Size::new(200.0, 200.0));
+    assert_eq!(adaptive.current(), Some(0));
+    assert_eq!(
+        std::pin::pin!(adaptive.wait_change()).poll(&mut cx),
+        std::task::Poll::Ready(0)
+    );
+
+    render(Size::new(250.0, 200.0));
+    assert!(
+        std::pin::pin!(adaptive.wait_change())
+            .poll(&mut cx)
+            .is_pending()
+    );
+
+    render(Size::new(400.0, 200.0));
+    assert_eq!(adaptive.current(), Some(1));
+    render(Size::new(400.0, 400.0));
+    assert_eq!(adaptive.current(), Some(2));
+    assert_eq!(
+        std::pin::pin!(adaptive.wait_change()).poll(&mut cx),
+        std::task::Poll::Ready(2)
+    );
+    c1.assert_loc(0.0, 0.0);
+    c1.assert_size(200.0, 400.0);
+    c2.assert_loc(200.0, 0.0);
+}
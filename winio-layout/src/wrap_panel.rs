@@ -0,0 +1,250 @@
+use taffy::{
+    Style,
+    prelude::{auto, length},
+};
+use winio_primitive::{Failable, LayoutDirection, Orient, Point, Rect, Size};
+
+use crate::{
+    LayoutCache, LayoutChild, LayoutError, align_in_slot, available_size, compute_layout,
+    default_direction, flip, layout_child, load_hints, offset, rect_t2e, store_hints,
+};
+
+layout_child! {
+    /// Builder of a child for [`WrapPanel`].
+    struct WrapPanelChild {}
+}
+
+/// A wrapping layout container.
+///
+/// The children are placed one after another in the orientation direction,
+/// and break into a new line when there is no more space.
+pub struct WrapPanel<'a, E> {
+    children: Vec<WrapPanelChild<'a, E>>,
+    orient: Orient,
+    item_spacing: f64,
+    line_spacing: f64,
+    item_width: Option<f64>,
+    item_height: Option<f64>,
+    collapse_when_hidden: bool,
+    direction: Option<LayoutDirection>,
+    cache: Option<&'a LayoutCache>,
+    loc: Point,
+    size: Size,
+}
+
+impl<'a, E> WrapPanel<'a, E> {
+    /// Create [`WrapPanel`] with orientation.
+    pub fn new(orient: Orient) -> Self {
+        Self {
+            children: vec![],
+            orient,
+            item_spacing: 0.0,
+            line_spacing: 0.0,
+            item_width: None,
+            item_height: None,
+            collapse_when_hidden: false,
+            direction: None,
+            cache: None,
+            loc: Point::zero(),
+            size: Size::zero(),
+        }
+    }
+
+    /// Spacing between the items in a line.
+    pub fn item_spacing(mut self, v: f64) -> Self {
+        self.item_spacing = v;
+        self
+    }
+
+    /// Spacing between the lines.
+    pub fn line_spacing(mut self, v: f64) -> Self {
+        self.line_spacing = v;
+        self
+    }
+
+    /// Uniform width of all items. An explicit `width` of a child takes
+    /// precedence.
+    pub fn item_width(mut self, v: f64) -> Self {
+        self.item_width = Some(v);
+        self
+    }
+
+    /// Uniform height of all items. An explicit `height` of a child takes
+    /// precedence.
+    pub fn item_height(mut self, v: f64) -> Self {
+        self.item_height = Some(v);
+        self
+    }
+
+    /// Uniform size of all items.
+    pub fn item_size(self, s: Size) -> Self {
+        self.item_width(s.width).item_height(s.height)
+    }
+
+    /// Layout direction of the panel. The default direction is used if not
+    /// specified, see [`set_default_direction`].
+    pub fn direction(mut self, v: LayoutDirection) -> Self {
+        self.direction = Some(v);
+        self
+    }
+
+    /// Collapse the hidden children, unless specified by the child.
+    pub fn collapse_when_hidden(mut self, v: bool) -> Self {
+        self.collapse_when_hidden = v;
+        self
+    }
+
+    /// Reuse the layout tree in the cache between renders.
+    pub fn cache(mut self, cache: &'a LayoutCache) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Push a child into the panel.
+    pub fn push<'b>(
+        &'b mut self,
+        widget: &'a mut dyn LayoutChild<Error = E>,
+    ) -> WrapPanelChildBuilder<'a, 'b, E> {
+        WrapPanelChildBuilder {
+            child: WrapPanelChild::new(widget),
+            children: &mut self.children,
+        }
+    }
+
+    fn styles(&self) -> Result<(Style, Vec<Style>), LayoutError<E>> {
+        load_hints(self.cache, &self.children);
+        let mut styles = vec![];
+        for child in &self.children {
+            if child.collapsed(self.collapse_when_hidden)? {
+                styles.push(Style {
+                    display: taffy::Display::None,
+                    ..Default::default()
+                });
+                continue;
+            }
+            let preferred_size = child.child_preferred_size()?;
+            let mut style = Style::default();
+            // Each node is a slot of the item, which fills the line in the cross axis.
+            // The child is aligned inside the slot after the layout is computed.
+            style.size.width = match child.width.or(self.item_width) {
+                Some(w) => length(w as f32),
+                None => match self.orient {
+                    Orient::Horizontal => length(preferred_size.width as f32),
+                    Orient::Vertical => auto(),
+                },
+            };
+            style.size.height = match child.height.or(self.item_height) {
+                Some(h) => length(h as f32),
+                None => match self.orient {
+                    Orient::Horizontal => auto(),
+                    Orient::Vertical => length(preferred_size.height as f32),
+                },
+            };
+            let min_size = child.child_min_size()?;
+            style.min_size = taffy::Size {
+                width: length(min_size.width as f32),
+                height: length(min_size.height as f32),
+            };
+            if style.size.width == auto() {
+                style.min_size.width = length(preferred_size.width as f32);
+            }
+            if style.size.height == auto() {
+                style.min_size.height = length(preferred_size.height as f32);
+            }
+            style.flex_shrink = 0.0;
+            styles.push(style);
+        }
+        let (column_gap, row_gap) = match self.orient {
+            Orient::Horizontal => (self.item_spacing, self.line_spacing),
+            Orient::Vertical => (self.line_spacing, self.item_spacing),
+        };
+        let root = Style {
+            size: taffy::Size::from_percent(1.0f32, 1.0f32),
+            flex_direction: match self.orient {
+                Orient::Horizontal => taffy::FlexDirection::Row,
+                Orient::Vertical => taffy::FlexDirection::Column,
+            },
+            flex_wrap: taffy::FlexWrap::Wrap,
+            align_content: Some(taffy::AlignContent::FLEX_START),
+            gap: taffy::Size {
+                width: length(column_gap as f32),
+                height: length(row_gap as f32),
+            },
+            ..Default::default()
+        };
+        store_hints(self.cache, &self.children);
+        Ok((root, styles))
+    }
+
+    fn render(&mut self) -> Result<(), LayoutError<E>> {
+        let (root, styles) = self.styles()?;
+        let (_, layouts) = compute_layout(self.cache, root, styles, available_size(self.size))?;
+        let area = Rect::new(self.loc, self.size);
+        let direction = self.direction.unwrap_or_else(default_direction);
+        for (layout, child) in layouts.iter().zip(&mut self.children) {
+            if child.collapsed(self.collapse_when_hidden)? {
+                continue;
+            }
+            let slot = offset(rect_t2e(layout), self.loc);
+            let rect = align_in_slot(
+                slot,
+                child.child_preferred_size()?,
+                child.width,
+                child.height,
+                child.halign,
+                child.valign,
+            );
+            child.set_child_rect(flip(rect, area, direction))?;
+        }
+        Ok(())
+    }
+
+    /// Move the location.
+    pub fn set_loc(&mut self, p: Point) -> Result<(), LayoutError<E>> {
+        LayoutChild::set_child_loc(self, p)
+    }
+
+    /// Resize.
+    pub fn set_size(&mut self, s: Size) -> Result<(), LayoutError<E>> {
+        LayoutChild::set_child_size(self, s)
+    }
+
+    /// Set the location and size.
+    pub fn set_rect(&mut self, r: Rect) -> Result<(), LayoutError<E>> {
+        LayoutChild::set_child_rect(self, r)
+    }
+}
+
+impl<E> Failable for WrapPanel<'_, E> {
+    type Error = E;
+}
+
+impl<E> LayoutChild for WrapPanel<'_, E> {
+    fn set_child_loc(&mut self, p: Point) -> Result<(), LayoutError<Self::Error>> {
+        self.loc = p;
+        self.render()
+    }
+
+    fn set_child_size(&mut self, s: Size) -> Result<(), LayoutError<Self::Error>> {
+        self.size = s;
+        self.render()
+    }
+
+    fn set_child_rect(&mut self, r: Rect) -> Result<(), LayoutError<Self::Error>> {
+        self.loc = r.origin;
+        self.size = r.size;
+        self.render()
+    }
+
+    fn child_preferred_size(&self) -> Result<Size, LayoutError<Self::Error>> {
+        let (root, styles) = self.styles()?;
+        let (layout, _) = compute_layout(self.cache, root, styles, taffy::Size::max_content())?;
+        Ok(rect_t2e(&layout).size)
+    }
+
+    fn child_min_size(&self) -> Result<Size, LayoutError<Self::Error>> {
+        let (root, styles) = self.styles()?;
+        let (layout, _) = compute_layout(self.cache, root, styles, taffy::Size::min_content())?;
+        Ok(rect_t2e(&layout).size)
+    }
+}
@@ -1,16 +1,94 @@
-use crate::{Color, RelativePoint, RelativeSize};
+use std::f64::consts::TAU;
+
+use crate::{
+    Color, ColorOps, RelativePoint, RelativeSize, RelativeSpace, RelativeToLogical, Size, Vector,
+};
+
+/// How a brush fills the area outside its content, e.g., beyond the ends of
+/// a gradient or the bounds of an image.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum SpreadMode {
+    /// Extend the colors at the edges.
+    #[default]
+    Pad,
+    /// Repeat the content.
+    Repeat,
+    /// Repeat the content, mirrored alternately.
+    Reflect,
+}
+
+/// How the interior of a path is determined when it intersects itself.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum FillRule {
+    /// A point is inside if the path winds around it a non-zero number of
+    /// times.
+    #[default]
+    NonZero,
+    /// A point is inside if a ray from it crosses the path an odd number of
+    /// times.
+    EvenOdd,
+}
+
+/// How the colors of a layer are blended with the content below it.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum BlendMode {
+    /// Draw the layer over the content.
+    #[default]
+    Normal,
+    /// Multiply the colors, which darkens.
+    Multiply,
+    /// Invert, multiply and invert again, which lightens.
+    Screen,
+    /// Multiply or screen, depending on the color below.
+    Overlay,
+    /// Keep the darker color.
+    Darken,
+    /// Keep the lighter color.
+    Lighten,
+    /// Brighten the color below to reflect the layer.
+    ColorDodge,
+    /// Darken the color below to reflect the layer.
+    ColorBurn,
+    /// Multiply or screen, depending on the color of the layer.
+    HardLight,
+    /// Darken or lighten, depending on the color of the layer.
+    SoftLight,
+    /// The absolute difference of the colors.
+    Difference,
+    /// Like [`BlendMode::Difference`], but with lower contrast.
+    Exclusion,
+}
 
 /// Brush with single solid color.
 #[derive(Debug, Clone)]
 pub struct SolidColorBrush {
     /// The color of the brush.
     pub color: Color,
+    /// The opacity of the brush, in `[0, 1]`.
+    pub opacity: f64,
 }
 
 impl SolidColorBrush {
     /// Create [`SolidColorBrush`] with color.
     pub fn new(color: Color) -> Self {
-        Self { color }
+        Self {
+            color,
+            opacity: 1.0,
+        }
+    }
+
+    /// Set the opacity.
+    pub fn with_opacity(mut self, opacity: f64) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// The color with the opacity applied.
+    pub fn faded_color(&self) -> Color {
+        self.color.fade(self.opacity)
     }
 }
 
@@ -36,6 +114,37 @@ impl From<(Color, f64)> for GradientStop {
     }
 }
 
+/// The color of the gradient at a relative position. The stops should be
+/// sorted by the positions.
+fn gradient_color(stops: &[GradientStop], pos: f64) -> Color {
+    let Some(first) = stops.first() else {
+        return Color::new(0, 0, 0, 0);
+    };
+    if pos <= first.pos {
+        return first.color;
+    }
+    for w in stops.windows(2) {
+        let (a, b) = (w[0], w[1]);
+        if pos <= b.pos {
+            let t = if b.pos > a.pos {
+                (pos - a.pos) / (b.pos - a.pos)
+            } else {
+                1.0
+            };
+            return a.color.mix(&b.color, t);
+        }
+    }
+    stops[stops.len() - 1].color
+}
+
+/// The gradient stops with the opacity applied.
+fn faded_stops(stops: &[GradientStop], opacity: f64) -> Vec<GradientStop> {
+    stops
+        .iter()
+        .map(|stop| GradientStop::new(stop.color.fade(opacity), stop.pos))
+        .collect()
+}
+
 /// Linear gradient brush.
 #[derive(Debug, Clone)]
 pub struct LinearGradientBrush {
@@ -45,6 +154,10 @@ pub struct LinearGradientBrush {
     pub start: RelativePoint,
     /// The relative end position.
     pub end: RelativePoint,
+    /// How to fill beyond the start and end positions.
+    pub spread: SpreadMode,
+    /// The opacity of the brush, in `[0, 1]`.
+    pub opacity: f64,
 }
 
 impl LinearGradientBrush {
@@ -58,8 +171,27 @@ impl LinearGradientBrush {
             stops: stops.into_iter().collect(),
             start,
             end,
+            spread: SpreadMode::Pad,
+            opacity: 1.0,
         }
     }
+
+    /// Set the spread mode.
+    pub fn with_spread(mut self, spread: SpreadMode) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Set the opacity.
+    pub fn with_opacity(mut self, opacity: f64) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// The stops with the opacity applied.
+    pub fn faded_stops(&self) -> Vec<GradientStop> {
+        faded_stops(&self.stops, self.opacity)
+    }
 }
 
 /// Radial gradient brush.
@@ -73,6 +205,10 @@ pub struct RadialGradientBrush {
     pub center: RelativePoint,
     /// The relative radius.
     pub radius: RelativeSize,
+    /// How to fill beyond the radius.
+    pub spread: SpreadMode,
+    /// The opacity of the brush, in `[0, 1]`.
+    pub opacity: f64,
 }
 
 impl RadialGradientBrush {
@@ -88,8 +224,220 @@ impl RadialGradientBrush {
             origin,
             center,
             radius,
+            spread: SpreadMode::Pad,
+            opacity: 1.0,
+        }
+    }
+
+    /// Set the spread mode.
+    pub fn with_spread(mut self, spread: SpreadMode) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Set the opacity.
+    pub fn with_opacity(mut self, opacity: f64) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// The stops with the opacity applied.
+    pub fn faded_stops(&self) -> Vec<GradientStop> {
+        faded_stops(&self.stops, self.opacity)
+    }
+}
+
+/// Conic gradient brush, also known as sweep gradient. The colors change
+/// clockwise around the center.
+#[derive(Debug, Clone)]
+pub struct ConicGradientBrush {
+    /// The gradient stops, from 0 to 1 for a full turn.
+    pub stops: Vec<GradientStop>,
+    /// The relative center position.
+    pub center: RelativePoint,
+    /// The start angle in radians, clockwise from the positive x axis.
+    pub angle: f64,
+    /// The opacity of the brush, in `[0, 1]`.
+    pub opacity: f64,
+}
+
+impl ConicGradientBrush {
+    /// Create [`ConicGradientBrush`].
+    pub fn new(
+        stops: impl IntoIterator<Item = GradientStop>,
+        center: RelativePoint,
+        angle: f64,
+    ) -> Self {
+        Self {
+            stops: stops.into_iter().collect(),
+            center,
+            angle,
+            opacity: 1.0,
+        }
+    }
+
+    /// Set the opacity.
+    pub fn with_opacity(mut self, opacity: f64) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// The stops with the opacity applied.
+    pub fn faded_stops(&self) -> Vec<GradientStop> {
+        faded_stops(&self.stops, self.opacity)
+    }
+
+    /// The color at a relative position, with the opacity applied. It is used
+    /// to rasterize the gradient on the platforms without a native one.
+    pub fn color_at(&self, p: RelativePoint) -> Color {
+        let v = p - self.center;
+        let pos = ((v.y.atan2(v.x) - self.angle) / TAU).rem_euclid(1.0);
+        gradient_color(&self.stops, pos).fade(self.opacity)
+    }
+}
+
+/// Brush filling with an image, e.g., a
+/// [`DrawingImage`](https://docs.rs/winio/latest/winio/ui/struct.DrawingImage.html).
+#[derive(Debug, Clone)]
+pub struct ImageBrush<I> {
+    /// The image.
+    pub image: I,
+    /// The relative position of the top-left corner of the image.
+    pub origin: RelativePoint,
+    /// The relative size the image is stretched to.
+    pub size: RelativeSize,
+    /// How to fill beyond the image, e.g., [`SpreadMode::Repeat`] tiles it.
+    pub spread: SpreadMode,
+    /// The opacity of the brush, in `[0, 1]`.
+    pub opacity: f64,
+}
+
+impl<I> ImageBrush<I> {
+    /// Create [`ImageBrush`], which stretches the image to the whole area.
+    pub fn new(image: I) -> Self {
+        Self {
+            image,
+            origin: RelativePoint::zero(),
+            size: RelativeSize::new(1.0, 1.0),
+            spread: SpreadMode::Pad,
+            opacity: 1.0,
+        }
+    }
+
+    /// Place the image in a relative rectangle of the area.
+    pub fn with_rect(mut self, origin: RelativePoint, size: RelativeSize) -> Self {
+        self.origin = origin;
+        self.size = size;
+        self
+    }
+
+    /// Set the spread mode.
+    pub fn with_spread(mut self, spread: SpreadMode) -> Self {
+        self.spread = spread;
+        self
+    }
+
+    /// Set the opacity.
+    pub fn with_opacity(mut self, opacity: f64) -> Self {
+        self.opacity = opacity;
+        self
+    }
+
+    /// The transform from the pixels of an image with `image_size`, to the
+    /// logical space where the relative space is mapped by `trans`.
+    pub fn pixel_transform(&self, image_size: Size, trans: RelativeToLogical) -> RelativeToLogical {
+        RelativeToLogical::scale(
+            self.size.width / image_size.width.max(1.0),
+            self.size.height / image_size.height.max(1.0),
+        )
+        .then_translate(Vector::new(self.origin.x, self.origin.y))
+        .with_destination::<RelativeSpace>()
+        .then(&trans)
+    }
+}
+
+/// The shape at the ends of the open lines and the dashes.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LineCap {
+    /// The line ends at the end point.
+    #[default]
+    Butt,
+    /// A half circle beyond the end point.
+    Round,
+    /// A half square beyond the end point.
+    Square,
+}
+
+/// The shape at the corners of the lines.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum LineJoin {
+    /// Sharp corners, which fall back to [`LineJoin::Bevel`] when exceeding
+    /// the miter limit.
+    #[default]
+    Miter,
+    /// Rounded corners.
+    Round,
+    /// Cut-off corners.
+    Bevel,
+}
+
+/// The style of the outlines drawn by a pen.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrokeStyle {
+    /// The shape at the ends of the lines.
+    pub cap: LineCap,
+    /// The shape at the corners.
+    pub join: LineJoin,
+    /// The limit of the miter length relative to the pen width.
+    pub miter_limit: f64,
+    /// The lengths of the dashes and the gaps alternately, relative to the pen
+    /// width. The line is solid if it is empty.
+    pub dashes: Vec<f64>,
+    /// The offset into the dash pattern to start with, relative to the pen
+    /// width.
+    pub dash_offset: f64,
+}
+
+impl StrokeStyle {
+    /// Solid lines.
+    pub fn solid() -> Self {
+        Self {
+            cap: LineCap::Butt,
+            join: LineJoin::Miter,
+            miter_limit: 10.0,
+            dashes: vec![],
+            dash_offset: 0.0,
+        }
+    }
+
+    /// Dashed lines.
+    pub fn dashed() -> Self {
+        Self {
+            dashes: vec![4.0, 2.0],
+            ..Self::solid()
         }
     }
+
+    /// Dotted lines.
+    pub fn dotted() -> Self {
+        Self {
+            dashes: vec![1.0, 2.0],
+            ..Self::solid()
+        }
+    }
+
+    /// If the lines are dashed.
+    pub fn is_dashed(&self) -> bool {
+        self.dashes.iter().any(|d| *d > 0.0)
+    }
+}
+
+impl Default for StrokeStyle {
+    fn default() -> Self {
+        Self::solid()
+    }
 }
 
 /// Pen with specified brush.
@@ -99,11 +447,23 @@ pub struct BrushPen<B> {
     pub brush: B,
     /// The width of the pen.
     pub width: f64,
+    /// The stroke style.
+    pub stroke: StrokeStyle,
 }
 
 impl<B> BrushPen<B> {
     /// Create [`BrushPen`] with brush and pen width.
     pub fn new(brush: B, width: f64) -> Self {
-        Self { brush, width }
+        Self {
+            brush,
+            width,
+            stroke: StrokeStyle::default(),
+        }
+    }
+
+    /// Set the stroke style.
+    pub fn with_stroke(mut self, stroke: StrokeStyle) -> Self {
+        self.stroke = stroke;
+        self
     }
 }
@@ -0,0 +1,784 @@
+use std::fmt::Display;
+
+use crate::{Color, ColorTheme};
+
+/// Error can be returned when parsing [`Color`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseColorError {
+    /// Invalid hex notation, e.g., wrong digit count.
+    InvalidHex(String),
+    /// Invalid `rgb()` or `hsl()` function syntax.
+    InvalidFunction(String),
+    /// Unknown color name.
+    UnknownName(String),
+}
+
+impl Display for ParseColorError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidHex(s) => write!(f, "invalid hex color: {s}"),
+            Self::InvalidFunction(s) => write!(f, "invalid color function: {s}"),
+            Self::UnknownName(s) => write!(f, "unknown color name: {s}"),
+        }
+    }
+}
+
+impl std::error::Error for ParseColorError {}
+
+/// Color in HSL space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsl {
+    /// Hue in degrees, in `[0, 360)`.
+    pub h: f64,
+    /// Saturation, in `[0, 1]`.
+    pub s: f64,
+    /// Lightness, in `[0, 1]`.
+    pub l: f64,
+    /// Alpha, in `[0, 1]`.
+    pub a: f64,
+}
+
+impl Hsl {
+    /// Create [`Hsl`].
+    pub fn new(h: f64, s: f64, l: f64, a: f64) -> Self {
+        Self { h, s, l, a }
+    }
+}
+
+/// Color in HSV space.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Hsv {
+    /// Hue in degrees, in `[0, 360)`.
+    pub h: f64,
+    /// Saturation, in `[0, 1]`.
+    pub s: f64,
+    /// Value, in `[0, 1]`.
+    pub v: f64,
+    /// Alpha, in `[0, 1]`.
+    pub a: f64,
+}
+
+impl Hsv {
+    /// Create [`Hsv`].
+    pub fn new(h: f64, s: f64, v: f64, a: f64) -> Self {
+        Self { h, s, v, a }
+    }
+}
+
+/// Color in OKLCH space, the polar form of OKLab.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Oklch {
+    /// Perceptual lightness, in `[0, 1]`.
+    pub l: f64,
+    /// Chroma, usually not greater than `0.4`.
+    pub c: f64,
+    /// Hue in degrees, in `[0, 360)`.
+    pub h: f64,
+    /// Alpha, in `[0, 1]`.
+    pub a: f64,
+}
+
+impl Oklch {
+    /// Create [`Oklch`].
+    pub fn new(l: f64, c: f64, h: f64, a: f64) -> Self {
+        Self { l, c, h, a }
+    }
+}
+
+fn to_unit(c: u8) -> f64 {
+    c as f64 / 255.0
+}
+
+fn from_unit(c: f64) -> u8 {
+    (c.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+fn normalize_hue(h: f64) -> f64 {
+    let h = h.rem_euclid(360.0);
+    if h.is_finite() { h } else { 0.0 }
+}
+
+/// Returns the hue, the max and the min components.
+fn hue_max_min(c: Color) -> (f64, f64, f64) {
+    let (r, g, b) = (to_unit(c.r), to_unit(c.g), to_unit(c.b));
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let d = max - min;
+    let h = if d == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / d)
+    } else if max == g {
+        60.0 * ((b - r) / d + 2.0)
+    } else {
+        60.0 * ((r - g) / d + 4.0)
+    };
+    (normalize_hue(h), max, min)
+}
+
+/// Build a color from the hue, chroma and the offset added to all components.
+fn from_hue_chroma(h: f64, chroma: f64, m: f64, a: f64) -> Color {
+    let h = normalize_hue(h) / 60.0;
+    let x = chroma * (1.0 - (h % 2.0 - 1.0).abs());
+    let (r, g, b) = match h as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    Color::new(
+        from_unit(r + m),
+        from_unit(g + m),
+        from_unit(b + m),
+        from_unit(a),
+    )
+}
+
+impl From<Color> for Hsl {
+    fn from(c: Color) -> Self {
+        let (h, max, min) = hue_max_min(c);
+        let l = (max + min) / 2.0;
+        let s = if max == min {
+            0.0
+        } else {
+            (max - min) / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        Self::new(h, s, l, to_unit(c.a))
+    }
+}
+
+impl From<Hsl> for Color {
+    fn from(c: Hsl) -> Self {
+        let s = c.s.clamp(0.0, 1.0);
+        let l = c.l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        from_hue_chroma(c.h, chroma, l - chroma / 2.0, c.a)
+    }
+}
+
+impl From<Color> for Hsv {
+    fn from(c: Color) -> Self {
+        let (h, max, min) = hue_max_min(c);
+        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Self::new(h, s, max, to_unit(c.a))
+    }
+}
+
+impl From<Hsv> for Color {
+    fn from(c: Hsv) -> Self {
+        let s = c.s.clamp(0.0, 1.0);
+        let v = c.v.clamp(0.0, 1.0);
+        let chroma = v * s;
+        from_hue_chroma(c.h, chroma, v - chroma, c.a)
+    }
+}
+
+fn srgb_to_linear(c: u8) -> f64 {
+    let c = to_unit(c);
+    if c <= 0.04045 {
+        c / 12.92
+    } else {
+        ((c + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+fn linear_to_srgb(c: f64) -> u8 {
+    let c = if c <= 0.0031308 {
+        c * 12.92
+    } else {
+        1.055 * c.powf(1.0 / 2.4) - 0.055
+    };
+    from_unit(c)
+}
+
+impl From<Color> for Oklch {
+    fn from(c: Color) -> Self {
+        let (r, g, b) = (
+            srgb_to_linear(c.r),
+            srgb_to_linear(c.g),
+            srgb_to_linear(c.b),
+        );
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+        let lab_l = 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s;
+        let lab_a = 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s;
+        let lab_b = 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s;
+        let chroma = lab_a.hypot(lab_b);
+        // The hue of grays is meaningless and unstable.
+        let h = if chroma < 1e-6 {
+            0.0
+        } else {
+            normalize_hue(lab_b.atan2(lab_a).to_degrees())
+        };
+        Self::new(lab_l, chroma, h, to_unit(c.a))
+    }
+}
+
+impl From<Oklch> for Color {
+    fn from(c: Oklch) -> Self {
+        let (sin, cos) = c.h.to_radians().sin_cos();
+        let (lab_a, lab_b) = (c.c * cos, c.c * sin);
+        let l = (c.l + 0.3963377774 * lab_a + 0.2158037573 * lab_b).powi(3);
+        let m = (c.l - 0.1055613458 * lab_a - 0.0638541728 * lab_b).powi(3);
+        let s = (c.l - 0.0894841775 * lab_a - 1.2914855480 * lab_b).powi(3);
+        let r = 4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s;
+        let g = -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s;
+        let b = -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s;
+        Color::new(
+            linear_to_srgb(r),
+            linear_to_srgb(g),
+            linear_to_srgb(b),
+            from_unit(c.a),
+        )
+    }
+}
+
+/// Parsing, formatting and manipulating of [`Color`].
+pub trait ColorOps: Sized {
+    /// Parse a CSS color string. Supported forms are `#rgb`, `#rgba`,
+    /// `#rrggbb`, `#rrggbbaa`, `rgb()`, `rgba()`, `hsl()`, `hsla()` and the
+    /// CSS named colors, including `transparent`. Components out of range,
+    /// e.g., `rgb(300, 0, 0)`, are rejected instead of clamped.
+    fn parse(s: &str) -> Result<Self, ParseColorError>;
+
+    /// Format as `#rrggbb`, or `#rrggbbaa` if the color is not opaque.
+    fn to_hex(&self) -> String;
+
+    /// The relative luminance defined by WCAG, in `[0, 1]`. The alpha is
+    /// ignored.
+    fn luminance(&self) -> f64;
+
+    /// The contrast ratio defined by WCAG, in `[1, 21]`.
+    fn contrast_ratio(&self, other: &Self) -> f64 {
+        let l1 = self.luminance();
+        let l2 = other.luminance();
+        (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05)
+    }
+
+    /// If the color is perceived as dark, e.g., a background that needs
+    /// light text.
+    fn is_dark(&self) -> bool {
+        self.luminance() < 0.18
+    }
+
+    /// Increase the HSL lightness by `amount`, in `[0, 1]`.
+    fn lighten(&self, amount: f64) -> Self;
+
+    /// Decrease the HSL lightness by `amount`, in `[0, 1]`.
+    fn darken(&self, amount: f64) -> Self {
+        self.lighten(-amount)
+    }
+
+    /// Mix with another color in sRGB space, including the alpha. `t = 0`
+    /// returns `self`, and `t = 1` returns `other`.
+    fn mix(&self, other: &Self, t: f64) -> Self;
+
+    /// Replace the alpha, in `[0, 1]`.
+    fn with_alpha(&self, a: f64) -> Self;
+
+    /// Multiply the alpha by `opacity`, in `[0, 1]`.
+    fn fade(&self, opacity: f64) -> Self;
+}
+
+impl ColorOps for Color {
+    fn parse(s: &str) -> Result<Self, ParseColorError> {
+        let s = s.trim();
+        if let Some(hex) = s.strip_prefix('#') {
+            return parse_hex(hex).ok_or_else(|| ParseColorError::InvalidHex(s.to_string()));
+        }
+        let lower = s.to_ascii_lowercase();
+        if let Some((name, args)) = lower.split_once('(') {
+            return args
+                .strip_suffix(')')
+                .and_then(|args| parse_function(name.trim_end(), args))
+                .ok_or_else(|| ParseColorError::InvalidFunction(s.to_string()));
+        }
+        NAMED_COLORS
+            .binary_search_by_key(&lower.as_str(), |(name, _)| *name)
+            .map(|i| {
+                let rgb = NAMED_COLORS[i].1;
+                Color::new((rgb >> 16) as u8, (rgb >> 8) as u8, rgb as u8, 255)
+            })
+            .or_else(|_| {
+                if lower == "transparent" {
+                    Ok(Color::new(0, 0, 0, 0))
+                } else {
+                    Err(ParseColorError::UnknownName(s.to_string()))
+                }
+            })
+    }
+
+    fn to_hex(&self) -> String {
+        if self.a == 255 {
+            format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+        } else {
+            format!("#{:02x}{:02x}{:02x}{:02x}", self.r, self.g, self.b, self.a)
+        }
+    }
+
+    fn luminance(&self) -> f64 {
+        0.2126 * srgb_to_linear(self.r)
+            + 0.7152 * srgb_to_linear(self.g)
+            + 0.0722 * srgb_to_linear(self.b)
+    }
+
+    fn lighten(&self, amount: f64) -> Self {
+        let mut hsl = Hsl::from(*self);
+        hsl.l = (hsl.l + amount).clamp(0.0, 1.0);
+        // Keep the alpha exact.
+        Color {
+            a: self.a,
+            ..hsl.into()
+        }
+    }
+
+    fn mix(&self, other: &Self, t: f64) -> Self {
+        let t = t.clamp(0.0, 1.0);
+        let lerp = |a: u8, b: u8| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+        Color::new(
+            lerp(self.r, other.r),
+            lerp(self.g, other.g),
+            lerp(self.b, other.b),
+            lerp(self.a, other.a),
+        )
+    }
+
+    fn with_alpha(&self, a: f64) -> Self {
+        Color {
+            a: from_unit(a),
+            ..*self
+        }
+    }
+
+    fn fade(&self, opacity: f64) -> Self {
+        ColorOps::with_alpha(self, self.a as f64 / 255.0 * opacity)
+    }
+}
+
+/// Colors of the system theme, used to draw custom widgets blending in with
+/// the native ones.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct SystemPalette {
+    /// Background of windows.
+    pub window: Color,
+    /// Foreground text on windows.
+    pub window_text: Color,
+    /// Background of the selected or highlighted items.
+    pub highlight: Color,
+    /// Text of the selected or highlighted items.
+    pub highlight_text: Color,
+    /// Text of the disabled widgets.
+    pub disabled_text: Color,
+    /// Borders of the widgets.
+    pub border: Color,
+    /// Background of tooltips.
+    pub tooltip: Color,
+    /// Text of tooltips.
+    pub tooltip_text: Color,
+}
+
+impl SystemPalette {
+    /// Derive a palette from the color theme and the accent color, for the
+    /// platforms without system colors.
+    pub fn from_theme(theme: ColorTheme, accent: Color) -> Self {
+        let (window, window_text) = match theme {
+            ColorTheme::Dark => (Color::new(32, 32, 32, 255), Color::new(255, 255, 255, 255)),
+            _ => (Color::new(243, 243, 243, 255), Color::new(0, 0, 0, 255)),
+        };
+        let highlight_text = if accent.is_dark() {
+            Color::new(255, 255, 255, 255)
+        } else {
+            Color::new(0, 0, 0, 255)
+        };
+        Self {
+            window,
+            window_text,
+            highlight: accent,
+            highlight_text,
+            disabled_text: window_text.mix(&window, 0.6),
+            border: window_text.mix(&window, 0.8),
+            // Tooltips are shown in the inverted colors.
+            tooltip: window_text.mix(&window, 0.15),
+            tooltip_text: window,
+        }
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<Color> {
+    if !hex.is_ascii() {
+        return None;
+    }
+    let digit = |i: usize, len: usize| u8::from_str_radix(&hex[i * len..(i + 1) * len], 16).ok();
+    let (len, count) = match hex.len() {
+        3 => (1, 3),
+        4 => (1, 4),
+        6 => (2, 3),
+        8 => (2, 4),
+        _ => return None,
+    };
+    let mut c = [255u8; 4];
+    for (i, c) in c.iter_mut().enumerate().take(count) {
+        let d = digit(i, len)?;
+        // Short notation duplicates each digit.
+        *c = if len == 1 { d * 17 } else { d };
+    }
+    Some(Color::new(c[0], c[1], c[2], c[3]))
+}
+
+/// Split the arguments by commas, or by spaces with an optional `/` before
+/// the alpha.
+fn split_args(args: &str) -> Vec<&str> {
+    if args.contains(',') {
+        args.split(',').map(str::trim).collect()
+    } else {
+        args.split(|c: char| c.is_ascii_whitespace() || c == '/')
+            .filter(|s| !s.is_empty())
+            .collect()
+    }
+}
+
+/// Parse a number, or a percentage mapped to `[0, scale]`. Values out of
+/// `[0, scale]` are rejected.
+fn parse_number(s: &str, scale: f64) -> Option<f64> {
+    let n = match s.strip_suffix('%') {
+        Some(p) => p.trim().parse::<f64>().ok()? / 100.0 * scale,
+        None => s.parse().ok()?,
+    };
+    (0.0..=scale).contains(&n).then_some(n)
+}
+
+fn parse_alpha(args: &[&str]) -> Option<f64> {
+    match args.get(3) {
+        Some(a) => parse_number(a, 1.0),
+        None => Some(1.0),
+    }
+}
+
+fn parse_function(name: &str, args: &str) -> Option<Color> {
+    let args = split_args(args);
+    if !(3..=4).contains(&args.len()) {
+        return None;
+    }
+    let a = parse_alpha(&args)?;
+    match name {
+        "rgb" | "rgba" => {
+            let r = parse_number(args[0], 255.0)?;
+            let g = parse_number(args[1], 255.0)?;
+            let b = parse_number(args[2], 255.0)?;
+            Some(Color::new(
+                from_unit(r / 255.0),
+                from_unit(g / 255.0),
+                from_unit(b / 255.0),
+                from_unit(a),
+            ))
+        }
+        "hsl" | "hsla" => {
+            let h = args[0]
+                .strip_suffix("deg")
+                .unwrap_or(args[0])
+                .parse()
+                .ok()?;
+            // The percent signs of saturation and lightness are optional.
+            let s = parse_number(args[1].trim_end_matches('%'), 100.0)? / 100.0;
+            let l = parse_number(args[2].trim_end_matches('%'), 100.0)? / 100.0;
+            Some(Hsl::new(h, s, l, a).into())
+        }
+        _ => None,
+    }
+}
+
+/// CSS named colors, sorted by name.
+const NAMED_COLORS: &[(&str, u32)] = &[
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(r: u8, g: u8, b: u8, a: u8) -> Color {
+        Color::new(r, g, b, a)
+    }
+
+    #[test]
+    fn parse_hex() {
+        assert_eq!(Color::parse("#f80"), Ok(rgba(255, 136, 0, 255)));
+        assert_eq!(Color::parse("#f808"), Ok(rgba(255, 136, 0, 136)));
+        assert_eq!(Color::parse("#12ab3c"), Ok(rgba(0x12, 0xab, 0x3c, 255)));
+        assert_eq!(Color::parse("#12AB3C80"), Ok(rgba(0x12, 0xab, 0x3c, 0x80)));
+        assert_eq!(
+            Color::parse("#12"),
+            Err(ParseColorError::InvalidHex("#12".to_string()))
+        );
+        assert!(Color::parse("#12345g").is_err());
+    }
+
+    #[test]
+    fn parse_function() {
+        assert_eq!(Color::parse("rgb(255, 0, 128)"), Ok(rgba(255, 0, 128, 255)));
+        assert_eq!(Color::parse("rgba(0, 0, 0, 0.5)"), Ok(rgba(0, 0, 0, 128)));
+        assert_eq!(
+            Color::parse("rgb(100% 0% 50% / 50%)"),
+            Ok(rgba(255, 0, 128, 128))
+        );
+        assert_eq!(Color::parse("hsl(0, 100%, 50%)"), Ok(rgba(255, 0, 0, 255)));
+        assert_eq!(
+            Color::parse("hsl(120deg 100% 25%)"),
+            Ok(rgba(0, 128, 0, 255))
+        );
+        assert_eq!(
+            Color::parse("HSLA(240, 100, 50, 1)"),
+            Ok(rgba(0, 0, 255, 255))
+        );
+        assert_eq!(
+            Color::parse("rgb(300,0,0)"),
+            Err(ParseColorError::InvalidFunction("rgb(300,0,0)".to_string()))
+        );
+        assert!(Color::parse("rgb(0, 0, 0, 2)").is_err());
+        assert!(Color::parse("hsl(0, 150%, 50%)").is_err());
+        assert!(Color::parse("rgb(0, 0)").is_err());
+        assert!(Color::parse("rgb(0, 0, 0").is_err());
+        assert!(Color::parse("cmyk(0, 0, 0, 0)").is_err());
+    }
+
+    #[test]
+    fn parse_named() {
+        assert_eq!(Color::parse("red"), Ok(rgba(255, 0, 0, 255)));
+        assert_eq!(Color::parse(" RebeccaPurple "), Ok(rgba(102, 51, 153, 255)));
+        assert_eq!(Color::parse("transparent"), Ok(rgba(0, 0, 0, 0)));
+        assert_eq!(
+            Color::parse("nocolor"),
+            Err(ParseColorError::UnknownName("nocolor".to_string()))
+        );
+        assert!(NAMED_COLORS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+
+    #[test]
+    fn hex_round_trip() {
+        for c in [
+            rgba(0, 0, 0, 255),
+            rgba(0x12, 0xab, 0x3c, 255),
+            rgba(255, 255, 255, 0),
+            rgba(1, 2, 3, 4),
+        ] {
+            assert_eq!(Color::parse(&c.to_hex()), Ok(c));
+        }
+        assert_eq!(rgba(0x12, 0xab, 0x3c, 255).to_hex(), "#12ab3c");
+        assert_eq!(rgba(0x12, 0xab, 0x3c, 0x80).to_hex(), "#12ab3c80");
+    }
+
+    #[test]
+    fn space_round_trip() {
+        for c in [
+            rgba(0, 0, 0, 255),
+            rgba(255, 255, 255, 255),
+            rgba(255, 0, 0, 255),
+            rgba(18, 171, 60, 128),
+            rgba(102, 51, 153, 0),
+            rgba(200, 200, 10, 255),
+        ] {
+            assert_eq!(Color::from(Hsl::from(c)), c);
+            assert_eq!(Color::from(Hsv::from(c)), c);
+            assert_eq!(Color::from(Oklch::from(c)), c);
+        }
+        let hsl = Hsl::from(rgba(255, 0, 0, 255));
+        assert_eq!((hsl.h, hsl.s, hsl.l, hsl.a), (0.0, 1.0, 0.5, 1.0));
+        let hsv = Hsv::from(rgba(0, 0, 255, 255));
+        assert_eq!((hsv.h, hsv.s, hsv.v), (240.0, 1.0, 1.0));
+        let oklch = Oklch::from(rgba(255, 255, 255, 255));
+        assert!((oklch.l - 1.0).abs() < 1e-6 && oklch.c < 1e-6);
+    }
+
+    #[test]
+    fn contrast() {
+        let black = rgba(0, 0, 0, 255);
+        let white = rgba(255, 255, 255, 255);
+        assert!((black.contrast_ratio(&white) - 21.0).abs() < 1e-9);
+        assert!((white.contrast_ratio(&black) - 21.0).abs() < 1e-9);
+        assert_eq!(white.contrast_ratio(&white), 1.0);
+        assert!(black.is_dark());
+        assert!(!white.is_dark());
+    }
+
+    #[test]
+    fn mix() {
+        let black = rgba(0, 0, 0, 0);
+        let white = rgba(255, 255, 255, 255);
+        assert_eq!(black.mix(&white, 0.0), black);
+        assert_eq!(black.mix(&white, 1.0), white);
+        assert_eq!(black.mix(&white, 0.5), rgba(128, 128, 128, 128));
+        // `t` is clamped.
+        assert_eq!(black.mix(&white, 2.0), white);
+        assert_eq!(black.mix(&white, -1.0), black);
+    }
+
+    #[test]
+    fn lighten_darken() {
+        let red = rgba(255, 0, 0, 100);
+        assert_eq!(red.lighten(0.25), rgba(255, 128, 128, 100));
+        assert_eq!(red.darken(0.25), rgba(128, 0, 0, 100));
+        // The lightness is clamped.
+        assert_eq!(red.lighten(2.0), rgba(255, 255, 255, 100));
+        assert_eq!(red.darken(2.0), rgba(0, 0, 0, 100));
+    }
+
+    #[test]
+    fn alpha() {
+        let c = rgba(1, 2, 3, 255);
+        assert_eq!(ColorOps::with_alpha(&c, 0.5), rgba(1, 2, 3, 128));
+        assert_eq!(ColorOps::with_alpha(&c, 2.0), c);
+        assert_eq!(c.fade(0.5).fade(0.5), rgba(1, 2, 3, 64));
+    }
+}
@@ -55,6 +55,16 @@ pub enum Orient {
     Vertical,
 }
 
+/// Layout direction.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub enum LayoutDirection {
+    /// Left to right.
+    #[default]
+    LeftToRight,
+    /// Right to left, for languages like Arabic and Hebrew.
+    RightToLeft,
+}
+
 /// Horizontal alignment.
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 pub enum HAlign {
@@ -79,22 +89,138 @@ pub enum VAlign {
     Bottom,
     /// Fill the vertical space.
     Stretch,
+    /// Align the first baseline with the other baseline-aligned widgets in
+    /// the same row. Only honored by `Grid` and horizontal `StackPanel`, and
+    /// treated as [`VAlign::Top`] elsewhere.
+    Baseline,
 }
 
 /// Color type.
 pub type Color = RGBA8;
 
+/// Weight of a font, from 100 (thin) to 900 (black) as in CSS.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct FontWeight(pub u16);
+
+impl FontWeight {
+    /// Black, 900.
+    pub const BLACK: Self = Self(900);
+    /// Bold, 700.
+    pub const BOLD: Self = Self(700);
+    /// Extra bold, 800.
+    pub const EXTRA_BOLD: Self = Self(800);
+    /// Extra light, 200.
+    pub const EXTRA_LIGHT: Self = Self(200);
+    /// Light, 300.
+    pub const LIGHT: Self = Self(300);
+    /// Medium, 500.
+    pub const MEDIUM: Self = Self(500);
+    /// Normal, 400.
+    pub const NORMAL: Self = Self(400);
+    /// Semi bold, 600.
+    pub const SEMI_BOLD: Self = Self(600);
+    /// Thin, 100.
+    pub const THIN: Self = Self(100);
+
+    /// If the weight is treated as bold on the platforms only supporting
+    /// bold or not.
+    pub fn is_bold(&self) -> bool {
+        self.0 >= 600
+    }
+}
+
+impl Default for FontWeight {
+    fn default() -> Self {
+        Self::NORMAL
+    }
+}
+
+/// Width of a font face.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum FontStretch {
+    /// 50%.
+    UltraCondensed,
+    /// 62.5%.
+    ExtraCondensed,
+    /// 75%.
+    Condensed,
+    /// 87.5%.
+    SemiCondensed,
+    /// 100%.
+    #[default]
+    Normal,
+    /// 112.5%.
+    SemiExpanded,
+    /// 125%.
+    Expanded,
+    /// 150%.
+    ExtraExpanded,
+    /// 200%.
+    UltraExpanded,
+}
+
+impl FontStretch {
+    /// The width relative to the normal one, in percent.
+    pub fn percent(&self) -> f64 {
+        match self {
+            Self::UltraCondensed => 50.0,
+            Self::ExtraCondensed => 62.5,
+            Self::Condensed => 75.0,
+            Self::SemiCondensed => 87.5,
+            Self::Normal => 100.0,
+            Self::SemiExpanded => 112.5,
+            Self::Expanded => 125.0,
+            Self::ExtraExpanded => 150.0,
+            Self::UltraExpanded => 200.0,
+        }
+    }
+
+    /// The nearest stretch of the percent.
+    pub fn from_percent(p: f64) -> Self {
+        [
+            Self::UltraCondensed,
+            Self::ExtraCondensed,
+            Self::Condensed,
+            Self::SemiCondensed,
+            Self::Normal,
+            Self::SemiExpanded,
+            Self::Expanded,
+            Self::ExtraExpanded,
+            Self::UltraExpanded,
+        ]
+        .into_iter()
+        .min_by(|a, b| (a.percent() - p).abs().total_cmp(&(b.percent() - p).abs()))
+        .unwrap_or_default()
+    }
+}
+
 /// Font for widgets.
+///
+/// ## Platform specific
+/// * GTK & Qt: All the fields are supported.
+/// * Others: The fallback families and the letter spacing are ignored, and the
+///   weight, the stretch and the decorations are partially supported.
 #[derive(Debug, Clone, PartialEq, Default)]
 pub struct Font {
     /// Font name.
     pub family: String,
+    /// Fallback font names, used in order for the characters not found in
+    /// [`Font::family`].
+    pub fallback: Vec<String>,
     /// Font size.
     pub size: f64,
     /// *Italic*.
     pub italic: bool,
-    /// **Bold**.
-    pub bold: bool,
+    /// Font weight.
+    pub weight: FontWeight,
+    /// Font stretch.
+    pub stretch: FontStretch,
+    /// <u>Underline</u>.
+    pub underline: bool,
+    /// ~~Strikethrough~~.
+    pub strikethrough: bool,
+    /// Extra spacing between the letters, in logical pixels.
+    pub letter_spacing: f64,
 }
 
 impl Font {
@@ -102,6 +228,23 @@ impl Font {
     pub fn builder() -> FontBuilder {
         FontBuilder::new()
     }
+
+    /// **Bold**, the weight is not less than [`FontWeight::SEMI_BOLD`].
+    #[deprecated = "use `weight` instead"]
+    pub fn bold(&self) -> bool {
+        self.weight.is_bold()
+    }
+
+    /// Set **bold**. It sets the weight to [`FontWeight::BOLD`] or
+    /// [`FontWeight::NORMAL`].
+    #[deprecated = "use `weight` instead"]
+    pub fn set_bold(&mut self, v: bool) {
+        self.weight = if v {
+            FontWeight::BOLD
+        } else {
+            FontWeight::NORMAL
+        };
+    }
 }
 
 /// Builder of [`Font`].
@@ -114,10 +257,8 @@ impl Default for FontBuilder {
     fn default() -> Self {
         Self {
             value: Font {
-                family: String::new(),
                 size: 0.1,
-                italic: false,
-                bold: false,
+                ..Default::default()
             },
         }
     }
@@ -135,6 +276,15 @@ impl FontBuilder {
         self
     }
 
+    /// Fallback font names.
+    pub fn fallback(mut self, families: impl IntoIterator<Item = impl AsRef<str>>) -> Self {
+        self.value.fallback = families
+            .into_iter()
+            .map(|s| s.as_ref().to_string())
+            .collect();
+        self
+    }
+
     /// Font size.
     pub fn size(mut self, s: f64) -> Self {
         self.value.size = s;
@@ -147,9 +297,44 @@ impl FontBuilder {
         self
     }
 
-    /// **Bold**.
+    /// **Bold**. It sets the weight to [`FontWeight::BOLD`] or
+    /// [`FontWeight::NORMAL`].
     pub fn bold(mut self, v: bool) -> Self {
-        self.value.bold = v;
+        self.value.weight = if v {
+            FontWeight::BOLD
+        } else {
+            FontWeight::NORMAL
+        };
+        self
+    }
+
+    /// Font weight.
+    pub fn weight(mut self, v: FontWeight) -> Self {
+        self.value.weight = v;
+        self
+    }
+
+    /// Font stretch.
+    pub fn stretch(mut self, v: FontStretch) -> Self {
+        self.value.stretch = v;
+        self
+    }
+
+    /// <u>Underline</u>.
+    pub fn underline(mut self, v: bool) -> Self {
+        self.value.underline = v;
+        self
+    }
+
+    /// ~~Strikethrough~~.
+    pub fn strikethrough(mut self, v: bool) -> Self {
+        self.value.strikethrough = v;
+        self
+    }
+
+    /// Extra spacing between the letters.
+    pub fn letter_spacing(mut self, v: f64) -> Self {
+        self.value.letter_spacing = v;
         self
     }
 
@@ -172,6 +357,64 @@ pub enum MouseButton {
     Other,
 }
 
+bitflags::bitflags! {
+    /// The keyboard modifiers held during a mouse event.
+    #[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+    pub struct Modifiers: u32 {
+        /// Shift.
+        const Shift   = 1 << 0;
+        /// Control.
+        const Control = 1 << 1;
+        /// Alt, or Option on macOS.
+        const Alt     = 1 << 2;
+        /// Windows, Super, or Command on macOS.
+        const Meta    = 1 << 3;
+    }
+}
+
+/// A mouse button event with the state when it happens.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MouseButtonEvent {
+    /// The button.
+    pub button: MouseButton,
+    /// The position relative to the widget.
+    pub pos: Point,
+    /// The keyboard modifiers.
+    pub modifiers: Modifiers,
+    /// The click count of the press: 1 for a single click, 2 for a double
+    /// click, and so on. The release event has the count of its press.
+    pub clicks: u32,
+}
+
+/// Shape of the mouse cursor.
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy, Hash)]
+#[non_exhaustive]
+pub enum Cursor {
+    /// The default arrow.
+    #[default]
+    Arrow,
+    /// The I-beam for text.
+    IBeam,
+    /// The pointing hand for links.
+    Hand,
+    /// The crosshair for precise selection.
+    Crosshair,
+    /// The program is busy.
+    Wait,
+    /// Something can be moved.
+    Move,
+    /// The action is not allowed.
+    NotAllowed,
+    /// Resize horizontally, west-east.
+    ResizeHorizontal,
+    /// Resize vertically, north-south.
+    ResizeVertical,
+    /// Resize diagonally, northwest-southeast.
+    ResizeNwse,
+    /// Resize diagonally, northeast-southwest.
+    ResizeNesw,
+}
+
 /// Tick position choices.
 #[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
 pub enum TickPosition {
@@ -9,11 +9,17 @@ pub use traits::*;
 mod drawing;
 pub use drawing::*;
 
+mod color;
+pub use color::*;
+
 mod monitor;
 pub use monitor::*;
 
 mod canvas;
 pub use canvas::*;
 
+mod text;
+pub use text::*;
+
 mod msgbox;
 pub use msgbox::*;
@@ -0,0 +1,99 @@
+use std::ops::Range;
+
+use crate::{Color, Font, HAlign, Rect};
+
+/// A styled range of the text in a text layout.
+///
+/// The properties left `None` are inherited from the layout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextSpan {
+    /// The byte range in the text.
+    pub range: Range<usize>,
+    /// The font of the range.
+    pub font: Option<Font>,
+    /// The color of the range.
+    pub color: Option<Color>,
+    /// <u>Underline</u>.
+    pub underline: Option<bool>,
+}
+
+impl TextSpan {
+    /// Create [`TextSpan`] with nothing overridden.
+    pub fn new(range: Range<usize>) -> Self {
+        Self {
+            range,
+            font: None,
+            color: None,
+            underline: None,
+        }
+    }
+
+    /// Set the font of the range.
+    pub fn with_font(mut self, font: Font) -> Self {
+        self.font = Some(font);
+        self
+    }
+
+    /// Set the color of the range.
+    pub fn with_color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Set the underline of the range.
+    pub fn with_underline(mut self, v: bool) -> Self {
+        self.underline = Some(v);
+        self
+    }
+}
+
+/// The paragraph options of a text layout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct TextFormat {
+    /// The max width of the lines. The text is wrapped at the word boundaries
+    /// if it is wider. The text is not wrapped if `None`.
+    pub max_width: Option<f64>,
+    /// The horizontal alignment of the lines. [`HAlign::Stretch`] justifies
+    /// the lines except the last one.
+    pub align: HAlign,
+    /// The line height relative to the natural height of the lines.
+    pub line_spacing: f64,
+    /// The max count of the lines. The rest of the text is trimmed.
+    pub max_lines: Option<usize>,
+    /// Trim the text with an ellipsis at the end of the last line, if it
+    /// doesn't fit in [`TextFormat::max_lines`].
+    pub ellipsis: bool,
+}
+
+impl Default for TextFormat {
+    fn default() -> Self {
+        Self {
+            max_width: None,
+            align: HAlign::Left,
+            line_spacing: 1.0,
+            max_lines: None,
+            ellipsis: false,
+        }
+    }
+}
+
+/// The metrics of a line in a text layout.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LineMetrics {
+    /// The byte range of the line in the text, including the trailing
+    /// whitespaces and line breaks.
+    pub range: Range<usize>,
+    /// The bounds of the line, relative to the origin of the layout.
+    pub rect: Rect,
+    /// The position of the baseline, relative to the top of the layout.
+    pub baseline: f64,
+}
+
+/// The result of hit testing a point on a text layout.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TextHitTest {
+    /// The byte index of the caret nearest to the point.
+    pub index: usize,
+    /// If the point is inside the text.
+    pub inside: bool,
+}
@@ -1,4 +1,6 @@
-use crate::{Font, Point, Rect, Size};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use crate::{Font, FontWeight, Point, Rect, Size};
 
 /// A trait for types that can fail with an associated error type.
 pub trait Failable {
@@ -101,6 +103,48 @@ pub trait Layoutable: Failable {
     fn min_size(&self) -> Result<Size, Self::Error> {
         self.preferred_size()
     }
+
+    /// If the widget is visible to the layout containers. The hidden widgets
+    /// could be collapsed by the containers.
+    ///
+    /// The widgets implementing [`Visible`] usually forward it with
+    /// [`impl_layout_visible!`](crate::impl_layout_visible).
+    fn is_layout_visible(&self) -> Result<bool, Self::Error> {
+        Ok(true)
+    }
+
+    /// The offset of the first text baseline from the top, if the widget
+    /// shows text. It is used by [`VAlign::Baseline`](crate::VAlign).
+    fn baseline(&self) -> Result<Option<f64>, Self::Error> {
+        Ok(None)
+    }
+
+    /// A stamp which changes whenever the size hints above may change. The
+    /// layout containers with a cache reuse the size hints while the stamp is
+    /// the same. [`None`] means the hints are queried on every layout.
+    ///
+    /// Get a new stamp with [`next_layout_stamp`].
+    fn layout_stamp(&self) -> Option<u64> {
+        None
+    }
+}
+
+/// Get a new stamp for [`Layoutable::layout_stamp`], different from all the
+/// stamps returned before.
+pub fn next_layout_stamp() -> u64 {
+    static STAMP: AtomicU64 = AtomicU64::new(0);
+    STAMP.fetch_add(1, Ordering::Relaxed)
+}
+
+/// Implement [`Layoutable::is_layout_visible`] with [`Visible::is_visible`].
+/// It is used inside the implementation of [`Layoutable`].
+#[macro_export]
+macro_rules! impl_layout_visible {
+    () => {
+        fn is_layout_visible(&self) -> ::std::result::Result<bool, Self::Error> {
+            $crate::Visible::is_visible(self)
+        }
+    };
 }
 
 /// Trait for a widget to set font.
@@ -132,10 +176,34 @@ pub trait Fontable: Failable {
         self.set_font(font)
     }
 
-    /// Set the font bold.
+    /// Set the font bold. It sets the weight to [`FontWeight::BOLD`] or
+    /// [`FontWeight::NORMAL`].
     fn set_font_bold(&mut self, bold: bool) -> Result<(), Self::Error> {
+        self.set_font_weight(if bold {
+            FontWeight::BOLD
+        } else {
+            FontWeight::NORMAL
+        })
+    }
+
+    /// Set the font weight.
+    fn set_font_weight(&mut self, weight: FontWeight) -> Result<(), Self::Error> {
+        let mut font = self.font()?;
+        font.weight = weight;
+        self.set_font(font)
+    }
+
+    /// Set the font underline.
+    fn set_font_underline(&mut self, underline: bool) -> Result<(), Self::Error> {
+        let mut font = self.font()?;
+        font.underline = underline;
+        self.set_font(font)
+    }
+
+    /// Set the font strikethrough.
+    fn set_font_strikethrough(&mut self, strikethrough: bool) -> Result<(), Self::Error> {
         let mut font = self.font()?;
-        font.bold = bold;
+        font.strikethrough = strikethrough;
         self.set_font(font)
     }
 }
@@ -10,6 +10,47 @@ jni::bind_java_type! {
     },
 }
 
+jni::bind_java_type! {
+    pub PaintCap => "android.graphics.Paint$Cap",
+    fields {
+        #[allow(non_snake_case)]
+        static BUTT: PaintCap,
+        #[allow(non_snake_case)]
+        static ROUND: PaintCap,
+        #[allow(non_snake_case)]
+        static SQUARE: PaintCap,
+    },
+}
+
+jni::bind_java_type! {
+    pub PaintJoin => "android.graphics.Paint$Join",
+    fields {
+        #[allow(non_snake_case)]
+        static BEVEL: PaintJoin,
+        #[allow(non_snake_case)]
+        static MITER: PaintJoin,
+        #[allow(non_snake_case)]
+        static ROUND: PaintJoin,
+    },
+}
+
+jni::bind_java_type! {
+    pub PathEffect => android.graphics.PathEffect,
+}
+
+jni::bind_java_type! {
+    pub DashPathEffect => android.graphics.DashPathEffect,
+    is_instance_of = {
+        base: PathEffect,
+    },
+    type_map {
+        PathEffect => android.graphics.PathEffect,
+    },
+    constructors {
+        fn new(intervals: &[jfloat], phase: jfloat),
+    },
+}
+
 jni::bind_java_type! {
     pub Shader => android.graphics.Shader,
 }
@@ -19,6 +60,10 @@ jni::bind_java_type! {
     fields {
         #[allow(non_snake_case)]
         static CLAMP: ShaderTileMode,
+        #[allow(non_snake_case)]
+        static REPEAT: ShaderTileMode,
+        #[allow(non_snake_case)]
+        static MIRROR: ShaderTileMode,
     }
 }
 
@@ -66,10 +111,49 @@ jni::bind_java_type! {
     },
 }
 
+jni::bind_java_type! {
+    pub SweepGradient => android.graphics.SweepGradient,
+    is_instance_of = {
+        base: Shader,
+    },
+    type_map {
+        Matrix => android.graphics.Matrix,
+        Shader => android.graphics.Shader,
+    },
+    constructors {
+        fn new(cx: jfloat, cy: jfloat, colors: &[jint], positions: &[jfloat]),
+    },
+    methods {
+        fn set_local_matrix(matrix: &Matrix),
+    },
+}
+
+jni::bind_java_type! {
+    pub BitmapShader => android.graphics.BitmapShader,
+    is_instance_of = {
+        base: Shader,
+    },
+    type_map {
+        Bitmap => android.graphics.Bitmap,
+        Matrix => android.graphics.Matrix,
+        Shader => android.graphics.Shader,
+        ShaderTileMode => "android.graphics.Shader$TileMode",
+    },
+    constructors {
+        fn new(bitmap: &Bitmap, tile_x: &ShaderTileMode, tile_y: &ShaderTileMode),
+    },
+    methods {
+        fn set_local_matrix(matrix: &Matrix),
+    },
+}
+
 jni::bind_java_type! {
     pub Paint => android.graphics.Paint,
     type_map {
+        PaintCap => "android.graphics.Paint$Cap",
+        PaintJoin => "android.graphics.Paint$Join",
         PaintStyle => "android.graphics.Paint$Style",
+        PathEffect => android.graphics.PathEffect,
         Shader => android.graphics.Shader,
         Typeface => android.graphics.Typeface,
     },
@@ -78,9 +162,14 @@ jni::bind_java_type! {
     },
     methods {
         fn set_a_r_g_b(a: jint, r: jint, g: jint, b: jint),
+        fn set_alpha(a: jint),
         fn set_style(style: &PaintStyle),
         fn set_shader(shader: &Shader) -> Shader,
         fn set_stroke_width(width: jfloat),
+        fn set_stroke_cap(cap: &PaintCap),
+        fn set_stroke_join(join: &PaintJoin),
+        fn set_stroke_miter(miter: jfloat),
+        fn set_path_effect(effect: &PathEffect) -> PathEffect,
         fn set_text_size(size: jfloat),
         fn set_typeface(typeface: &Typeface) -> Typeface,
     },
@@ -191,19 +280,45 @@ jni::bind_java_type! {
     }
 }
 
+jni::bind_java_type! {
+    pub PathDirection => "android.graphics.Path$Direction",
+    fields {
+        #[allow(non_snake_case)]
+        static CW: PathDirection,
+    },
+}
+
+jni::bind_java_type! {
+    pub PathFillType => "android.graphics.Path$FillType",
+    fields {
+        #[allow(non_snake_case)]
+        static WINDING: PathFillType,
+        #[allow(non_snake_case)]
+        static EVEN_ODD: PathFillType,
+    },
+}
+
 jni::bind_java_type! {
     pub Path => android.graphics.Path,
+    type_map {
+        PathDirection => "android.graphics.Path$Direction",
+        PathFillType => "android.graphics.Path$FillType",
+    },
     constructors {
         fn new(),
     },
     methods {
         #[allow(clippy::too_many_arguments)]
         fn arc_to(left: jfloat, top: jfloat, right: jfloat, bottom: jfloat, start_angle: jfloat, sweep_angle: jfloat, force_move_to: bool),
+        fn add_oval(left: jfloat, top: jfloat, right: jfloat, bottom: jfloat, dir: &PathDirection),
+        fn add_rect(left: jfloat, top: jfloat, right: jfloat, bottom: jfloat, dir: &PathDirection),
         fn close(),
         #[allow(clippy::too_many_arguments)]
         fn cubic_to(x1: jfloat, y1: jfloat, x2: jfloat, y2: jfloat, x3: jfloat, y3: jfloat),
         fn line_to(x: jfloat, y: jfloat),
         fn move_to(x: jfloat, y: jfloat),
+        fn quad_to(x1: jfloat, y1: jfloat, x2: jfloat, y2: jfloat),
+        fn set_fill_type(ft: &PathFillType),
     }
 }
 
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use compio_log::error;
-use image::DynamicImage;
+use image::{DynamicImage, RgbaImage};
 use inherit_methods_macro::inherit_methods;
 use jni::{
     Env,
@@ -12,16 +12,19 @@ use jni_min_helper::{DynamicProxy, JBoolean};
 use winio_callback::SyncCallback;
 use winio_handle::{AsContainer, impl_as_widget};
 use winio_primitive::{
-    BrushPen, Font, GradientStop, LinearGradientBrush, MouseButton, Point, RadialGradientBrush,
-    Rect, RelativePoint, RelativeToLogical, Size, SolidColorBrush, Transform, Vector,
+    Angle, BlendMode, BrushPen, ConicGradientBrush, Cursor, FillRule, Font, GradientStop,
+    ImageBrush, LineCap, LineJoin, LineMetrics, LinearGradientBrush, MouseButton, Point,
+    RadialGradientBrush, Rect, RelativePoint, RelativeSpace, RelativeToLogical, Size,
+    SolidColorBrush, SpreadMode, StrokeStyle, TextFormat, TextHitTest, TextSpan, Transform, Vector,
 };
 
 use crate::{
-    BaseWidget, Result, current_activity,
+    BaseWidget, Error, Result, current_activity,
     java::android::{
         graphics::{
-            Bitmap, BitmapConfig, Canvas as ACanvas, LinearGradient, Matrix as AMatrix, Paint,
-            PaintStyle, Path, Picture, RadialGradient, Rect as ARect, ShaderTileMode, Typeface,
+            Bitmap, BitmapConfig, BitmapShader, Canvas as ACanvas, DashPathEffect, LinearGradient,
+            Matrix as AMatrix, Paint, PaintCap, PaintJoin, PaintStyle, Path, Picture,
+            RadialGradient, Rect as ARect, ShaderTileMode, SweepGradient, Typeface,
             drawable::PictureDrawable, typeface,
         },
         text::{StaticLayout, StaticLayoutBuilder, TextPaint},
@@ -57,13 +60,8 @@ impl Brush for SolidColorBrush {
         _rect: RelativeToLogical,
     ) -> Result<Paint<'local>> {
         let paint = Paint::new(env)?;
-        paint.set_a_r_g_b(
-            env,
-            self.color.a as _,
-            self.color.r as _,
-            self.color.g as _,
-            self.color.b as _,
-        )?;
+        let color = self.faded_color();
+        paint.set_a_r_g_b(env, color.a as _, color.r as _, color.g as _, color.b as _)?;
         let style = PaintStyle::FILL(env)?;
         paint.set_style(env, &style)?;
         Ok(paint)
@@ -92,6 +90,37 @@ fn colors_stops<'local>(
     Ok((jcolors, jpositions))
 }
 
+fn tile_mode<'local>(env: &mut Env<'local>, spread: SpreadMode) -> Result<ShaderTileMode<'local>> {
+    let mode = match spread {
+        SpreadMode::Repeat => ShaderTileMode::REPEAT(env)?,
+        SpreadMode::Reflect => ShaderTileMode::MIRROR(env)?,
+        _ => ShaderTileMode::CLAMP(env)?,
+    };
+    Ok(mode)
+}
+
+fn local_matrix<'local>(
+    env: &mut Env<'local>,
+    trans: RelativeToLogical,
+) -> Result<AMatrix<'local>> {
+    let matrix = AMatrix::new(env)?;
+    let values = [
+        trans.m11 as f32,
+        trans.m21 as f32,
+        trans.m31 as f32,
+        trans.m12 as f32,
+        trans.m22 as f32,
+        trans.m32 as f32,
+        0.0,
+        0.0,
+        1.0,
+    ];
+    let arr = env.new_float_array(values.len())?;
+    arr.set_region(env, 0, &values)?;
+    matrix.set_values(env, &arr)?;
+    Ok(matrix)
+}
+
 impl Brush for LinearGradientBrush {
     fn create_paint<'local>(
         &self,
@@ -101,8 +130,8 @@ impl Brush for LinearGradientBrush {
         let paint = Paint::new(env)?;
         let style = PaintStyle::FILL(env)?;
         paint.set_style(env, &style)?;
-        let (jcolors, jpositions) = colors_stops(env, &self.stops)?;
-        let mode = ShaderTileMode::CLAMP(env)?;
+        let (jcolors, jpositions) = colors_stops(env, &self.faded_stops())?;
+        let mode = tile_mode(env, self.spread)?;
         let start = rect.transform_point(self.start);
         let end = rect.transform_point(self.end);
         let gradient = LinearGradient::new(
@@ -129,8 +158,8 @@ impl Brush for RadialGradientBrush {
         let paint = Paint::new(env)?;
         let style = PaintStyle::FILL(env)?;
         paint.set_style(env, &style)?;
-        let (jcolors, jpositions) = colors_stops(env, &self.stops)?;
-        let mode = ShaderTileMode::CLAMP(env)?;
+        let (jcolors, jpositions) = colors_stops(env, &self.faded_stops())?;
+        let mode = tile_mode(env, self.spread)?;
         let center = rect.transform_point(self.center);
         let radius = rect.transform_vector(self.radius.to_vector());
         let gradient = RadialGradient::new(
@@ -147,6 +176,49 @@ impl Brush for RadialGradientBrush {
     }
 }
 
+impl Brush for ConicGradientBrush {
+    fn create_paint<'local>(
+        &self,
+        env: &mut Env<'local>,
+        rect: RelativeToLogical,
+    ) -> Result<Paint<'local>> {
+        let paint = Paint::new(env)?;
+        let style = PaintStyle::FILL(env)?;
+        paint.set_style(env, &style)?;
+        let (jcolors, jpositions) = colors_stops(env, &self.faded_stops())?;
+        let gradient = SweepGradient::new(env, 0.0, 0.0, &jcolors, &jpositions)?;
+        // The sweep gradient starts from the positive x axis.
+        let trans = RelativeToLogical::rotation(Angle::radians(self.angle))
+            .then_translate(Vector::new(self.center.x, self.center.y))
+            .with_destination::<RelativeSpace>()
+            .then(&rect);
+        let matrix = local_matrix(env, trans)?;
+        gradient.set_local_matrix(env, &matrix)?;
+        paint.set_shader(env, &gradient)?;
+        Ok(paint)
+    }
+}
+
+impl<I: AsRef<DrawingImage>> Brush for ImageBrush<I> {
+    fn create_paint<'local>(
+        &self,
+        env: &mut Env<'local>,
+        rect: RelativeToLogical,
+    ) -> Result<Paint<'local>> {
+        let image = self.image.as_ref();
+        let paint = Paint::new(env)?;
+        let style = PaintStyle::FILL(env)?;
+        paint.set_style(env, &style)?;
+        paint.set_alpha(env, (self.opacity.clamp(0.0, 1.0) * 255.0).round() as _)?;
+        let mode = tile_mode(env, self.spread)?;
+        let shader = BitmapShader::new(env, &image.bitmap, &mode, &mode)?;
+        let matrix = local_matrix(env, self.pixel_transform(image.size()?, rect))?;
+        shader.set_local_matrix(env, &matrix)?;
+        paint.set_shader(env, &shader)?;
+        Ok(paint)
+    }
+}
+
 /// Drawing pen.
 pub trait Pen {
     fn create_paint<'local>(
@@ -176,15 +248,59 @@ impl<B: Brush> Pen for BrushPen<B> {
         paint.set_stroke_width(env, self.width as _)?;
         let style = PaintStyle::STROKE(env)?;
         paint.set_style(env, &style)?;
+        set_stroke(env, &paint, &self.stroke, self.width)?;
         Ok(paint)
     }
 }
 
+fn set_stroke<'local>(
+    env: &mut Env<'local>,
+    paint: &Paint<'local>,
+    stroke: &StrokeStyle,
+    width: f64,
+) -> Result<()> {
+    let cap = match stroke.cap {
+        LineCap::Round => PaintCap::ROUND(env)?,
+        LineCap::Square => PaintCap::SQUARE(env)?,
+        _ => PaintCap::BUTT(env)?,
+    };
+    paint.set_stroke_cap(env, &cap)?;
+    let join = match stroke.join {
+        LineJoin::Round => PaintJoin::ROUND(env)?,
+        LineJoin::Bevel => PaintJoin::BEVEL(env)?,
+        _ => PaintJoin::MITER(env)?,
+    };
+    paint.set_stroke_join(env, &join)?;
+    paint.set_stroke_miter(env, stroke.miter_limit as _)?;
+    if stroke.is_dashed() {
+        // The intervals are absolute lengths, and the count should be even.
+        let mut intervals = stroke
+            .dashes
+            .iter()
+            .map(|d| (d.max(0.0) * width) as f32)
+            .collect::<Vec<_>>();
+        if intervals.len() % 2 != 0 {
+            intervals.extend_from_within(..);
+        }
+        let jintervals = env.new_float_array(intervals.len() as _)?;
+        jintervals.set_region(env, 0, &intervals)?;
+        let effect = DashPathEffect::new(env, &jintervals, (stroke.dash_offset * width) as _)?;
+        paint.set_path_effect(env, &effect)?;
+    }
+    Ok(())
+}
+
 #[derive(Debug)]
 pub struct DrawingImage {
     bitmap: Global<Bitmap<'static>>,
 }
 
+impl AsRef<DrawingImage> for DrawingImage {
+    fn as_ref(&self) -> &DrawingImage {
+        self
+    }
+}
+
 impl DrawingImage {
     fn new(image: DynamicImage) -> Result<Self> {
         vm_exec(|env| {
@@ -317,8 +433,18 @@ impl<'a> DrawingContext<'a> {
         })
     }
 
-    pub fn fill_path(&mut self, brush: impl Brush, path: &DrawingPath) -> Result<()> {
+    pub fn fill_path(
+        &mut self,
+        brush: impl Brush,
+        path: &DrawingPath,
+        rule: FillRule,
+    ) -> Result<()> {
         vm_exec(|env| {
+            let fill_type = match rule {
+                FillRule::EvenOdd => PathFillType::EVEN_ODD(env)?,
+                _ => PathFillType::WINDING(env)?,
+            };
+            path.path.set_fill_type(env, &fill_type)?;
             let paint = brush.create_paint(env, self.logical())?;
             self.canvas.draw_path(env, &path.path, &paint)?;
             Ok(())
@@ -505,7 +631,7 @@ impl<'a> DrawingContext<'a> {
         text: &str,
     ) -> Result<(StaticLayout<'local>, Size)> {
         let mut style = typeface::NORMAL;
-        if font.bold {
+        if font.weight.is_bold() {
             style |= typeface::BOLD;
         }
         if font.italic {
@@ -607,6 +733,49 @@ impl<'a> DrawingContext<'a> {
         })
     }
 
+    pub fn push_clip_rect(&mut self, _rect: Rect) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn push_clip_path(&mut self, _path: &DrawingPath) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn pop_clip(&mut self) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn push_layer(&mut self, _opacity: f64, _blend: BlendMode) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn pop_layer(&mut self) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn create_text_layout(
+        &self,
+        _text: &str,
+        _font: Font,
+        _spans: &[TextSpan],
+        _format: &TextFormat,
+    ) -> Result<TextLayout> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn draw_text_layout(
+        &mut self,
+        _brush: impl Brush,
+        layout: &TextLayout,
+        _pos: Point,
+    ) -> Result<()> {
+        match *layout {}
+    }
+
+    pub fn create_bitmap(&self, _size: Size) -> Result<DrawingBitmap> {
+        Err(Error::NotSupported)
+    }
+
     pub fn create_path_builder(&self, start: Point) -> Result<DrawingPathBuilder> {
         DrawingPathBuilder::new(start)
     }
@@ -620,6 +789,70 @@ pub struct DrawingPath {
     path: Global<Path<'static>>,
 }
 
+impl DrawingPath {
+    pub fn bounds(&self) -> Result<Rect> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn contains(&self, _p: Point, _rule: FillRule) -> Result<bool> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn stroke_contains(&self, _p: Point, _width: f64) -> Result<bool> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn length(&self) -> Result<f64> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn transform(&self, _transform: &Transform) -> Result<DrawingPath> {
+        Err(Error::NotSupported)
+    }
+}
+
+/// Text layouts are not supported yet.
+pub enum TextLayout {}
+
+impl TextLayout {
+    pub fn size(&self) -> Result<Size> {
+        match *self {}
+    }
+
+    pub fn line_metrics(&self) -> Result<Vec<LineMetrics>> {
+        match *self {}
+    }
+
+    pub fn hit_test(&self, _p: Point) -> Result<TextHitTest> {
+        match *self {}
+    }
+
+    pub fn caret_rect(&self, _index: usize) -> Result<Rect> {
+        match *self {}
+    }
+}
+
+/// Bitmaps are not supported yet.
+pub enum DrawingBitmap {}
+
+impl DrawingBitmap {
+    pub fn size(&self) -> Result<Size> {
+        match *self {}
+    }
+
+    pub fn context(&mut self) -> Result<DrawingContext<'_>> {
+        match *self {}
+    }
+
+    pub fn to_image(&self) -> Result<DrawingImage> {
+        match *self {}
+    }
+
+    pub fn to_rgba(&self) -> Result<RgbaImage> {
+        match *self {}
+    }
+}
+
 pub struct DrawingPathBuilder {
     path: Global<Path<'static>>,
 }
@@ -641,6 +874,17 @@ impl DrawingPathBuilder {
         })
     }
 
+    pub fn move_to(&mut self, p: Point) -> Result<()> {
+        vm_exec(|env| {
+            self.path.move_to(env, p.x as f32, p.y as f32)?;
+            Ok(())
+        })
+    }
+
+    pub fn close_figure(&mut self) -> Result<()> {
+        vm_exec(|env| self.path.close(env))
+    }
+
     pub fn add_arc(
         &mut self,
         center: Point,
@@ -691,6 +935,46 @@ impl DrawingPathBuilder {
         })
     }
 
+    pub fn add_quad(&mut self, p1: Point, p2: Point) -> Result<()> {
+        vm_exec(|env| {
+            self.path
+                .quad_to(env, p1.x as f32, p1.y as f32, p2.x as f32, p2.y as f32)?;
+            Ok(())
+        })
+    }
+
+    pub fn add_rect(&mut self, rect: Rect) -> Result<()> {
+        let rect = rect.to_box2d();
+        vm_exec(|env| {
+            let dir = PathDirection::CW(env)?;
+            self.path.add_rect(
+                env,
+                rect.min.x as f32,
+                rect.min.y as f32,
+                rect.max.x as f32,
+                rect.max.y as f32,
+                &dir,
+            )?;
+            Ok(())
+        })
+    }
+
+    pub fn add_ellipse(&mut self, rect: Rect) -> Result<()> {
+        let rect = rect.to_box2d();
+        vm_exec(|env| {
+            let dir = PathDirection::CW(env)?;
+            self.path.add_oval(
+                env,
+                rect.min.x as f32,
+                rect.min.y as f32,
+                rect.max.x as f32,
+                rect.max.y as f32,
+                &dir,
+            )?;
+            Ok(())
+        })
+    }
+
     pub fn build(self, close: bool) -> Result<DrawingPath> {
         if close {
             vm_exec(|env| self.path.close(env))?;
@@ -706,6 +990,7 @@ pub struct Canvas {
     on_up: Arc<SyncCallback<MouseButton>>,
     on_move: Arc<SyncCallback<Point>>,
     on_scroll: Arc<SyncCallback<Vector>>,
+    on_paint: SyncCallback<Rect>,
     #[allow(dead_code)]
     touch_proxy: DynamicProxy,
     latest_size: Size,
@@ -796,6 +1081,7 @@ impl Canvas {
                 on_up,
                 on_move,
                 on_scroll,
+                on_paint: SyncCallback::new(),
                 touch_proxy,
                 latest_size: Size::zero(),
             })
@@ -828,11 +1114,20 @@ impl Canvas {
         self.inner.set_size(v)
     }
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
     pub fn tooltip(&self) -> Result<String>;
 
     pub fn set_tooltip(&mut self, s: impl AsRef<str>) -> Result<()>;
 
-    pub fn context(&self) -> Result<DrawingContext<'_>> {
+    pub fn invalidate(&mut self, rect: Rect) -> Result<()> {
+        self.on_paint.signal(rect);
+        Ok(())
+    }
+
+    pub fn context(&self, _dirty: Option<Rect>) -> Result<DrawingContext<'_>> {
         vm_exec(|env| {
             let picture = Picture::new(env)?;
             let picture = env.new_global_ref(picture)?;
@@ -861,6 +1156,10 @@ impl Canvas {
     pub async fn wait_mouse_wheel(&self) -> Vector {
         self.on_scroll.wait().await
     }
+
+    pub async fn wait_paint(&self) -> Rect {
+        self.on_paint.wait().await
+    }
 }
 
 impl_as_widget!(Canvas, inner);
@@ -75,10 +75,10 @@ impl Label {
 
     pub fn set_halign(&mut self, align: HAlign) -> Result<()> {
         let gravity = match align {
-            HAlign::Left => gravity::LEFT,
             HAlign::Center => gravity::CENTER_HORIZONTAL,
             HAlign::Right => gravity::RIGHT,
             HAlign::Stretch => gravity::FILL_HORIZONTAL,
+            _ => gravity::LEFT,
         } | gravity::CENTER_VERTICAL;
         vm_exec(|env| {
             self.inner.set_gravity(env, gravity)?;
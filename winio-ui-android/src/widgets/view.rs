@@ -1,8 +1,10 @@
 use inherit_methods_macro::inherit_methods;
 use winio_handle::AsContainer;
-use winio_primitive::{Point, Size};
+use winio_primitive::{Cursor, Point, Size};
 
-use crate::{BaseWidget, Result, current_activity, java::android::widget::FrameLayout, vm_exec};
+use crate::{
+    BaseWidget, Error, Result, current_activity, java::android::widget::FrameLayout, vm_exec,
+};
 
 #[derive(Debug)]
 pub struct View {
@@ -42,6 +44,10 @@ impl View {
     pub fn size(&self) -> Result<Size>;
 
     pub fn set_size(&mut self, size: Size) -> Result<()>;
+
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(Error::NotSupported)
+    }
 }
 
 winio_handle::impl_as_widget!(View, inner);
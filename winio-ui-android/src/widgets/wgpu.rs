@@ -12,11 +12,11 @@ use wgpu::{
 };
 use winio_callback::SyncCallback;
 use winio_handle::AsContainer;
-use winio_primitive::{MouseButton, Point, Size, Vector};
+use winio_primitive::{Cursor, MouseButton, Point, Size, Vector};
 
 use crate::{
-    BaseWidget, Result, current_activity, java::android::view::SurfaceView, view_touch_proxy,
-    vm_exec,
+    BaseWidget, Error, Result, current_activity, java::android::view::SurfaceView,
+    view_touch_proxy, vm_exec,
 };
 
 #[derive(Debug)]
@@ -76,6 +76,10 @@ impl WgpuCanvas {
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
     pub fn tooltip(&self) -> Result<String>;
 
     pub fn set_tooltip(&mut self, s: impl AsRef<str>) -> Result<()>;
@@ -44,12 +44,12 @@ pub(crate) fn text_view_to_font(
         let family = unsafe { JString::from_raw(env, family.into_raw()) };
         family.try_to_string(env)?
     };
-    Ok(Font {
-        family,
-        size: px as f64 / metrics.scaled_density(env)? as f64,
-        bold: typeface.is_bold(env)?,
-        italic: typeface.is_italic(env)?,
-    })
+    Ok(Font::builder()
+        .family(family)
+        .size(px as f64 / metrics.scaled_density(env)? as f64)
+        .italic(typeface.is_italic(env)?)
+        .bold(typeface.is_bold(env)?)
+        .build())
 }
 
 /// Set the font of a [`ATextView`].
@@ -59,7 +59,7 @@ pub(crate) fn font_to_text_view(
     font: &Font,
 ) -> Result<()> {
     let mut style = typeface::NORMAL;
-    if font.bold {
+    if font.weight.is_bold() {
         style |= typeface::BOLD;
     }
     if font.italic {
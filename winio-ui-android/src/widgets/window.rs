@@ -10,10 +10,10 @@ use jni::{
 use jni_min_helper::{DynamicProxy, JInteger};
 use winio_callback::SyncCallback;
 use winio_handle::{AsWindow, BorrowedContainer, BorrowedWindow};
-use winio_primitive::{Point, Size};
+use winio_primitive::{Cursor, Point, Size};
 
 use crate::{
-    BaseWidget, Result, current_activity,
+    BaseWidget, Error, Result, current_activity,
     java::{
         android::{
             view::{ViewOnLayoutChangeListener, WindowInsetsType},
@@ -169,6 +169,10 @@ impl Window {
         Ok(())
     }
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
     pub fn text(&self) -> Result<String> {
         Ok(String::new())
     }
@@ -87,10 +87,10 @@ impl EditImpl {
 
     pub fn set_halign(&mut self, align: HAlign) -> Result<()> {
         let align = match align {
-            HAlign::Left => NSTextAlignment::Left,
             HAlign::Center => NSTextAlignment::Center,
             HAlign::Right => NSTextAlignment::Right,
             HAlign::Stretch => NSTextAlignment::Justified,
+            _ => NSTextAlignment::Left,
         };
         catch(|| self.view.setAlignment(align))
     }
@@ -75,15 +75,16 @@ pub fn color_theme() -> crate::Result<ColorTheme> {
 /// Convert an [`NSFont`] to a [`Font`].
 pub(crate) fn nsfont_to_font(font: &NSFont) -> Font {
     let traits = font.fontDescriptor().symbolicTraits();
-    Font {
-        family: font
-            .familyName()
-            .map(|s| crate::from_nsstring(&s))
-            .unwrap_or_default(),
-        size: font.pointSize(),
-        bold: traits.contains(NSFontDescriptorSymbolicTraits::TraitBold),
-        italic: traits.contains(NSFontDescriptorSymbolicTraits::TraitItalic),
-    }
+    Font::builder()
+        .family(
+            font.familyName()
+                .map(|s| crate::from_nsstring(&s))
+                .unwrap_or_default(),
+        )
+        .size(font.pointSize())
+        .italic(traits.contains(NSFontDescriptorSymbolicTraits::TraitItalic))
+        .bold(traits.contains(NSFontDescriptorSymbolicTraits::TraitBold))
+        .build()
 }
 
 /// Convert a [`Font`] to an [`NSFont`].
@@ -91,7 +92,7 @@ pub(crate) fn font_to_nsfont(font: &Font) -> Retained<NSFont> {
     let base = NSFont::fontWithName_size(&NSString::from_str(&font.family), font.size)
         .unwrap_or_else(|| NSFont::systemFontOfSize(font.size));
     let mut traits = NSFontDescriptorSymbolicTraits::empty();
-    if font.bold {
+    if font.weight.is_bold() {
         traits |= NSFontDescriptorSymbolicTraits::TraitBold;
     }
     if font.italic {
@@ -136,10 +136,10 @@ impl TextBox {
 
     pub fn set_halign(&mut self, align: HAlign) -> Result<()> {
         let align = match align {
-            HAlign::Left => NSTextAlignment::Left,
             HAlign::Center => NSTextAlignment::Center,
             HAlign::Right => NSTextAlignment::Right,
             HAlign::Stretch => NSTextAlignment::Justified,
+            _ => NSTextAlignment::Left,
         };
         catch(|| self.text_view.setAlignment(align))
     }
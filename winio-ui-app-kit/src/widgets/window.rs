@@ -19,7 +19,7 @@ use winio_callback::Callback;
 use winio_handle::{
     AsContainer, AsWidget, AsWindow, BorrowedContainer, BorrowedWidget, BorrowedWindow,
 };
-use winio_primitive::{Point, Rect, Size};
+use winio_primitive::{Cursor, Point, Rect, Size};
 
 use crate::{
     Error, GlobalRuntime, Result, catch, from_cgsize, from_nsstring, to_cgsize, transform_cgrect,
@@ -134,6 +134,10 @@ impl Window {
         })
     }
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
     pub fn client_size(&self) -> Result<Size> {
         catch(|| from_cgsize(self.content_view.frame().size))
     }
@@ -530,6 +534,10 @@ impl View {
     pub fn size(&self) -> Result<Size>;
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
+
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(Error::NotSupported)
+    }
 }
 
 winio_handle::impl_as_widget!(View, handle);
@@ -1,7 +1,7 @@
 use std::ptr::{null, null_mut};
 
 use compio_log::error;
-use image::DynamicImage;
+use image::{DynamicImage, Rgba, RgbaImage};
 use objc2_core_foundation::{
     CFMutableArray, CFMutableAttributedString, CFRange, CFRetained, CGAffineTransform,
     kCFAllocatorDefault,
@@ -9,7 +9,7 @@ use objc2_core_foundation::{
 use objc2_core_graphics::{
     CGAffineTransformIsIdentity, CGBitmapContextCreate, CGBitmapContextCreateImage, CGColor,
     CGColorSpace, CGContext, CGGradient, CGGradientDrawingOptions, CGImage, CGImageAlphaInfo,
-    CGPath, kCGColorWhite,
+    CGLineCap, CGLineJoin, CGPath, kCGColorWhite,
 };
 use objc2_core_text::{
     CTFont, CTFontDescriptor, CTFontSymbolicTraits, CTFramesetter, kCTFontAttributeName,
@@ -17,8 +17,9 @@ use objc2_core_text::{
 };
 use objc2_foundation::{NSPoint, NSRect, NSSize, NSString};
 use winio_primitive::{
-    BrushPen, Color, Font, GradientStop, LinearGradientBrush, Point, RadialGradientBrush, Rect,
-    RelativePoint, Size, SolidColorBrush,
+    BrushPen, Color, ConicGradientBrush, FillRule, Font, GradientStop, ImageBrush, LineCap,
+    LineJoin, LinearGradientBrush, Point, RadialGradientBrush, Rect, RelativePoint, Size,
+    SolidColorBrush, SpreadMode, StrokeStyle,
 };
 
 use crate::{Error, Result, TollFreeBridge};
@@ -93,6 +94,12 @@ pub enum DrawGradientAction {
         end_center: NSPoint,
         end_radius: f64,
     },
+    Image {
+        image: DrawingImage,
+        rect: NSRect,
+        tiled: bool,
+        opacity: f64,
+    },
 }
 
 impl DrawGradientAction {
@@ -128,14 +135,87 @@ impl DrawGradientAction {
                     CGGradientDrawingOptions::all(),
                 );
             }
+            Self::Image {
+                image,
+                rect,
+                tiled,
+                opacity,
+            } => {
+                CGContext::set_alpha(Some(context), *opacity);
+                if *tiled {
+                    CGContext::draw_tiled_image(Some(context), *rect, Some(image.cgimage()));
+                } else {
+                    CGContext::draw_image(Some(context), *rect, Some(image.cgimage()));
+                }
+            }
+        }
+    }
+}
+
+/// The width and the style of a stroke.
+#[derive(Debug)]
+pub struct DrawStroke {
+    width: f64,
+    style: StrokeStyle,
+}
+
+impl DrawStroke {
+    fn set(&self, context: &CGContext) {
+        CGContext::set_line_width(Some(context), self.width);
+        CGContext::set_line_cap(
+            Some(context),
+            match self.style.cap {
+                LineCap::Round => CGLineCap::Round,
+                LineCap::Square => CGLineCap::Square,
+                _ => CGLineCap::Butt,
+            },
+        );
+        CGContext::set_line_join(
+            Some(context),
+            match self.style.join {
+                LineJoin::Round => CGLineJoin::Round,
+                LineJoin::Bevel => CGLineJoin::Bevel,
+                _ => CGLineJoin::Miter,
+            },
+        );
+        CGContext::set_miter_limit(Some(context), self.style.miter_limit);
+        if self.style.is_dashed() {
+            // The dashes of Core Graphics are absolute lengths.
+            let lengths = self
+                .style
+                .dashes
+                .iter()
+                .map(|d| d.max(0.0) * self.width)
+                .collect::<Vec<_>>();
+            unsafe {
+                CGContext::set_line_dash(
+                    Some(context),
+                    self.style.dash_offset * self.width,
+                    lengths.as_ptr(),
+                    lengths.len(),
+                );
+            }
         }
     }
 }
 
+/// How a path is painted.
+#[derive(Debug)]
+pub enum DrawMode {
+    Fill(FillRule),
+    Stroke(DrawStroke),
+}
+
+impl Default for DrawMode {
+    fn default() -> Self {
+        Self::Fill(FillRule::NonZero)
+    }
+}
+
 #[derive(Debug)]
 pub enum DrawAction {
-    Path(CFRetained<CGPath>, CFRetained<CGColor>, Option<f64>),
-    GradientPath(CFRetained<CGPath>, DrawGradientAction, Option<f64>),
+    Path(CFRetained<CGPath>, CFRetained<CGColor>, DrawMode),
+    GradientPath(CFRetained<CGPath>, DrawGradientAction, DrawMode),
     Text(CFRetained<CTFramesetter>, NSRect),
     GradientText(CFRetained<CTFramesetter>, DrawGradientAction, NSRect),
     Image(DrawingImage, NSRect, Option<NSRect>),
@@ -143,12 +223,18 @@ pub enum DrawAction {
 }
 
 impl DrawAction {
-    fn with_width(self, width: f64) -> Self {
+    fn with_stroke(self, width: f64, style: StrokeStyle) -> Self {
+        self.with_mode(DrawMode::Stroke(DrawStroke { width, style }))
+    }
+
+    pub fn with_fill_rule(self, rule: FillRule) -> Self {
+        self.with_mode(DrawMode::Fill(rule))
+    }
+
+    fn with_mode(self, mode: DrawMode) -> Self {
         match self {
-            Self::Path(path, color, _) => Self::Path(path, color, Some(width)),
-            Self::GradientPath(path, gradient, _) => {
-                Self::GradientPath(path, gradient, Some(width))
-            }
+            Self::Path(path, color, _) => Self::Path(path, color, mode),
+            Self::GradientPath(path, gradient, _) => Self::GradientPath(path, gradient, mode),
             _ => self,
         }
     }
@@ -161,28 +247,36 @@ impl DrawAction {
                 CGContext::concat_ctm(Some(context), *transform);
             }
             match action {
-                DrawAction::Path(path, color, width) => {
+                DrawAction::Path(path, color, mode) => {
                     CGContext::add_path(Some(context), Some(path));
-                    if let Some(width) = width {
-                        CGContext::set_stroke_color_with_color(Some(context), Some(color));
-                        CGContext::set_line_width(Some(context), *width);
-                        CGContext::stroke_path(Some(context));
-                    } else {
-                        CGContext::set_fill_color_with_color(Some(context), Some(color));
-                        CGContext::fill_path(Some(context));
+                    match mode {
+                        DrawMode::Stroke(stroke) => {
+                            CGContext::set_stroke_color_with_color(Some(context), Some(color));
+                            stroke.set(context);
+                            CGContext::stroke_path(Some(context));
+                        }
+                        DrawMode::Fill(FillRule::EvenOdd) => {
+                            CGContext::set_fill_color_with_color(Some(context), Some(color));
+                            CGContext::eo_fill_path(Some(context));
+                        }
+                        DrawMode::Fill(_) => {
+                            CGContext::set_fill_color_with_color(Some(context), Some(color));
+                            CGContext::fill_path(Some(context));
+                        }
                     }
                 }
-                DrawAction::GradientPath(path, gradient, width) => {
+                DrawAction::GradientPath(path, gradient, mode) => {
                     CGContext::add_path(Some(context), Some(path));
-                    if let Some(width) = width {
-                        CGContext::set_line_width(Some(context), *width);
-                        CGContext::replace_path_with_stroked_path(Some(context));
-                        CGContext::clip(Some(context));
-                        gradient.draw(context);
-                    } else {
-                        CGContext::clip(Some(context));
-                        gradient.draw(context);
+                    match mode {
+                        DrawMode::Stroke(stroke) => {
+                            stroke.set(context);
+                            CGContext::replace_path_with_stroked_path(Some(context));
+                            CGContext::clip(Some(context));
+                        }
+                        DrawMode::Fill(FillRule::EvenOdd) => CGContext::eo_clip(Some(context)),
+                        DrawMode::Fill(_) => CGContext::clip(Some(context)),
                     }
+                    gradient.draw(context);
                 }
                 DrawAction::Text(framesetter, rect) => unsafe {
                     let text_path = CGPath::with_rect(*rect, null());
@@ -265,7 +359,7 @@ pub fn create_attr_str(
         if font.italic {
             traits |= CTFontSymbolicTraits::TraitItalic;
         }
-        if font.bold {
+        if font.weight.is_bold() {
             traits |= CTFontSymbolicTraits::TraitBold;
         }
         if !traits.is_empty() {
@@ -353,11 +447,15 @@ impl<B: Brush> Brush for &'_ B {
 
 impl Brush for SolidColorBrush {
     fn create_action(&self, path: CFRetained<CGPath>) -> Result<DrawAction> {
-        Ok(DrawAction::Path(path, to_cgcolor(self.color), None))
+        Ok(DrawAction::Path(
+            path,
+            to_cgcolor(self.faded_color()),
+            DrawMode::default(),
+        ))
     }
 
     fn text_color(&self) -> Result<CFRetained<CGColor>> {
-        Ok(to_cgcolor(self.color))
+        Ok(to_cgcolor(self.faded_color()))
     }
 
     fn create_text_action(
@@ -383,8 +481,9 @@ fn create_gradient(stops: &[GradientStop]) -> Result<CFRetained<CGGradient>> {
     }
 }
 
+// Core Graphics only pads the gradients, so the spread mode is ignored.
 fn linear_gradient(b: &LinearGradientBrush, rect: NSRect) -> Result<DrawGradientAction> {
-    let gradient = create_gradient(&b.stops)?;
+    let gradient = create_gradient(&b.faded_stops())?;
     Ok(DrawGradientAction::Linear {
         gradient,
         start_point: real_point(b.start, rect),
@@ -398,7 +497,7 @@ impl Brush for LinearGradientBrush {
         Ok(DrawAction::GradientPath(
             path,
             linear_gradient(self, rect)?,
-            None,
+            DrawMode::default(),
         ))
     }
 
@@ -420,7 +519,7 @@ impl Brush for LinearGradientBrush {
 }
 
 fn radial_gradient(b: &RadialGradientBrush, rect: NSRect) -> Result<DrawGradientAction> {
-    let gradient = create_gradient(&b.stops)?;
+    let gradient = create_gradient(&b.faded_stops())?;
     Ok(DrawGradientAction::Radial {
         gradient,
         start_center: real_point(b.origin, rect),
@@ -436,7 +535,7 @@ impl Brush for RadialGradientBrush {
         Ok(DrawAction::GradientPath(
             path,
             radial_gradient(self, rect)?,
-            None,
+            DrawMode::default(),
         ))
     }
 
@@ -457,16 +556,117 @@ impl Brush for RadialGradientBrush {
     }
 }
 
+fn conic_gradient(b: &ConicGradientBrush, rect: NSRect) -> Result<DrawGradientAction> {
+    // Core Graphics has no conic gradient before macOS 14, so it is rasterized
+    // for the area.
+    let width = rect.size.width.ceil().clamp(1.0, 4096.0) as u32;
+    let height = rect.size.height.ceil().clamp(1.0, 4096.0) as u32;
+    let image = RgbaImage::from_fn(width, height, |x, y| {
+        let p = RelativePoint::new(
+            (x as f64 + 0.5) / width as f64,
+            (y as f64 + 0.5) / height as f64,
+        );
+        let c = b.color_at(p);
+        Rgba([c.r, c.g, c.b, c.a])
+    });
+    Ok(DrawGradientAction::Image {
+        image: DrawingImage::new(DynamicImage::ImageRgba8(image))?,
+        rect,
+        tiled: false,
+        opacity: 1.0,
+    })
+}
+
+impl Brush for ConicGradientBrush {
+    fn create_action(&self, path: CFRetained<CGPath>) -> Result<DrawAction> {
+        let rect = CGPath::bounding_box(Some(&path));
+        Ok(DrawAction::GradientPath(
+            path,
+            conic_gradient(self, rect)?,
+            DrawMode::default(),
+        ))
+    }
+
+    fn text_color(&self) -> Result<CFRetained<CGColor>> {
+        unsafe { CGColor::constant_color(Some(kCGColorWhite)).ok_or(Error::NullPointer) }
+    }
+
+    fn create_text_action(
+        &self,
+        framesetter: CFRetained<CTFramesetter>,
+        rect: NSRect,
+    ) -> Result<DrawAction> {
+        Ok(DrawAction::GradientText(
+            framesetter,
+            conic_gradient(self, rect)?,
+            rect,
+        ))
+    }
+}
+
+// Core Graphics could only tile the image, so [`SpreadMode::Reflect`] behaves
+// like [`SpreadMode::Repeat`], and [`SpreadMode::Pad`] leaves the rest of the
+// area transparent.
+fn image_brush<I: AsRef<DrawingImage>>(b: &ImageBrush<I>, rect: NSRect) -> DrawGradientAction {
+    // The bottom-left corner in the flipped coordinates.
+    let origin = real_point(
+        RelativePoint::new(b.origin.x, b.origin.y + b.size.height),
+        rect,
+    );
+    let size = NSSize::new(
+        rect.size.width * b.size.width,
+        rect.size.height * b.size.height,
+    );
+    DrawGradientAction::Image {
+        image: b.image.as_ref().clone(),
+        rect: NSRect::new(origin, size),
+        tiled: !matches!(b.spread, SpreadMode::Pad),
+        opacity: b.opacity,
+    }
+}
+
+impl<I: AsRef<DrawingImage>> Brush for ImageBrush<I> {
+    fn create_action(&self, path: CFRetained<CGPath>) -> Result<DrawAction> {
+        let rect = CGPath::bounding_box(Some(&path));
+        Ok(DrawAction::GradientPath(
+            path,
+            image_brush(self, rect),
+            DrawMode::default(),
+        ))
+    }
+
+    fn text_color(&self) -> Result<CFRetained<CGColor>> {
+        unsafe { CGColor::constant_color(Some(kCGColorWhite)).ok_or(Error::NullPointer) }
+    }
+
+    fn create_text_action(
+        &self,
+        framesetter: CFRetained<CTFramesetter>,
+        rect: NSRect,
+    ) -> Result<DrawAction> {
+        Ok(DrawAction::GradientText(
+            framesetter,
+            image_brush(self, rect),
+            rect,
+        ))
+    }
+}
+
 /// Drawing pen.
 pub trait Pen {
     #[doc(hidden)]
     fn brush(&self) -> &dyn Brush;
     #[doc(hidden)]
     fn width(&self) -> f64;
+    #[doc(hidden)]
+    fn stroke(&self) -> &StrokeStyle;
 
     #[doc(hidden)]
     fn create_action(&self, path: CFRetained<CGPath>) -> Result<DrawAction> {
-        Ok(self.brush().create_action(path)?.with_width(self.width()))
+        Ok(self
+            .brush()
+            .create_action(path)?
+            .with_stroke(self.width(), self.stroke().clone()))
     }
 }
 
@@ -478,6 +678,10 @@ impl<P: Pen> Pen for &'_ P {
     fn width(&self) -> f64 {
         (**self).width()
     }
+
+    fn stroke(&self) -> &StrokeStyle {
+        (**self).stroke()
+    }
 }
 
 impl<B: Brush> Pen for BrushPen<B> {
@@ -488,6 +692,10 @@ impl<B: Brush> Pen for BrushPen<B> {
     fn width(&self) -> f64 {
         self.width
     }
+
+    fn stroke(&self) -> &StrokeStyle {
+        &self.stroke
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -496,6 +704,12 @@ pub struct DrawingImage {
     size: Size,
 }
 
+impl AsRef<DrawingImage> for DrawingImage {
+    fn as_ref(&self) -> &DrawingImage {
+        self
+    }
+}
+
 impl DrawingImage {
     pub fn new(image: DynamicImage) -> Result<Self> {
         let width = image.width();
@@ -4,5 +4,8 @@ pub use monitor::*;
 mod accent;
 pub use accent::*;
 
+mod palette;
+pub use palette::*;
+
 mod timer;
 pub use timer::*;
@@ -0,0 +1,69 @@
+use std::cell::Cell;
+
+use gtk4::{gdk::RGBA, prelude::*};
+use winio_primitive::{Color, ColorOps, ColorTheme, SystemPalette};
+
+use crate::{Error, Result};
+
+thread_local! {
+    static SYSTEM_PALETTE: Cell<Option<SystemPalette>> = const { Cell::new(None) };
+}
+
+/// Convert a [`RGBA`] to a [`Color`].
+pub(crate) fn rgba_to_color(c: &RGBA) -> Color {
+    Color::new(
+        (c.red() * 255.0).round() as u8,
+        (c.green() * 255.0).round() as u8,
+        (c.blue() * 255.0).round() as u8,
+        (c.alpha() * 255.0).round() as u8,
+    )
+}
+
+/// Look up the first defined named color of the theme. GTK 4 provides no
+/// replacement of [`gtk4::StyleContext`] to read the named colors.
+#[allow(deprecated)]
+fn lookup_color(ctx: &gtk4::StyleContext, names: &[&str]) -> Option<Color> {
+    names
+        .iter()
+        .find_map(|name| ctx.lookup_color(name))
+        .map(|c| rgba_to_color(&c))
+}
+
+/// Read the colors of the theme from a window, and cache them. It is called
+/// when a window is created, and when the theme changes.
+#[allow(deprecated)]
+pub(crate) fn update_system_palette(w: &gtk4::Window) {
+    let ctx = w.style_context();
+    let window = lookup_color(&ctx, &["window_bg_color", "theme_bg_color"]);
+    let theme = match window {
+        Some(c) if c.is_dark() => ColorTheme::Dark,
+        Some(_) => ColorTheme::Light,
+        None => crate::color_theme().unwrap_or(ColorTheme::Light),
+    };
+    let accent = lookup_color(&ctx, &["accent_bg_color", "theme_selected_bg_color"])
+        .unwrap_or(Color::new(53, 132, 228, 255));
+    let mut palette = SystemPalette::from_theme(theme, accent);
+    let set = |field: &mut Color, names: &[&str]| {
+        if let Some(c) = lookup_color(&ctx, names) {
+            *field = c;
+        }
+    };
+    set(&mut palette.window, &["window_bg_color", "theme_bg_color"]);
+    palette.window_text = rgba_to_color(&w.color());
+    set(
+        &mut palette.highlight_text,
+        &["accent_fg_color", "theme_selected_fg_color"],
+    );
+    set(&mut palette.disabled_text, &["insensitive_fg_color"]);
+    set(&mut palette.border, &["borders"]);
+    // GTK themes don't name the tooltip colors, and the tooltips are usually
+    // shown in the inverted colors.
+    palette.tooltip = palette.window_text.mix(&palette.window, 0.15);
+    palette.tooltip_text = palette.window;
+    SYSTEM_PALETTE.set(Some(palette));
+}
+
+/// Get the colors of the current theme, read from the windows.
+pub fn system_palette() -> Result<SystemPalette> {
+    SYSTEM_PALETTE.get().ok_or(Error::NoColorTheme)
+}
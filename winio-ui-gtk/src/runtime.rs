@@ -11,6 +11,7 @@ use gtk4::{
         translate::{FromGlibPtrBorrow, FromGlibPtrFull, IntoGlibPtr},
     },
 };
+use winio_primitive::LayoutDirection;
 
 use crate::Result;
 
@@ -34,6 +35,14 @@ impl App {
         Ok(())
     }
 
+    pub fn set_layout_direction(&mut self, direction: LayoutDirection) -> Result<()> {
+        gtk4::Widget::set_default_direction(match direction {
+            LayoutDirection::LeftToRight => gtk4::TextDirection::Ltr,
+            LayoutDirection::RightToLeft => gtk4::TextDirection::Rtl,
+        });
+        Ok(())
+    }
+
     pub fn block_on<F: Future>(&self, future: F) -> F::Output {
         winio_pollable::block_on(future, glib_waker(self.ctx.clone()), || {
             self.ctx.iteration(true);
@@ -1,6 +1,7 @@
 use std::{
     cell::RefCell,
-    f64::consts::{FRAC_PI_2, PI},
+    f64::consts::{FRAC_PI_2, PI, TAU},
+    ops::Range,
     rc::Rc,
 };
 
@@ -8,25 +9,36 @@ use compio_log::error;
 use gtk4::{
     EventControllerMotion, EventControllerScroll, EventControllerScrollFlags, GestureClick,
     cairo::{
-        Content, Context, Format, ImageSurface, LinearGradient, Matrix, RadialGradient,
-        RecordingSurface,
+        self, Content, Context, Format, Gradient, ImageSurface, LinearGradient, Matrix,
+        PathSegment, RadialGradient, RecordingSurface, SurfacePattern,
     },
-    gdk::ScrollUnit,
+    gdk::{ModifierType, ScrollUnit},
     glib::{Propagation, object::Cast},
-    pango::{FontDescription, Layout, SCALE as PANGO_SCALE, Style, Weight},
-    prelude::{DrawingAreaExtManual, GestureSingleExt, WidgetExt},
+    pango::{
+        Alignment, AttrColor, AttrFontDesc, AttrInt, AttrList, Attribute, EllipsizeMode,
+        FontDescription, Layout, SCALE as PANGO_SCALE, Underline, WrapMode,
+    },
+    prelude::{
+        DrawingAreaExt, DrawingAreaExtManual, EventControllerExt, GestureSingleExt, WidgetExt,
+    },
 };
-use image::{DynamicImage, Rgba, Rgba32FImage};
+use image::{DynamicImage, Rgba, Rgba32FImage, RgbaImage};
 use inherit_methods_macro::inherit_methods;
 use pangocairo::functions::show_layout;
 use winio_callback::Callback;
 use winio_handle::AsContainer;
 use winio_primitive::{
-    BrushPen, Font, LinearGradientBrush, MouseButton, Point, RadialGradientBrush, Rect, RectBox,
-    RelativePoint, RelativeToLogical, Size, SolidColorBrush, Transform, Vector,
+    BlendMode, BrushPen, ConicGradientBrush, Cursor, FillRule, Font, GradientStop, HAlign,
+    ImageBrush, LineCap, LineJoin, LineMetrics, LinearGradientBrush, Modifiers, MouseButton,
+    MouseButtonEvent, Point, RadialGradientBrush, Rect, RectBox, RelativePoint, RelativeToLogical,
+    Size, SolidColorBrush, SpreadMode, StrokeStyle, TextFormat, TextHitTest, TextSpan, Transform,
+    Vector,
 };
 
-use crate::{GlobalRuntime, Result, widgets::Widget};
+use crate::{
+    GlobalRuntime, Result,
+    widgets::{Widget, font_to_attrs, font_to_desc},
+};
 
 #[derive(Debug)]
 pub struct Canvas {
@@ -34,9 +46,17 @@ pub struct Canvas {
     on_pressed: Rc<Callback<MouseButton>>,
     on_released: Rc<Callback<MouseButton>>,
     on_scroll: Rc<Callback<Vector>>,
+    on_button_pressed: Rc<Callback<MouseButtonEvent>>,
+    on_button_released: Rc<Callback<MouseButtonEvent>>,
+    on_enter: Rc<Callback<Point>>,
+    on_leave: Rc<Callback>,
+    on_paint: Rc<Callback<Rect>>,
     widget: gtk4::DrawingArea,
     handle: Widget,
-    surface: Rc<RefCell<RecordingSurface>>,
+    surface: Rc<RefCell<Option<ImageSurface>>>,
+    /// The last front buffer, reused as the back buffer if the size is the
+    /// same.
+    back: Option<ImageSurface>,
 }
 
 #[inherit_methods(from = "self.handle")]
@@ -49,23 +69,47 @@ impl Canvas {
         let on_pressed = Rc::new(Callback::new());
         let on_released = Rc::new(Callback::new());
         let on_scroll = Rc::new(Callback::new());
+        let on_button_pressed = Rc::new(Callback::new());
+        let on_button_released = Rc::new(Callback::new());
+        let on_enter = Rc::new(Callback::new());
+        let on_leave = Rc::new(Callback::new());
+        let on_paint = Rc::new(Callback::new());
 
-        let surface = Rc::new(RefCell::new(RecordingSurface::create(
-            Content::ColorAlpha,
-            None,
-        )?));
+        let surface = Rc::new(RefCell::new(None::<ImageSurface>));
 
         widget.set_draw_func({
             let surface = surface.clone();
             move |_, ctx, _, _| {
+                let surface = surface.borrow();
+                let Some(surface) = surface.as_ref() else {
+                    return;
+                };
                 if let Err(_e) = (|| {
-                    ctx.set_source_surface(&*surface.borrow(), 0.0, 0.0)?;
+                    ctx.set_source_surface(surface, 0.0, 0.0)?;
                     ctx.paint()
                 })() {
                     error!("Canvas draw error: {_e:?}");
                 }
             }
         });
+        widget.connect_resize({
+            let on_paint = on_paint.clone();
+            move |_, width, height| {
+                on_paint.signal::<GlobalRuntime>(Rect::new(
+                    Point::zero(),
+                    Size::new(width as _, height as _),
+                ));
+            }
+        });
+        widget.connect_scale_factor_notify({
+            let on_paint = on_paint.clone();
+            move |widget| {
+                on_paint.signal::<GlobalRuntime>(Rect::new(
+                    Point::zero(),
+                    Size::new(widget.width() as _, widget.height() as _),
+                ));
+            }
+        });
 
         let controller = EventControllerMotion::new();
         controller.connect_motion({
@@ -74,6 +118,18 @@ impl Canvas {
                 on_motion.signal::<GlobalRuntime>(Point::new(x, y));
             }
         });
+        controller.connect_enter({
+            let on_enter = on_enter.clone();
+            move |_, x, y| {
+                on_enter.signal::<GlobalRuntime>(Point::new(x, y));
+            }
+        });
+        controller.connect_leave({
+            let on_leave = on_leave.clone();
+            move |_| {
+                on_leave.signal::<GlobalRuntime>(());
+            }
+        });
         widget.add_controller(controller);
 
         const fn gtk_current_button(b: u32) -> MouseButton {
@@ -85,18 +141,45 @@ impl Canvas {
             }
         }
 
+        fn gtk_button_event(controller: &GestureClick, n: i32, x: f64, y: f64) -> MouseButtonEvent {
+            let state = controller.current_event_state();
+            let mut modifiers = Modifiers::empty();
+            modifiers.set(Modifiers::Shift, state.contains(ModifierType::SHIFT_MASK));
+            modifiers.set(
+                Modifiers::Control,
+                state.contains(ModifierType::CONTROL_MASK),
+            );
+            modifiers.set(Modifiers::Alt, state.contains(ModifierType::ALT_MASK));
+            modifiers.set(
+                Modifiers::Meta,
+                state.intersects(ModifierType::SUPER_MASK | ModifierType::META_MASK),
+            );
+            MouseButtonEvent {
+                button: gtk_current_button(controller.current_button()),
+                pos: Point::new(x, y),
+                modifiers,
+                clicks: n.max(1) as u32,
+            }
+        }
+
         let controller = GestureClick::new();
+        // Listen to all buttons.
+        controller.set_button(0);
         controller.connect_pressed({
             let on_pressed = on_pressed.clone();
-            move |controller, _, _, _| {
+            let on_button_pressed = on_button_pressed.clone();
+            move |controller, n, x, y| {
                 on_pressed.signal::<GlobalRuntime>(gtk_current_button(controller.current_button()));
+                on_button_pressed.signal::<GlobalRuntime>(gtk_button_event(controller, n, x, y));
             }
         });
         controller.connect_released({
             let on_released = on_released.clone();
-            move |controller, _, _, _| {
+            let on_button_released = on_button_released.clone();
+            move |controller, n, x, y| {
                 on_released
                     .signal::<GlobalRuntime>(gtk_current_button(controller.current_button()));
+                on_button_released.signal::<GlobalRuntime>(gtk_button_event(controller, n, x, y));
             }
         });
         widget.add_controller(controller);
@@ -120,9 +203,15 @@ impl Canvas {
             on_pressed,
             on_released,
             on_scroll,
+            on_button_pressed,
+            on_button_released,
+            on_enter,
+            on_leave,
+            on_paint,
             widget,
             handle,
             surface,
+            back: None,
         })
     }
 
@@ -146,13 +235,52 @@ impl Canvas {
 
     pub fn set_tooltip(&mut self, s: impl AsRef<str>) -> Result<()>;
 
-    pub fn context(&mut self) -> Result<DrawingContext<'_>> {
-        let surface = RecordingSurface::create(Content::ColorAlpha, None)?;
+    pub fn set_cursor(&mut self, cursor: Cursor) -> Result<()>;
+
+    pub fn invalidate(&mut self, rect: Rect) -> Result<()> {
+        self.on_paint.signal::<()>(rect);
+        Ok(())
+    }
+
+    pub fn context(&mut self, dirty: Option<Rect>) -> Result<DrawingContext<'_>> {
+        let size = self.size()?;
+        let scale = self.widget.scale_factor();
+        let width = (size.width * scale as f64).ceil() as i32;
+        let height = (size.height * scale as f64).ceil() as i32;
+        let surface = match self.back.take() {
+            Some(back) if back.width() == width && back.height() == height => back,
+            _ => ImageSurface::create(Format::ARgb32, width, height)?,
+        };
+        surface.set_device_scale(scale as f64, scale as f64);
         let ctx = Context::new(&surface)?;
+        // Keep the front buffer outside the dirty rectangle.
+        if let Some(dirty) = dirty
+            && let Some(front) = self.surface.borrow().as_ref()
+            && front.width() == surface.width()
+            && front.height() == surface.height()
+        {
+            ctx.set_operator(cairo::Operator::Source);
+            ctx.set_source_surface(front, 0.0, 0.0)?;
+            ctx.paint()?;
+            ctx.rectangle(
+                dirty.origin.x,
+                dirty.origin.y,
+                dirty.size.width,
+                dirty.size.height,
+            );
+            ctx.clip();
+        }
+        // Clear the dirty rectangle, or the whole buffer, which may be reused
+        // and hold an older frame.
+        ctx.set_operator(cairo::Operator::Clear);
+        ctx.paint()?;
+        ctx.set_operator(cairo::Operator::Over);
         Ok(DrawingContext {
-            surface: Some(surface),
+            surface,
             ctx,
-            canvas: self,
+            target: DrawingTarget::Canvas(self),
+            clips: 0,
+            layers: vec![],
         })
     }
 
@@ -171,14 +299,91 @@ impl Canvas {
     pub async fn wait_mouse_wheel(&self) -> Vector {
         self.on_scroll.wait().await
     }
+
+    pub async fn wait_mouse_button_down(&self) -> MouseButtonEvent {
+        self.on_button_pressed.wait().await
+    }
+
+    pub async fn wait_mouse_button_up(&self) -> MouseButtonEvent {
+        self.on_button_released.wait().await
+    }
+
+    pub async fn wait_mouse_enter(&self) -> Point {
+        self.on_enter.wait().await
+    }
+
+    pub async fn wait_mouse_leave(&self) {
+        self.on_leave.wait().await
+    }
+
+    pub async fn wait_paint(&self) -> Rect {
+        self.on_paint.wait().await
+    }
 }
 
 winio_handle::impl_as_widget!(Canvas, handle);
 
 pub struct DrawingContext<'a> {
-    surface: Option<RecordingSurface>,
+    surface: ImageSurface,
     ctx: Context,
-    canvas: &'a mut Canvas,
+    target: DrawingTarget<'a>,
+    clips: usize,
+    layers: Vec<Layer>,
+}
+
+/// The owner of a [`DrawingContext`].
+enum DrawingTarget<'a> {
+    /// The surface is a back buffer, swapped to the front when dropped.
+    Canvas(&'a mut Canvas),
+    /// The surface of the bitmap is drawn in place.
+    Bitmap(&'a mut DrawingBitmap),
+}
+
+impl DrawingTarget<'_> {
+    fn widget(&self) -> &gtk4::DrawingArea {
+        match self {
+            Self::Canvas(canvas) => &canvas.widget,
+            Self::Bitmap(bitmap) => &bitmap.widget,
+        }
+    }
+
+    fn width(&self) -> i32 {
+        match self {
+            Self::Canvas(canvas) => canvas.widget.width(),
+            Self::Bitmap(bitmap) => bitmap.size.width as i32,
+        }
+    }
+}
+
+/// A pushed group, with the clips pushed before it.
+struct Layer {
+    clips: usize,
+    opacity: f64,
+    blend: BlendMode,
+}
+
+fn fill_rule(rule: FillRule) -> cairo::FillRule {
+    match rule {
+        FillRule::EvenOdd => cairo::FillRule::EvenOdd,
+        _ => cairo::FillRule::Winding,
+    }
+}
+
+fn operator(blend: BlendMode) -> cairo::Operator {
+    match blend {
+        BlendMode::Multiply => cairo::Operator::Multiply,
+        BlendMode::Screen => cairo::Operator::Screen,
+        BlendMode::Overlay => cairo::Operator::Overlay,
+        BlendMode::Darken => cairo::Operator::Darken,
+        BlendMode::Lighten => cairo::Operator::Lighten,
+        BlendMode::ColorDodge => cairo::Operator::ColorDodge,
+        BlendMode::ColorBurn => cairo::Operator::ColorBurn,
+        BlendMode::HardLight => cairo::Operator::HardLight,
+        BlendMode::SoftLight => cairo::Operator::SoftLight,
+        BlendMode::Difference => cairo::Operator::Difference,
+        BlendMode::Exclusion => cairo::Operator::Exclusion,
+        _ => cairo::Operator::Over,
+    }
 }
 
 #[inline]
@@ -218,6 +423,66 @@ impl DrawingContext<'_> {
         ))
     }
 
+    pub fn push_clip_rect(&mut self, rect: Rect) -> Result<()> {
+        self.ctx.save()?;
+        self.path_rect(rect);
+        self.ctx.clip();
+        self.clips += 1;
+        Ok(())
+    }
+
+    pub fn push_clip_path(&mut self, path: &DrawingPath) -> Result<()> {
+        let p = path.ctx.copy_path()?;
+        self.ctx.save()?;
+        self.ctx.new_path();
+        self.ctx.append_path(&p);
+        self.ctx.clip();
+        self.clips += 1;
+        Ok(())
+    }
+
+    pub fn pop_clip(&mut self) -> Result<()> {
+        if self.clips == 0 {
+            return Ok(());
+        }
+        // Only the clip is restored; the transform is kept.
+        let matrix = self.ctx.matrix();
+        self.ctx.restore()?;
+        self.clips -= 1;
+        self.ctx.set_matrix(matrix);
+        Ok(())
+    }
+
+    pub fn push_layer(&mut self, opacity: f64, blend: BlendMode) -> Result<()> {
+        self.ctx.push_group();
+        self.layers.push(Layer {
+            clips: std::mem::take(&mut self.clips),
+            opacity,
+            blend,
+        });
+        Ok(())
+    }
+
+    pub fn pop_layer(&mut self) -> Result<()> {
+        let Some(layer) = self.layers.pop() else {
+            return Ok(());
+        };
+        // Only the group is popped; the transform is kept.
+        let matrix = self.ctx.matrix();
+        for _ in 0..self.clips {
+            self.ctx.restore()?;
+        }
+        self.clips = layer.clips;
+        let pattern = self.ctx.pop_group()?;
+        self.ctx.save()?;
+        self.ctx.set_source(&pattern)?;
+        self.ctx.set_operator(operator(layer.blend));
+        self.ctx.paint_with_alpha(layer.opacity)?;
+        self.ctx.restore()?;
+        self.ctx.set_matrix(matrix);
+        Ok(())
+    }
+
     #[inline]
     fn set_brush(&self, brush: impl Brush, rect: Rect) -> Result<()> {
         brush.set(&self.ctx, to_trans(rect))
@@ -292,22 +557,28 @@ impl DrawingContext<'_> {
     }
 
     pub fn draw_path(&mut self, pen: impl Pen, path: &DrawingPath) -> Result<()> {
-        let (x, y, width, height) = path.surface.ink_extents();
-        let rect = Rect::new(Point::new(x, y), Size::new(width, height));
-        pen.set(&path.ctx, to_trans(rect))?;
-        path.ctx.stroke()?;
-        self.ctx.set_source_surface(&path.surface, 0.0, 0.0)?;
-        self.ctx.paint()?;
+        let rect = path.bounds()?;
+        self.ctx.new_path();
+        self.ctx.append_path(&path.ctx.copy_path()?);
+        self.set_pen(pen, rect)?;
+        self.ctx.stroke()?;
         Ok(())
     }
 
-    pub fn fill_path(&mut self, brush: impl Brush, path: &DrawingPath) -> Result<()> {
-        let (x, y, width, height) = path.surface.ink_extents();
-        let rect = Rect::new(Point::new(x, y), Size::new(width, height));
-        brush.set(&path.ctx, to_trans(rect))?;
-        path.ctx.stroke()?;
-        self.ctx.set_source_surface(&path.surface, 0.0, 0.0)?;
-        self.ctx.paint()?;
+    pub fn fill_path(
+        &mut self,
+        brush: impl Brush,
+        path: &DrawingPath,
+        rule: FillRule,
+    ) -> Result<()> {
+        let rect = path.bounds()?;
+        self.ctx.new_path();
+        self.ctx.append_path(&path.ctx.copy_path()?);
+        self.set_brush(brush, rect)?;
+        self.ctx.set_fill_rule(fill_rule(rule));
+        let res = self.ctx.fill();
+        self.ctx.set_fill_rule(cairo::FillRule::Winding);
+        res?;
         Ok(())
     }
 
@@ -383,17 +654,10 @@ impl DrawingContext<'_> {
     }
 
     fn measure_str_impl(&self, font: &Font, text: &str) -> (Size, Layout) {
-        let layout = self.canvas.widget.create_pango_layout(Some(text));
-        let mut desp = FontDescription::from_string(&font.family);
-        desp.set_size((font.size / 1.33) as i32 * PANGO_SCALE);
-        if font.italic {
-            desp.set_style(Style::Italic);
-        }
-        if font.bold {
-            desp.set_weight(Weight::Bold);
-        }
-        layout.set_font_description(Some(&desp));
-        layout.set_width(self.canvas.widget.width() * PANGO_SCALE);
+        let layout = self.target.widget().create_pango_layout(Some(text));
+        layout.set_font_description(Some(&canvas_font_desc(font)));
+        layout.set_attributes(Some(&font_to_attrs(font)));
+        layout.set_width(self.target.width() * PANGO_SCALE);
 
         let (width, height) = layout.pixel_size();
         (Size::new(width as f64, height as f64), layout)
@@ -424,6 +688,96 @@ impl DrawingContext<'_> {
         Ok(self.measure_str_impl(&font, text).0)
     }
 
+    pub fn create_text_layout(
+        &self,
+        text: &str,
+        font: Font,
+        spans: &[TextSpan],
+        format: &TextFormat,
+    ) -> Result<TextLayout> {
+        let layout = self.target.widget().create_pango_layout(Some(text));
+        layout.set_font_description(Some(&canvas_font_desc(&font)));
+        let attrs = font_to_attrs(&font);
+        for span in spans {
+            let range = &span.range;
+            if let Some(font) = &span.font {
+                insert_attr(&attrs, AttrFontDesc::new(&canvas_font_desc(font)), range);
+                for attr in font_to_attrs(font).iterator().attrs() {
+                    insert_attr(&attrs, attr, range);
+                }
+            }
+            if let Some(color) = span.color {
+                insert_attr(
+                    &attrs,
+                    AttrColor::new_foreground(
+                        color.r as u16 * 257,
+                        color.g as u16 * 257,
+                        color.b as u16 * 257,
+                    ),
+                    range,
+                );
+                insert_attr(
+                    &attrs,
+                    AttrInt::new_foreground_alpha(color.a as u16 * 257),
+                    range,
+                );
+            }
+            if let Some(underline) = span.underline {
+                insert_attr(
+                    &attrs,
+                    AttrInt::new_underline(if underline {
+                        Underline::Single
+                    } else {
+                        Underline::None
+                    }),
+                    range,
+                );
+            }
+        }
+        layout.set_attributes(Some(&attrs));
+
+        if let Some(width) = format.max_width {
+            layout.set_width((width * PANGO_SCALE as f64) as i32);
+            layout.set_wrap(WrapMode::WordChar);
+        }
+        layout.set_alignment(match format.align {
+            HAlign::Center => Alignment::Center,
+            HAlign::Right => Alignment::Right,
+            _ => Alignment::Left,
+        });
+        layout.set_justify(format.align == HAlign::Stretch);
+        layout.set_line_spacing(format.line_spacing as f32);
+        if let Some(lines) = format.max_lines {
+            if format.ellipsis {
+                layout.set_ellipsize(EllipsizeMode::End);
+                layout.set_height(-(lines.max(1) as i32));
+            }
+            // The height limits the lines of each paragraph only.
+            if layout.line_count() as usize > lines {
+                let mut iter = layout.iter();
+                for _ in 0..lines {
+                    iter.next_line();
+                }
+                let end = iter.index() as usize;
+                layout.set_text(&text[..end]);
+            }
+        }
+        Ok(TextLayout { layout })
+    }
+
+    pub fn draw_text_layout(
+        &mut self,
+        brush: impl Brush,
+        layout: &TextLayout,
+        pos: Point,
+    ) -> Result<()> {
+        let rect = Rect::new(pos, layout.size()?);
+        self.ctx.move_to(pos.x, pos.y);
+        self.set_brush(brush, rect)?;
+        show_layout(&self.ctx, &layout.layout);
+        Ok(())
+    }
+
     pub fn create_image(&self, image: DynamicImage) -> Result<DrawingImage> {
         DrawingImage::new(image)
     }
@@ -465,6 +819,10 @@ impl DrawingContext<'_> {
         DrawingPathBuilder::new(start)
     }
 
+    pub fn create_bitmap(&self, size: Size) -> Result<DrawingBitmap> {
+        DrawingBitmap::new(self.target.widget().clone(), size)
+    }
+
     pub fn close(self) -> Result<()> {
         Ok(())
     }
@@ -472,26 +830,269 @@ impl DrawingContext<'_> {
 
 impl Drop for DrawingContext<'_> {
     fn drop(&mut self) {
-        *self.canvas.surface.borrow_mut() =
-            self.surface.take().expect("DrawingContext dropped twice");
-        self.canvas.widget.queue_draw();
+        while !self.layers.is_empty() {
+            if let Err(_e) = self.pop_layer() {
+                error!("Failed to pop layer: {_e:?}");
+                break;
+            }
+        }
+        self.surface.flush();
+        if let DrawingTarget::Canvas(canvas) = &mut self.target {
+            canvas.back = canvas.surface.borrow_mut().replace(self.surface.clone());
+            canvas.widget.queue_draw();
+        }
+    }
+}
+
+/// An offscreen image surface, scaled by the scale factor of the canvas.
+pub struct DrawingBitmap {
+    surface: ImageSurface,
+    widget: gtk4::DrawingArea,
+    size: Size,
+}
+
+impl DrawingBitmap {
+    fn new(widget: gtk4::DrawingArea, size: Size) -> Result<Self> {
+        let scale = widget.scale_factor();
+        let surface = ImageSurface::create(
+            Format::ARgb32,
+            (size.width * scale as f64).ceil() as i32,
+            (size.height * scale as f64).ceil() as i32,
+        )?;
+        surface.set_device_scale(scale as f64, scale as f64);
+        Ok(Self {
+            surface,
+            widget,
+            size,
+        })
+    }
+
+    pub fn size(&self) -> Result<Size> {
+        Ok(self.size)
+    }
+
+    pub fn context(&mut self) -> Result<DrawingContext<'_>> {
+        let surface = self.surface.clone();
+        let ctx = Context::new(&surface)?;
+        Ok(DrawingContext {
+            surface,
+            ctx,
+            target: DrawingTarget::Bitmap(self),
+            clips: 0,
+            layers: vec![],
+        })
+    }
+
+    /// Copy the pixels into an unscaled surface.
+    fn copy_surface(&self) -> Result<ImageSurface> {
+        let surface =
+            ImageSurface::create(Format::ARgb32, self.surface.width(), self.surface.height())?;
+        let ctx = Context::new(&surface)?;
+        let (sx, sy) = self.surface.device_scale();
+        ctx.scale(sx, sy);
+        ctx.set_source_surface(&self.surface, 0.0, 0.0)?;
+        ctx.set_operator(cairo::Operator::Source);
+        ctx.paint()?;
+        drop(ctx);
+        surface.flush();
+        Ok(surface)
+    }
+
+    pub fn to_image(&self) -> Result<DrawingImage> {
+        Ok(DrawingImage(self.copy_surface()?))
+    }
+
+    pub fn to_rgba(&self) -> Result<RgbaImage> {
+        let mut surface = self.copy_surface()?;
+        let width = surface.width() as u32;
+        let height = surface.height() as u32;
+        let stride = surface.stride() as usize;
+        let data = surface.data()?;
+        Ok(RgbaImage::from_fn(width, height, |x, y| {
+            let offset = y as usize * stride + x as usize * 4;
+            // Premultiplied ARGB in native endian.
+            let pixel = u32::from_ne_bytes([
+                data[offset],
+                data[offset + 1],
+                data[offset + 2],
+                data[offset + 3],
+            ]);
+            let [a, r, g, b] = pixel.to_be_bytes();
+            let unmultiply = |c: u8| {
+                if a == 0 {
+                    0
+                } else {
+                    ((c as u32 * 255 + a as u32 / 2) / a as u32).min(255) as u8
+                }
+            };
+            Rgba([unmultiply(r), unmultiply(g), unmultiply(b), a])
+        }))
+    }
+}
+
+/// The canvas measures the font size in pixels.
+fn canvas_font_desc(font: &Font) -> FontDescription {
+    let mut desc = font_to_desc(font);
+    desc.set_size((font.size / 1.33) as i32 * PANGO_SCALE);
+    desc
+}
+
+fn insert_attr(attrs: &AttrList, attr: impl Into<Attribute>, range: &Range<usize>) {
+    let mut attr = attr.into();
+    attr.set_start_index(range.start as _);
+    attr.set_end_index(range.end as _);
+    attrs.insert(attr);
+}
+
+#[inline]
+fn from_pango(v: i32) -> f64 {
+    v as f64 / PANGO_SCALE as f64
+}
+
+#[inline]
+fn to_pango(v: f64) -> i32 {
+    (v * PANGO_SCALE as f64) as i32
+}
+
+pub struct TextLayout {
+    layout: Layout,
+}
+
+impl TextLayout {
+    pub fn size(&self) -> Result<Size> {
+        let (width, height) = self.layout.pixel_size();
+        Ok(Size::new(width as f64, height as f64))
+    }
+
+    pub fn line_metrics(&self) -> Result<Vec<LineMetrics>> {
+        let len = self.layout.text().len();
+        let mut lines = vec![];
+        let mut iter = self.layout.iter();
+        loop {
+            let start = iter.index() as usize;
+            let (_, rect) = iter.line_extents();
+            let baseline = from_pango(iter.baseline());
+            let has_next = iter.next_line();
+            let end = if has_next { iter.index() as usize } else { len };
+            lines.push(LineMetrics {
+                range: start..end,
+                rect: Rect::new(
+                    Point::new(from_pango(rect.x()), from_pango(rect.y())),
+                    Size::new(from_pango(rect.width()), from_pango(rect.height())),
+                ),
+                baseline,
+            });
+            if !has_next {
+                break;
+            }
+        }
+        Ok(lines)
+    }
+
+    pub fn hit_test(&self, p: Point) -> Result<TextHitTest> {
+        let (inside, index, trailing) = self.layout.xy_to_index(to_pango(p.x), to_pango(p.y));
+        let index = index as usize;
+        // The trailing count is in graphemes, which are approximated by chars.
+        let text = self.layout.text();
+        let index = index
+            + text[index..]
+                .chars()
+                .take(trailing as usize)
+                .map(char::len_utf8)
+                .sum::<usize>();
+        Ok(TextHitTest { index, inside })
+    }
+
+    pub fn caret_rect(&self, index: usize) -> Result<Rect> {
+        let index = index.min(self.layout.text().len());
+        let (strong, _) = self.layout.cursor_pos(index as i32);
+        Ok(Rect::new(
+            Point::new(from_pango(strong.x()), from_pango(strong.y())),
+            Size::new(0.0, from_pango(strong.height())),
+        ))
     }
 }
 
 pub type DrawingPath = DrawingPathBuilder;
 
+impl DrawingPath {
+    pub fn bounds(&self) -> Result<Rect> {
+        let (x1, y1, x2, y2) = self.ctx.path_extents()?;
+        Ok(RectBox::new(Point::new(x1, y1), Point::new(x2, y2)).to_rect())
+    }
+
+    pub fn contains(&self, p: Point, rule: FillRule) -> Result<bool> {
+        self.ctx.set_fill_rule(fill_rule(rule));
+        Ok(self.ctx.in_fill(p.x, p.y)?)
+    }
+
+    pub fn stroke_contains(&self, p: Point, width: f64) -> Result<bool> {
+        self.ctx.set_line_width(width);
+        Ok(self.ctx.in_stroke(p.x, p.y)?)
+    }
+
+    pub fn length(&self) -> Result<f64> {
+        let mut length = 0.0;
+        let mut start = Point::zero();
+        let mut current = Point::zero();
+        for segment in self.ctx.copy_path_flat()?.iter() {
+            match segment {
+                PathSegment::MoveTo((x, y)) => {
+                    start = Point::new(x, y);
+                    current = start;
+                }
+                PathSegment::LineTo((x, y)) => {
+                    let p = Point::new(x, y);
+                    length += (p - current).length();
+                    current = p;
+                }
+                PathSegment::ClosePath => {
+                    length += (start - current).length();
+                    current = start;
+                }
+                // The flattened path contains no curve.
+                PathSegment::CurveTo(..) => {}
+            }
+        }
+        Ok(length)
+    }
+
+    pub fn transform(&self, transform: &Transform) -> Result<DrawingPath> {
+        let path = Self::empty()?;
+        // The path is appended in the user space, and stored in the device
+        // space.
+        path.ctx.set_matrix(Matrix::new(
+            transform.m11,
+            transform.m12,
+            transform.m21,
+            transform.m22,
+            transform.m31,
+            transform.m32,
+        ));
+        path.ctx.append_path(&self.ctx.copy_path()?);
+        path.ctx.identity_matrix();
+        Ok(path)
+    }
+}
+
+/// The path is kept in a context, which holds a reference to its recording
+/// surface.
 pub struct DrawingPathBuilder {
-    surface: RecordingSurface,
     ctx: Context,
 }
 
 impl DrawingPathBuilder {
-    fn new(start: Point) -> Result<Self> {
+    fn empty() -> Result<Self> {
         let surface = RecordingSurface::create(Content::ColorAlpha, None)?;
         let ctx = Context::new(&surface)?;
         ctx.new_path();
-        ctx.move_to(start.x, start.y);
-        Ok(Self { surface, ctx })
+        Ok(Self { ctx })
+    }
+
+    fn new(start: Point) -> Result<Self> {
+        let builder = Self::empty()?;
+        builder.ctx.move_to(start.x, start.y);
+        Ok(builder)
     }
 
     pub fn add_line(&mut self, p: Point) -> Result<()> {
@@ -499,6 +1100,16 @@ impl DrawingPathBuilder {
         Ok(())
     }
 
+    pub fn move_to(&mut self, p: Point) -> Result<()> {
+        self.ctx.move_to(p.x, p.y);
+        Ok(())
+    }
+
+    pub fn close_figure(&mut self) -> Result<()> {
+        self.ctx.close_path();
+        Ok(())
+    }
+
     pub fn add_arc(
         &mut self,
         center: Point,
@@ -526,6 +1137,38 @@ impl DrawingPathBuilder {
         Ok(())
     }
 
+    pub fn add_quad(&mut self, p1: Point, p2: Point) -> Result<()> {
+        // Cairo has no quadratic curve; elevate it to a cubic one.
+        let (x, y) = self.ctx.current_point()?;
+        let p0 = Point::new(x, y);
+        let c1 = p0 + (p1 - p0) * (2.0 / 3.0);
+        let c2 = p2 + (p1 - p2) * (2.0 / 3.0);
+        self.add_bezier(c1, c2, p2)
+    }
+
+    pub fn add_rect(&mut self, rect: Rect) -> Result<()> {
+        self.ctx.rectangle(
+            rect.origin.x,
+            rect.origin.y,
+            rect.size.width,
+            rect.size.height,
+        );
+        Ok(())
+    }
+
+    pub fn add_ellipse(&mut self, rect: Rect) -> Result<()> {
+        let save_matrix = self.ctx.matrix();
+        let center = rect.center();
+        self.ctx.new_sub_path();
+        self.ctx.translate(center.x, center.y);
+        self.ctx
+            .scale(rect.size.width / 2.0, rect.size.height / 2.0);
+        self.ctx.arc(0.0, 0.0, 1.0, 0.0, TAU);
+        self.ctx.close_path();
+        self.ctx.set_matrix(save_matrix);
+        Ok(())
+    }
+
     pub fn build(self, close: bool) -> Result<DrawingPath> {
         if close {
             self.ctx.close_path();
@@ -548,30 +1191,44 @@ impl<B: Brush> Brush for &'_ B {
 
 impl Brush for SolidColorBrush {
     fn set(&self, ctx: &Context, _trans: RelativeToLogical) -> Result<()> {
+        let color = self.faded_color();
         ctx.set_source_rgba(
-            self.color.r as f64 / 255.0,
-            self.color.g as f64 / 255.0,
-            self.color.b as f64 / 255.0,
-            self.color.a as f64 / 255.0,
+            color.r as f64 / 255.0,
+            color.g as f64 / 255.0,
+            color.b as f64 / 255.0,
+            color.a as f64 / 255.0,
         );
         Ok(())
     }
 }
 
+fn extend(spread: SpreadMode) -> cairo::Extend {
+    match spread {
+        SpreadMode::Repeat => cairo::Extend::Repeat,
+        SpreadMode::Reflect => cairo::Extend::Reflect,
+        _ => cairo::Extend::Pad,
+    }
+}
+
+fn add_color_stops(p: &Gradient, stops: &[GradientStop]) {
+    for stop in stops {
+        p.add_color_stop_rgba(
+            stop.pos,
+            stop.color.r as f64 / 255.0,
+            stop.color.g as f64 / 255.0,
+            stop.color.b as f64 / 255.0,
+            stop.color.a as f64 / 255.0,
+        );
+    }
+}
+
 impl Brush for LinearGradientBrush {
     fn set(&self, ctx: &Context, trans: RelativeToLogical) -> Result<()> {
         let start = trans.transform_point(self.start);
         let end = trans.transform_point(self.end);
         let p = LinearGradient::new(start.x, start.y, end.x, end.y);
-        for stop in &self.stops {
-            p.add_color_stop_rgba(
-                stop.pos,
-                stop.color.r as f64 / 255.0,
-                stop.color.g as f64 / 255.0,
-                stop.color.b as f64 / 255.0,
-                stop.color.a as f64 / 255.0,
-            );
-        }
+        add_color_stops(&p, &self.faded_stops());
+        p.set_extend(extend(self.spread));
         ctx.set_source(&p)?;
         Ok(())
     }
@@ -591,15 +1248,50 @@ impl Brush for RadialGradientBrush {
         p.set_matrix(Matrix::new(
             trans.m11, trans.m12, trans.m21, trans.m22, trans.m31, trans.m32,
         ));
-        for stop in &self.stops {
-            p.add_color_stop_rgba(
-                stop.pos,
-                stop.color.r as f64 / 255.0,
-                stop.color.g as f64 / 255.0,
-                stop.color.b as f64 / 255.0,
-                stop.color.a as f64 / 255.0,
+        add_color_stops(&p, &self.faded_stops());
+        p.set_extend(extend(self.spread));
+        ctx.set_source(&p)?;
+        Ok(())
+    }
+}
+
+impl Brush for ConicGradientBrush {
+    fn set(&self, ctx: &Context, trans: RelativeToLogical) -> Result<()> {
+        // Cairo has no conic gradient, so it is rasterized for the area.
+        let width = trans.m11.abs().ceil().clamp(1.0, 4096.0) as u32;
+        let height = trans.m22.abs().ceil().clamp(1.0, 4096.0) as u32;
+        let image = RgbaImage::from_fn(width, height, |x, y| {
+            let p = RelativePoint::new(
+                (x as f64 + 0.5) / width as f64,
+                (y as f64 + 0.5) / height as f64,
             );
-        }
+            let c = self.color_at(p);
+            Rgba([c.r, c.g, c.b, c.a])
+        });
+        let image = DrawingImage::new(DynamicImage::ImageRgba8(image))?;
+        ImageBrush::new(&image).set(ctx, trans)
+    }
+}
+
+impl<I: AsRef<DrawingImage>> Brush for ImageBrush<I> {
+    fn set(&self, ctx: &Context, trans: RelativeToLogical) -> Result<()> {
+        let image = self.image.as_ref();
+        let surface = if self.opacity < 1.0 {
+            let surface = ImageSurface::create(Format::ARgb32, image.0.width(), image.0.height())?;
+            let faded = Context::new(&surface)?;
+            faded.set_source_surface(&image.0, 0.0, 0.0)?;
+            faded.paint_with_alpha(self.opacity.max(0.0))?;
+            drop(faded);
+            surface
+        } else {
+            image.0.clone()
+        };
+        let p = SurfacePattern::create(&surface);
+        let m = self.pixel_transform(image.size()?, trans);
+        let m = Matrix::new(m.m11, m.m12, m.m21, m.m22, m.m31, m.m32);
+        // The pattern matrix maps the user space to the pattern space.
+        p.set_matrix(m.try_invert()?);
+        p.set_extend(extend(self.spread));
         ctx.set_source(&p)?;
         Ok(())
     }
@@ -621,12 +1313,44 @@ impl<B: Brush> Pen for BrushPen<B> {
     fn set(&self, ctx: &Context, trans: RelativeToLogical) -> Result<()> {
         self.brush.set(ctx, trans)?;
         ctx.set_line_width(self.width);
+        set_stroke(ctx, &self.stroke, self.width);
         Ok(())
     }
 }
 
+fn set_stroke(ctx: &Context, stroke: &StrokeStyle, width: f64) {
+    ctx.set_line_cap(match stroke.cap {
+        LineCap::Round => cairo::LineCap::Round,
+        LineCap::Square => cairo::LineCap::Square,
+        _ => cairo::LineCap::Butt,
+    });
+    ctx.set_line_join(match stroke.join {
+        LineJoin::Round => cairo::LineJoin::Round,
+        LineJoin::Bevel => cairo::LineJoin::Bevel,
+        _ => cairo::LineJoin::Miter,
+    });
+    ctx.set_miter_limit(stroke.miter_limit);
+    // The dashes of cairo are absolute lengths.
+    if stroke.is_dashed() {
+        let dashes = stroke
+            .dashes
+            .iter()
+            .map(|d| d.max(0.0) * width)
+            .collect::<Vec<_>>();
+        ctx.set_dash(&dashes, stroke.dash_offset * width);
+    } else {
+        ctx.set_dash(&[], 0.0);
+    }
+}
+
 pub struct DrawingImage(ImageSurface);
 
+impl AsRef<DrawingImage> for DrawingImage {
+    fn as_ref(&self) -> &DrawingImage {
+        self
+    }
+}
+
 impl DrawingImage {
     fn new(image: DynamicImage) -> Result<Self> {
         fn alpha_premultiply(mut image: Rgba32FImage) -> Rgba32FImage {
@@ -678,3 +1402,109 @@ impl DrawingImage {
         Ok(Size::new(self.0.width() as _, self.0.height() as _))
     }
 }
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn path(f: impl FnOnce(&mut DrawingPathBuilder) -> Result<()>, close: bool) -> DrawingPath {
+        let mut builder = DrawingPathBuilder::new(Point::zero()).unwrap();
+        f(&mut builder).unwrap();
+        builder.build(close).unwrap()
+    }
+
+    fn rect() -> DrawingPath {
+        path(
+            |b| b.add_rect(Rect::new(Point::new(10.0, 20.0), Size::new(100.0, 50.0))),
+            false,
+        )
+    }
+
+    #[test]
+    fn bounds() {
+        assert_eq!(
+            rect().bounds().unwrap(),
+            Rect::new(Point::new(10.0, 20.0), Size::new(100.0, 50.0))
+        );
+        let moved = rect()
+            .transform(&Transform::translation(5.0, -10.0))
+            .unwrap();
+        assert_eq!(
+            moved.bounds().unwrap(),
+            Rect::new(Point::new(15.0, 10.0), Size::new(100.0, 50.0))
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let rect = rect();
+        assert!(
+            rect.contains(Point::new(50.0, 40.0), FillRule::NonZero)
+                .unwrap()
+        );
+        assert!(
+            !rect
+                .contains(Point::new(5.0, 5.0), FillRule::NonZero)
+                .unwrap()
+        );
+
+        // Two rects in the same direction: the inner one is a hole only with
+        // the even-odd rule.
+        let nested = path(
+            |b| {
+                b.add_rect(Rect::new(Point::zero(), Size::new(100.0, 100.0)))?;
+                b.add_rect(Rect::new(Point::new(25.0, 25.0), Size::new(50.0, 50.0)))
+            },
+            false,
+        );
+        let center = Point::new(50.0, 50.0);
+        assert!(nested.contains(center, FillRule::NonZero).unwrap());
+        assert!(!nested.contains(center, FillRule::EvenOdd).unwrap());
+        let edge = Point::new(10.0, 10.0);
+        assert!(nested.contains(edge, FillRule::NonZero).unwrap());
+        assert!(nested.contains(edge, FillRule::EvenOdd).unwrap());
+    }
+
+    #[test]
+    fn stroke_contains() {
+        let line = path(|b| b.add_line(Point::new(100.0, 0.0)), false);
+        assert!(line.stroke_contains(Point::new(50.0, 2.0), 6.0).unwrap());
+        assert!(!line.stroke_contains(Point::new(50.0, 5.0), 6.0).unwrap());
+        assert!(line.stroke_contains(Point::new(50.0, 5.0), 12.0).unwrap());
+    }
+
+    #[test]
+    fn length() {
+        assert_eq!(rect().length().unwrap(), 300.0);
+
+        let open = path(
+            |b| {
+                b.add_line(Point::new(30.0, 0.0))?;
+                b.add_line(Point::new(30.0, 40.0))
+            },
+            false,
+        );
+        assert_eq!(open.length().unwrap(), 70.0);
+        let closed = path(
+            |b| {
+                b.add_line(Point::new(30.0, 0.0))?;
+                b.add_line(Point::new(30.0, 40.0))
+            },
+            true,
+        );
+        assert_eq!(closed.length().unwrap(), 120.0);
+
+        // A straight quadratic curve.
+        let quad = path(
+            |b| b.add_quad(Point::new(50.0, 0.0), Point::new(100.0, 0.0)),
+            false,
+        );
+        assert!((quad.length().unwrap() - 100.0).abs() < 1e-6);
+
+        let circle = path(
+            |b| b.add_ellipse(Rect::new(Point::zero(), Size::new(100.0, 100.0))),
+            false,
+        );
+        assert!((circle.length().unwrap() - 100.0 * PI).abs() < 1.0);
+    }
+}
@@ -53,6 +53,8 @@ impl CheckBox {
 
     pub fn preferred_size(&self) -> Result<Size>;
 
+    pub fn baseline(&self) -> Result<Option<f64>>;
+
     pub fn loc(&self) -> Result<Point>;
 
     pub fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -121,6 +123,8 @@ impl RadioButton {
 
     pub fn preferred_size(&self) -> Result<Size>;
 
+    pub fn baseline(&self) -> Result<Option<f64>>;
+
     pub fn loc(&self) -> Result<Point>;
 
     pub fn set_loc(&mut self, p: Point) -> Result<()>;
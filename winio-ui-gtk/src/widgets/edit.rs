@@ -47,6 +47,8 @@ impl Edit {
 
     pub fn preferred_size(&self) -> Result<Size>;
 
+    pub fn baseline(&self) -> Result<Option<f64>>;
+
     pub fn loc(&self) -> Result<Point>;
 
     pub fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -1,15 +1,13 @@
-use gtk4::{
-    glib::object::Cast,
-    pango::{AttrFontDesc, AttrList},
-    prelude::WidgetExt,
-};
+use gtk4::{glib::object::Cast, pango::AttrFontDesc, prelude::WidgetExt};
 use inherit_methods_macro::inherit_methods;
 use winio_handle::AsContainer;
 use winio_primitive::{Font, HAlign, Point, Size};
 
 use crate::{
     Result,
-    widgets::{Widget, desc_to_font, font_desc_from_attrs, font_to_desc},
+    widgets::{
+        Widget, desc_to_font, font_desc_from_attrs, font_from_attrs, font_to_attrs, font_to_desc,
+    },
 };
 
 #[derive(Debug)]
@@ -36,6 +34,8 @@ impl Label {
 
     pub fn preferred_size(&self) -> Result<Size>;
 
+    pub fn baseline(&self) -> Result<Option<f64>>;
+
     pub fn loc(&self) -> Result<Point>;
 
     pub fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -81,14 +81,17 @@ impl Label {
     }
 
     pub fn font(&self) -> Result<Font> {
-        let desc = font_desc_from_attrs(self.widget.attributes().as_ref())
+        let attr_list = self.widget.attributes();
+        let desc = font_desc_from_attrs(attr_list.as_ref())
             .or_else(|| self.widget.pango_context().font_description())
             .unwrap_or_default();
-        Ok(desc_to_font(&desc))
+        let mut font = desc_to_font(&desc);
+        font_from_attrs(&mut font, attr_list.as_ref());
+        Ok(font)
     }
 
     pub fn set_font(&mut self, font: Font) -> Result<()> {
-        let attr_list = AttrList::new();
+        let attr_list = font_to_attrs(&font);
         attr_list.insert(AttrFontDesc::new(&font_to_desc(&font)));
         self.widget.set_attributes(Some(&attr_list));
         self.handle.reset_preferred_size();
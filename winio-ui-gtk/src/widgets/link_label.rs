@@ -3,7 +3,7 @@ use std::rc::Rc;
 use compio_log::info;
 use gtk4::{
     glib::{Propagation, object::Cast},
-    pango::{AttrFontDesc, AttrList},
+    pango::AttrFontDesc,
     prelude::{ButtonExt, WidgetExt},
 };
 use inherit_methods_macro::inherit_methods;
@@ -13,7 +13,9 @@ use winio_primitive::{Font, Point, Size};
 
 use crate::{
     GlobalRuntime, Result,
-    widgets::{Widget, desc_to_font, font_desc_from_attrs, font_to_desc},
+    widgets::{
+        Widget, desc_to_font, font_desc_from_attrs, font_from_attrs, font_to_attrs, font_to_desc,
+    },
 };
 
 #[derive(Debug)]
@@ -59,6 +61,8 @@ impl LinkLabel {
 
     pub fn preferred_size(&self) -> Result<Size>;
 
+    pub fn baseline(&self) -> Result<Option<f64>>;
+
     pub fn loc(&self) -> Result<Point>;
 
     pub fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -95,18 +99,21 @@ impl LinkLabel {
     }
 
     pub fn font(&self) -> Result<Font> {
-        let attrs = self
+        let attr_list = self
             .widget
             .child()
             .and_then(|child| child.downcast::<gtk4::Label>().ok())
-            .and_then(|label| font_desc_from_attrs(label.attributes().as_ref()))
+            .and_then(|label| label.attributes());
+        let desc = font_desc_from_attrs(attr_list.as_ref())
             .or_else(|| self.widget.pango_context().font_description())
             .unwrap_or_default();
-        Ok(desc_to_font(&attrs))
+        let mut font = desc_to_font(&desc);
+        font_from_attrs(&mut font, attr_list.as_ref());
+        Ok(font)
     }
 
     pub fn set_font(&mut self, font: Font) -> Result<()> {
-        let attr_list = AttrList::new();
+        let attr_list = font_to_attrs(&font);
         attr_list.insert(AttrFontDesc::new(&font_to_desc(&font)));
         if let Some(label) = self
             .widget
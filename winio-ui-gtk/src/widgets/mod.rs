@@ -1,7 +1,13 @@
 use std::cell::Cell;
 
-use gtk4::pango::{AttrFontDesc, AttrList, FontDescription, SCALE as PANGO_SCALE, Style, Weight};
-use winio_primitive::{ColorTheme, Font};
+use gtk4::{
+    glib::translate::{FromGlib, IntoGlib},
+    pango::{
+        AttrFontDesc, AttrInt, AttrList, AttrType, FontDescription, SCALE as PANGO_SCALE, Stretch,
+        Style, Underline, Weight,
+    },
+};
+use winio_primitive::{ColorTheme, Cursor, Font, FontStretch, FontWeight};
 
 thread_local! {
     pub(crate) static COLOR_THEME: Cell<Option<ColorTheme>> = const { Cell::new(None) };
@@ -14,28 +20,110 @@ pub fn color_theme() -> crate::Result<ColorTheme> {
 /// Convert a [`Font`] to a [`FontDescription`].
 pub(crate) fn font_to_desc(font: &Font) -> FontDescription {
     let mut desc = FontDescription::new();
-    desc.set_family(&font.family);
+    // Pango accepts a comma-separated family list as fallbacks.
+    let family = std::iter::once(&font.family)
+        .chain(&font.fallback)
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(",");
+    desc.set_family(&family);
     desc.set_size((font.size * PANGO_SCALE as f64) as i32);
     desc.set_style(if font.italic {
         Style::Italic
     } else {
         Style::Normal
     });
-    desc.set_weight(if font.bold {
-        Weight::Bold
-    } else {
-        Weight::Normal
+    desc.set_weight(unsafe { Weight::from_glib(font.weight.0 as i32) });
+    desc.set_stretch(match font.stretch {
+        FontStretch::UltraCondensed => Stretch::UltraCondensed,
+        FontStretch::ExtraCondensed => Stretch::ExtraCondensed,
+        FontStretch::Condensed => Stretch::Condensed,
+        FontStretch::SemiCondensed => Stretch::SemiCondensed,
+        FontStretch::Normal => Stretch::Normal,
+        FontStretch::SemiExpanded => Stretch::SemiExpanded,
+        FontStretch::Expanded => Stretch::Expanded,
+        FontStretch::ExtraExpanded => Stretch::ExtraExpanded,
+        FontStretch::UltraExpanded => Stretch::UltraExpanded,
     });
     desc
 }
 
 /// Convert a [`FontDescription`] to a [`Font`].
 pub(crate) fn desc_to_font(desc: &FontDescription) -> Font {
-    Font {
-        family: desc.family().map(|s| s.to_string()).unwrap_or_default(),
-        size: desc.size() as f64 / PANGO_SCALE as f64,
-        italic: desc.style() == Style::Italic,
-        bold: desc.weight() == Weight::Bold,
+    let family = desc.family().map(|s| s.to_string()).unwrap_or_default();
+    let mut families = family.split(',').map(|s| s.trim().to_string());
+    Font::builder()
+        .family(families.next().unwrap_or_default())
+        .fallback(families)
+        .size(desc.size() as f64 / PANGO_SCALE as f64)
+        .italic(desc.style() == Style::Italic)
+        .weight(FontWeight(desc.weight().into_glib().clamp(1, 1000) as u16))
+        .stretch(match desc.stretch() {
+            Stretch::UltraCondensed => FontStretch::UltraCondensed,
+            Stretch::ExtraCondensed => FontStretch::ExtraCondensed,
+            Stretch::Condensed => FontStretch::Condensed,
+            Stretch::SemiCondensed => FontStretch::SemiCondensed,
+            Stretch::SemiExpanded => FontStretch::SemiExpanded,
+            Stretch::Expanded => FontStretch::Expanded,
+            Stretch::ExtraExpanded => FontStretch::ExtraExpanded,
+            Stretch::UltraExpanded => FontStretch::UltraExpanded,
+            _ => FontStretch::Normal,
+        })
+        .build()
+}
+
+/// Create the attributes of the decorations and the letter spacing of a
+/// [`Font`], which are not part of a [`FontDescription`].
+pub(crate) fn font_to_attrs(font: &Font) -> AttrList {
+    let attr_list = AttrList::new();
+    if font.underline {
+        attr_list.insert(AttrInt::new_underline(Underline::Single));
+    }
+    if font.strikethrough {
+        attr_list.insert(AttrInt::new_strikethrough(true));
+    }
+    if font.letter_spacing != 0.0 {
+        attr_list.insert(AttrInt::new_letter_spacing(
+            (font.letter_spacing * PANGO_SCALE as f64) as i32,
+        ));
+    }
+    attr_list
+}
+
+/// Read the decorations and the letter spacing from an attribute list.
+pub(crate) fn font_from_attrs(font: &mut Font, attr_list: Option<&AttrList>) {
+    let Some(attr_list) = attr_list else {
+        return;
+    };
+    for attr in attr_list.iterator().attrs() {
+        let Some(attr) = attr.downcast_ref::<AttrInt>() else {
+            continue;
+        };
+        match attr.type_() {
+            AttrType::Underline => font.underline = attr.value() != Underline::None.into_glib(),
+            AttrType::Strikethrough => font.strikethrough = attr.value() != 0,
+            AttrType::LetterSpacing => {
+                font.letter_spacing = attr.value() as f64 / PANGO_SCALE as f64
+            }
+            _ => {}
+        }
+    }
+}
+
+/// The CSS name of a [`Cursor`].
+pub(crate) fn cursor_name(cursor: Cursor) -> &'static str {
+    match cursor {
+        Cursor::IBeam => "text",
+        Cursor::Hand => "pointer",
+        Cursor::Crosshair => "crosshair",
+        Cursor::Wait => "wait",
+        Cursor::Move => "move",
+        Cursor::NotAllowed => "not-allowed",
+        Cursor::ResizeHorizontal => "ew-resize",
+        Cursor::ResizeVertical => "ns-resize",
+        Cursor::ResizeNwse => "nwse-resize",
+        Cursor::ResizeNesw => "nesw-resize",
+        _ => "default",
     }
 }
 
@@ -108,6 +196,9 @@ pub use webview::*;
 mod tab_view;
 pub use tab_view::*;
 
+mod splitter;
+pub use splitter::*;
+
 #[cfg(feature = "wgpu")]
 mod wgpu;
 #[cfg(feature = "wgpu")]
@@ -0,0 +1,209 @@
+use std::rc::Rc;
+
+use gtk4::{
+    glib::object::Cast,
+    graphene,
+    prelude::{BoxExt, OrientableExt, WidgetExt},
+};
+use inherit_methods_macro::inherit_methods;
+use winio_callback::Callback;
+use winio_handle::{AsContainer, BorrowedContainer};
+use winio_primitive::{Orient, Point, Size};
+
+use crate::{GlobalRuntime, Result, Widget};
+
+#[derive(Debug)]
+struct Pane {
+    swindow: gtk4::ScrolledWindow,
+    fixed: gtk4::Fixed,
+}
+
+impl Pane {
+    fn new() -> Self {
+        let swindow = gtk4::ScrolledWindow::new();
+        swindow.set_hscrollbar_policy(gtk4::PolicyType::External);
+        swindow.set_vscrollbar_policy(gtk4::PolicyType::External);
+        let fixed = gtk4::Fixed::new();
+        swindow.set_child(Some(&fixed));
+        Self { swindow, fixed }
+    }
+}
+
+/// GTK paned only holds two children, so the panes are chained with nested
+/// [`gtk4::Paned`]s: the end child of each paned is the next paned.
+#[derive(Debug)]
+pub struct Splitter {
+    on_change: Rc<Callback>,
+    root: gtk4::Box,
+    handle: Widget,
+    orient: gtk4::Orientation,
+    panes: Vec<Pane>,
+    paneds: Vec<gtk4::Paned>,
+}
+
+#[inherit_methods(from = "self.handle")]
+impl Splitter {
+    pub fn new(parent: impl AsContainer) -> Result<Self> {
+        let root = gtk4::Box::new(gtk4::Orientation::Horizontal, 0);
+        let handle = Widget::new(parent, unsafe { root.clone().unsafe_cast() })?;
+        Ok(Self {
+            on_change: Rc::new(Callback::new()),
+            root,
+            handle,
+            orient: gtk4::Orientation::Horizontal,
+            panes: vec![],
+            paneds: vec![],
+        })
+    }
+
+    pub fn is_visible(&self) -> Result<bool>;
+
+    pub fn set_visible(&mut self, v: bool) -> Result<()>;
+
+    pub fn is_enabled(&self) -> Result<bool>;
+
+    pub fn set_enabled(&mut self, v: bool) -> Result<()>;
+
+    pub fn loc(&self) -> Result<Point>;
+
+    pub fn set_loc(&mut self, p: Point) -> Result<()>;
+
+    pub fn size(&self) -> Result<Size>;
+
+    pub fn set_size(&mut self, s: Size) -> Result<()>;
+
+    pub async fn wait_change(&self) {
+        self.on_change.wait().await
+    }
+
+    pub fn orient(&self) -> Result<Orient> {
+        match self.orient {
+            gtk4::Orientation::Horizontal => Ok(Orient::Horizontal),
+            gtk4::Orientation::Vertical => Ok(Orient::Vertical),
+            _ => unreachable!(),
+        }
+    }
+
+    pub fn set_orient(&mut self, v: Orient) -> Result<()> {
+        self.orient = match v {
+            Orient::Horizontal => gtk4::Orientation::Horizontal,
+            Orient::Vertical => gtk4::Orientation::Vertical,
+        };
+        for paned in &self.paneds {
+            paned.set_orientation(self.orient);
+        }
+        Ok(())
+    }
+
+    pub fn len(&self) -> Result<usize> {
+        Ok(self.panes.len())
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    pub fn insert(&mut self, i: usize) -> Result<()> {
+        self.panes.insert(i, Pane::new());
+        self.rebuild();
+        Ok(())
+    }
+
+    pub fn remove(&mut self, i: usize) -> Result<()> {
+        self.panes.remove(i);
+        self.rebuild();
+        Ok(())
+    }
+
+    fn rebuild(&mut self) {
+        for paned in self.paneds.drain(..) {
+            paned.set_start_child(gtk4::Widget::NONE);
+            paned.set_end_child(gtk4::Widget::NONE);
+        }
+        while let Some(child) = self.root.first_child() {
+            self.root.remove(&child);
+        }
+        let Some(last) = self.panes.last() else {
+            return;
+        };
+        let mut end: gtk4::Widget = last.swindow.clone().upcast();
+        for pane in self.panes.iter().rev().skip(1) {
+            let paned = gtk4::Paned::new(self.orient);
+            paned.set_wide_handle(true);
+            paned.set_shrink_start_child(false);
+            paned.set_shrink_end_child(false);
+            paned.set_start_child(Some(&pane.swindow));
+            paned.set_end_child(Some(&end));
+            paned.connect_position_notify({
+                let on_change = self.on_change.clone();
+                move |_| on_change.signal::<GlobalRuntime>(())
+            });
+            end = paned.clone().upcast();
+            self.paneds.insert(0, paned);
+        }
+        end.set_hexpand(true);
+        end.set_vexpand(true);
+        self.root.append(&end);
+    }
+
+    pub fn pane(&self, i: usize) -> BorrowedContainer<'_> {
+        BorrowedContainer::gtk(&self.panes[i].fixed)
+    }
+
+    pub fn pane_size(&self, i: usize) -> Result<Size> {
+        let swindow = &self.panes[i].swindow;
+        Ok(Size::new(swindow.width() as _, swindow.height() as _))
+    }
+
+    pub fn pane_min_size(&self, i: usize) -> Result<Size> {
+        let (width, height) = self.panes[i].swindow.size_request();
+        Ok(Size::new(width.max(0) as _, height.max(0) as _))
+    }
+
+    pub fn set_pane_min_size(&mut self, i: usize, s: Size) -> Result<()> {
+        self.panes[i]
+            .swindow
+            .set_size_request(s.width as _, s.height as _);
+        Ok(())
+    }
+
+    /// The offset of the widget in the splitter along the orientation.
+    fn offset(&self, w: &impl WidgetExt) -> f64 {
+        let Some(p) = w.compute_point(&self.root, &graphene::Point::new(0.0, 0.0)) else {
+            return 0.0;
+        };
+        match self.orient {
+            gtk4::Orientation::Vertical => p.y() as _,
+            _ => p.x() as _,
+        }
+    }
+
+    /// The size of the divider handle.
+    fn handle_size(&self) -> f64 {
+        self.paneds
+            .first()
+            .and_then(|paned| paned.end_child())
+            .map(|end| (self.offset(&end) - self.paneds[0].position() as f64).max(0.0))
+            .unwrap_or_default()
+    }
+
+    pub fn pos(&self) -> Result<Vec<f64>> {
+        Ok(self
+            .paneds
+            .iter()
+            .map(|paned| self.offset(paned) + paned.position() as f64)
+            .collect())
+    }
+
+    pub fn set_pos(&mut self, pos: &[f64]) -> Result<()> {
+        let handle = self.handle_size();
+        let mut start = 0.0;
+        for (paned, p) in self.paneds.iter().zip(pos) {
+            paned.set_position((p - start).max(0.0) as _);
+            start = p + handle;
+        }
+        Ok(())
+    }
+}
+
+winio_handle::impl_as_widget!(Splitter, handle);
@@ -97,10 +97,10 @@ impl TextBox {
 
     pub fn set_halign(&mut self, align: HAlign) -> Result<()> {
         let align = match align {
-            HAlign::Left => Justification::Left,
             HAlign::Center => Justification::Center,
             HAlign::Right => Justification::Right,
             HAlign::Stretch => Justification::Fill,
+            _ => Justification::Left,
         };
         self.widget.set_justification(align);
         Ok(())
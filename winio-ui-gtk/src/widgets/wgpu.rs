@@ -16,7 +16,7 @@ use wgpu::{
     },
 };
 use winio_handle::{AsContainer, AsWidget};
-use winio_primitive::{MouseButton, Point, Size, Vector};
+use winio_primitive::{Cursor, MouseButton, MouseButtonEvent, Point, Size, Vector};
 
 use crate::{Canvas, Result};
 
@@ -52,6 +52,8 @@ impl WgpuCanvas {
 
     pub fn set_tooltip(&mut self, s: impl AsRef<str>) -> Result<()>;
 
+    pub fn set_cursor(&mut self, cursor: Cursor) -> Result<()>;
+
     pub async fn wait_mouse_down(&self) -> MouseButton {
         self.widget.wait_mouse_down().await
     }
@@ -68,6 +70,22 @@ impl WgpuCanvas {
         self.widget.wait_mouse_wheel().await
     }
 
+    pub async fn wait_mouse_button_down(&self) -> MouseButtonEvent {
+        self.widget.wait_mouse_button_down().await
+    }
+
+    pub async fn wait_mouse_button_up(&self) -> MouseButtonEvent {
+        self.widget.wait_mouse_button_up().await
+    }
+
+    pub async fn wait_mouse_enter(&self) -> Point {
+        self.widget.wait_mouse_enter().await
+    }
+
+    pub async fn wait_mouse_leave(&self) {
+        self.widget.wait_mouse_leave().await
+    }
+
     fn wayland(&self) -> Option<WindowHandleWrapper> {
         let native = self.as_widget().to_gtk().native()?;
         let surface = native
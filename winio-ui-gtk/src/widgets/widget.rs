@@ -2,9 +2,9 @@ use std::cell::Cell;
 
 use gtk4::prelude::{Cast, FixedExt, WidgetExt};
 use winio_handle::{AsContainer, AsWidget, BorrowedWidget};
-use winio_primitive::{Point, Size};
+use winio_primitive::{Cursor, Point, Size};
 
-use crate::{Error, Result};
+use crate::{Error, Result, widgets::cursor_name};
 
 #[derive(Debug)]
 pub(crate) struct Widget {
@@ -49,6 +49,11 @@ impl Widget {
         Ok(preferred_size)
     }
 
+    pub fn baseline(&self) -> Result<Option<f64>> {
+        let (_, _, _, baseline) = self.widget.measure(gtk4::Orientation::Vertical, -1);
+        Ok((baseline >= 0).then_some(baseline as f64))
+    }
+
     pub fn reset_preferred_size(&mut self) {
         self.preferred_size.set(Size::new(f64::MAX, f64::MAX));
     }
@@ -98,6 +103,11 @@ impl Widget {
         self.widget.set_tooltip_text(s);
         Ok(())
     }
+
+    pub fn set_cursor(&mut self, cursor: Cursor) -> Result<()> {
+        self.widget.set_cursor_from_name(Some(cursor_name(cursor)));
+        Ok(())
+    }
 }
 
 impl AsWidget for Widget {
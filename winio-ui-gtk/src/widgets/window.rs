@@ -4,9 +4,9 @@ use gtk4::{glib::Propagation, prelude::*};
 use inherit_methods_macro::inherit_methods;
 use winio_callback::Callback;
 use winio_handle::{AsContainer, AsWindow, BorrowedContainer, BorrowedWindow};
-use winio_primitive::{ColorTheme, Point, Size};
+use winio_primitive::{ColorTheme, Cursor, Point, Size};
 
-use crate::{GlobalRuntime, Result, Widget};
+use crate::{GlobalRuntime, Result, Widget, update_system_palette, widgets::cursor_name};
 
 #[derive(Debug)]
 pub struct Window {
@@ -147,6 +147,11 @@ impl Window {
         Ok(())
     }
 
+    pub fn set_cursor(&mut self, cursor: Cursor) -> Result<()> {
+        self.window.set_cursor_from_name(Some(cursor_name(cursor)));
+        Ok(())
+    }
+
     pub async fn wait_size(&self) {
         self.on_size.wait().await
     }
@@ -185,6 +190,7 @@ fn set_color_theme(w: &gtk4::Window) {
         ColorTheme::Light
     };
     super::COLOR_THEME.set(Some(theme));
+    update_system_palette(w);
 }
 
 #[derive(Debug)]
@@ -212,6 +218,8 @@ impl View {
     pub fn size(&self) -> Result<Size>;
 
     pub fn set_size(&mut self, s: Size) -> Result<()>;
+
+    pub fn set_cursor(&mut self, cursor: Cursor) -> Result<()>;
 }
 
 winio_handle::impl_as_widget!(View, handle);
@@ -41,6 +41,7 @@ fn main() {
             "src/common",
             "src/widgets/widget",
             "src/platform/monitor",
+            "src/platform/palette",
             "src/platform/timer",
             "src/dialogs/msgbox",
             "src/dialogs/filebox",
@@ -55,6 +56,7 @@ fn main() {
             "src/widgets/scroll_bar",
             "src/widgets/scroll_view",
             "src/widgets/tab_view",
+            "src/widgets/splitter",
             #[cfg(feature = "media")]
             "src/widgets/media",
             #[cfg(feature = "webview")]
@@ -1,5 +1,8 @@
 mod monitor;
 pub use monitor::*;
 
+mod palette;
+pub use palette::*;
+
 mod timer;
 pub use timer::*;
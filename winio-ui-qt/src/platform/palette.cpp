@@ -0,0 +1,7 @@
+#include "palette.hpp"
+#include <QApplication>
+
+void color_palette(QColor &c, QPaletteColorGroup group,
+                   QPaletteColorRole role) {
+    new (&c) QColor{QApplication::palette().color(group, role)};
+}
@@ -0,0 +1,14 @@
+#pragma once
+
+#include <QColor>
+#include <QPalette>
+
+#include <rust/cxx.h>
+
+using QPaletteColorGroup = QPalette::ColorGroup;
+using QPaletteColorRole = QPalette::ColorRole;
+
+#include <winio-ui-qt/src/platform/palette.rs.h>
+
+void color_palette(QColor &c, QPaletteColorGroup group,
+                   QPaletteColorRole role);
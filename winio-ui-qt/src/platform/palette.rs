@@ -0,0 +1,84 @@
+use std::pin::Pin;
+
+use cxx::{ExternType, type_id};
+use winio_primitive::{Color, ColorTheme, SystemPalette};
+
+use crate::{
+    Result,
+    widgets::{QColor, is_dark},
+};
+
+/// Get the colors of the current palette.
+pub fn system_palette() -> Result<SystemPalette> {
+    use QPaletteColorGroup::*;
+    use QPaletteColorRole::*;
+
+    let color = |group, role| -> Result<Color> {
+        let mut c = QColor::invalid();
+        ffi::color_palette(Pin::new(&mut c), group, role)?;
+        Ok(c.into())
+    };
+    let theme = if is_dark()? {
+        ColorTheme::Dark
+    } else {
+        ColorTheme::Light
+    };
+    let mut palette = SystemPalette::from_theme(theme, color(Active, Highlight)?);
+    palette.window = color(Active, Window)?;
+    palette.window_text = color(Active, WindowText)?;
+    palette.highlight_text = color(Active, HighlightedText)?;
+    palette.disabled_text = color(Disabled, WindowText)?;
+    palette.border = color(Active, Mid)?;
+    palette.tooltip = color(Active, ToolTipBase)?;
+    palette.tooltip_text = color(Active, ToolTipText)?;
+    Ok(palette)
+}
+
+#[repr(i32)]
+#[allow(dead_code)]
+pub(crate) enum QPaletteColorGroup {
+    Active   = 0,
+    Disabled = 1,
+    Inactive = 2,
+}
+
+unsafe impl ExternType for QPaletteColorGroup {
+    type Id = type_id!("QPaletteColorGroup");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[repr(i32)]
+#[allow(dead_code)]
+pub(crate) enum QPaletteColorRole {
+    WindowText      = 0,
+    Mid             = 5,
+    Text            = 6,
+    Base            = 9,
+    Window          = 10,
+    Highlight       = 12,
+    HighlightedText = 13,
+    ToolTipBase     = 18,
+    ToolTipText     = 19,
+}
+
+unsafe impl ExternType for QPaletteColorRole {
+    type Id = type_id!("QPaletteColorRole");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++-unwind" {
+        include!("winio-ui-qt/src/platform/palette.hpp");
+
+        type QColor = crate::widgets::QColor;
+        type QPaletteColorGroup = super::QPaletteColorGroup;
+        type QPaletteColorRole = super::QPaletteColorRole;
+
+        fn color_palette(
+            c: Pin<&mut QColor>,
+            group: QPaletteColorGroup,
+            role: QPaletteColorRole,
+        ) -> Result<()>;
+    }
+}
@@ -63,6 +63,10 @@ void WinioQtEventLoop::setAppName(rust::Str name) {
     m_app.setDesktopFileName(QString::fromUtf8(name.data(), name.size()));
 }
 
+void WinioQtEventLoop::setLayoutDirection(bool rtl) {
+    QApplication::setLayoutDirection(rtl ? Qt::RightToLeft : Qt::LeftToRight);
+}
+
 void event_loop_wake_up() {
     auto dispatcher = QApplication::eventDispatcher();
     dispatcher->wakeUp();
@@ -20,6 +20,7 @@ struct WinioQtEventLoop {
     WinioQtEventLoop(rust::Vec<rust::String> args);
 
     void setAppName(rust::Str name);
+    void setLayoutDirection(bool rtl);
 
     void registerFd(int fd, int timeout, rust::Fn<void()> callback);
     void unregisterFd();
@@ -7,6 +7,7 @@ use std::{
 };
 
 use cxx::UniquePtr;
+use winio_primitive::LayoutDirection;
 
 use crate::Result;
 
@@ -20,6 +21,7 @@ mod ffi {
         fn new_event_loop(args: Vec<String>) -> Result<UniquePtr<WinioQtEventLoop>>;
 
         fn setAppName(self: Pin<&mut Self>, name: &str) -> Result<()>;
+        fn setLayoutDirection(self: Pin<&mut Self>, rtl: bool) -> Result<()>;
 
         unsafe fn registerFd(self: Pin<&mut Self>, fd: i32, timeout: i32, callback: unsafe fn());
         fn unregisterFd(self: Pin<&mut Self>);
@@ -50,6 +52,14 @@ impl App {
         Ok(())
     }
 
+    pub fn set_layout_direction(&mut self, direction: LayoutDirection) -> Result<()> {
+        self.event_loop
+            .borrow_mut()
+            .pin_mut()
+            .setLayoutDirection(direction == LayoutDirection::RightToLeft)?;
+        Ok(())
+    }
+
     #[allow(dead_code)]
     pub(crate) fn register_fd(&self, fd: RawFd, timeout: Option<Duration>, waker: Waker) {
         fn callback() {
@@ -1,7 +1,10 @@
 #include "canvas.hpp"
 #include <QApplication>
 #include <QBrush>
+#include <QConicalGradient>
+#include <QCursor>
 #include <QFont>
+#include <QFontMetricsF>
 #include <QLinearGradient>
 #include <QPen>
 #include <QRadialGradient>
@@ -13,9 +16,12 @@ WinioCanvas::WinioCanvas(QWidget *parent)
 #else
       QWidget(parent),
 #endif
-      m_paint_callback(std::nullopt), m_move_callback(std::nullopt),
+      m_resize_callback(std::nullopt), m_move_callback(std::nullopt),
       m_press_callback(std::nullopt), m_release_callback(std::nullopt),
-      m_wheel_callback(std::nullopt), m_buffer() {
+      m_wheel_callback(std::nullopt),
+      m_button_press_callback(std::nullopt),
+      m_button_release_callback(std::nullopt), m_enter_callback(std::nullopt),
+      m_leave_callback(std::nullopt), m_clicks(1), m_buffer() {
     setMouseTracking(true);
 }
 
@@ -23,7 +29,15 @@ WinioCanvas::~WinioCanvas() {}
 
 void WinioCanvas::paintEvent(QPaintEvent *) {
     QPainter painter(this);
-    m_buffer.play(&painter);
+    painter.drawImage(QPointF{}, m_buffer);
+}
+
+void WinioCanvas::resizeEvent(QResizeEvent *event) {
+    if (m_resize_callback) {
+        auto &[callback, data] = *m_resize_callback;
+        auto size = event->size();
+        callback(data, size.width(), size.height());
+    }
 }
 
 void WinioCanvas::mouseMoveEvent(QMouseEvent *event) {
@@ -35,10 +49,18 @@ void WinioCanvas::mouseMoveEvent(QMouseEvent *event) {
 }
 
 void WinioCanvas::mousePressEvent(QMouseEvent *event) {
+    // The default mouseDoubleClickEvent calls this method.
+    m_clicks = event->type() == QEvent::MouseButtonDblClick ? 2 : 1;
     if (m_press_callback) {
         auto &[callback, data] = *m_press_callback;
         callback(data, event->button());
     }
+    if (m_button_press_callback) {
+        auto &[callback, data] = *m_button_press_callback;
+        auto pos = event->pos();
+        callback(data, event->button(), pos.x(), pos.y(),
+                 static_cast<int>(event->modifiers()), m_clicks);
+    }
 }
 
 void WinioCanvas::mouseReleaseEvent(QMouseEvent *event) {
@@ -46,6 +68,12 @@ void WinioCanvas::mouseReleaseEvent(QMouseEvent *event) {
         auto &[callback, data] = *m_release_callback;
         callback(data, event->button());
     }
+    if (m_button_release_callback) {
+        auto &[callback, data] = *m_button_release_callback;
+        auto pos = event->pos();
+        callback(data, event->button(), pos.x(), pos.y(),
+                 static_cast<int>(event->modifiers()), m_clicks);
+    }
 }
 
 void WinioCanvas::wheelEvent(QWheelEvent *event) {
@@ -57,10 +85,37 @@ void WinioCanvas::wheelEvent(QWheelEvent *event) {
     }
 }
 
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+void WinioCanvas::enterEvent(QEnterEvent *event) {
+    auto pos = event->position().toPoint();
+#else
+void WinioCanvas::enterEvent(QEvent *) {
+    auto pos = mapFromGlobal(QCursor::pos());
+#endif
+    if (m_enter_callback) {
+        auto &[callback, data] = *m_enter_callback;
+        callback(data, pos.x(), pos.y());
+    }
+}
+
+void WinioCanvas::leaveEvent(QEvent *) {
+    if (m_leave_callback) {
+        auto &[callback, data] = *m_leave_callback;
+        callback(data);
+    }
+}
+
 std::unique_ptr<QWidget> new_canvas(QWidget *parent) {
     return std::make_unique<WinioCanvas>(parent);
 }
 
+void canvas_register_resize_event(QWidget &w,
+                                  callback_fn_t<void(int, int)> callback,
+                                  std::uint8_t const *data) {
+    static_cast<WinioCanvas &>(w).m_resize_callback =
+        std::make_tuple(std::move(callback), data);
+}
+
 void canvas_register_move_event(QWidget &w,
                                 callback_fn_t<void(int, int)> callback,
                                 std::uint8_t const *data) {
@@ -89,18 +144,139 @@ void canvas_register_wheel_event(QWidget &w,
         std::make_tuple(std::move(callback), data);
 }
 
-std::unique_ptr<QPainter> canvas_new_painter(QWidget &w) {
-    auto &c = static_cast<WinioCanvas &>(w);
-    c.m_buffer = QPicture{};
-    return std::make_unique<QPainter>(&c.m_buffer);
+void canvas_register_button_press_event(
+    QWidget &w,
+    callback_fn_t<void(QtMouseButton, int, int, int, int)> callback,
+    std::uint8_t const *data) {
+    static_cast<WinioCanvas &>(w).m_button_press_callback =
+        std::make_tuple(std::move(callback), data);
+}
+
+void canvas_register_button_release_event(
+    QWidget &w,
+    callback_fn_t<void(QtMouseButton, int, int, int, int)> callback,
+    std::uint8_t const *data) {
+    static_cast<WinioCanvas &>(w).m_button_release_callback =
+        std::make_tuple(std::move(callback), data);
+}
+
+void canvas_register_enter_event(QWidget &w,
+                                 callback_fn_t<void(int, int)> callback,
+                                 std::uint8_t const *data) {
+    static_cast<WinioCanvas &>(w).m_enter_callback =
+        std::make_tuple(std::move(callback), data);
+}
+
+void canvas_register_leave_event(QWidget &w, callback_fn_t<void()> callback,
+                                 std::uint8_t const *data) {
+    static_cast<WinioCanvas &>(w).m_leave_callback =
+        std::make_tuple(std::move(callback), data);
+}
+
+bool canvas_buffer_valid(QWidget const &w) {
+    auto &c = static_cast<WinioCanvas const &>(w);
+    qreal ratio = w.devicePixelRatioF();
+    return !c.m_buffer.isNull() && c.m_buffer.size() == w.size() * ratio &&
+           c.m_buffer.devicePixelRatio() == ratio;
+}
+
+std::unique_ptr<QImage> canvas_new_buffer(QWidget &w, bool keep) {
+    if (keep && canvas_buffer_valid(w)) {
+        // Implicitly shared; detached when painted.
+        return std::make_unique<QImage>(static_cast<WinioCanvas &>(w).m_buffer);
+    }
+    return canvas_new_layer(w);
 }
 
-void painter_set_font(QPainter &p, rust::Str family, double size, bool italic,
-                      bool bold) {
-    auto font = QFont{QString::fromUtf8(family.data(), family.size()), -1,
-                      bold ? QFont::Bold : QFont::Normal, italic};
+void canvas_set_buffer(QWidget &w, QImage const &image) {
+    static_cast<WinioCanvas &>(w).m_buffer = image;
+    w.update();
+}
+
+std::unique_ptr<QImage> canvas_new_layer(QWidget &w) {
+    qreal ratio = w.devicePixelRatioF();
+    auto image = std::make_unique<QImage>(w.size() * ratio,
+                                          QImage::Format_ARGB32_Premultiplied);
+    image->setDevicePixelRatio(ratio);
+    image->fill(Qt::transparent);
+    return image;
+}
+
+std::unique_ptr<QImage> image_new_layer(QImage const &image) {
+    auto layer = std::make_unique<QImage>(image.size(),
+                                          QImage::Format_ARGB32_Premultiplied);
+    layer->setDevicePixelRatio(image.devicePixelRatio());
+    layer->fill(Qt::transparent);
+    return layer;
+}
+
+std::unique_ptr<QImage> new_bitmap(QImage const &image, double width,
+                                   double height) {
+    qreal ratio = image.devicePixelRatio();
+    auto size = (QSizeF{width, height} * ratio).toSize();
+    auto bitmap =
+        std::make_unique<QImage>(size, QImage::Format_ARGB32_Premultiplied);
+    bitmap->setDevicePixelRatio(ratio);
+    bitmap->fill(Qt::transparent);
+    return bitmap;
+}
+
+std::unique_ptr<QImage> image_unscaled(QImage const &image) {
+    auto copy = std::make_unique<QImage>(image.copy());
+    copy->setDevicePixelRatio(1.0);
+    return copy;
+}
+
+rust::Vec<std::uint8_t> image_rgba_bytes(QImage const &image) {
+    auto rgba = image.convertToFormat(QImage::Format_RGBA8888);
+    auto line = static_cast<std::size_t>(rgba.width()) * 4;
+    rust::Vec<std::uint8_t> bytes;
+    bytes.reserve(line * rgba.height());
+    for (int y = 0; y < rgba.height(); y++) {
+        auto bits = rgba.constScanLine(y);
+        for (std::size_t i = 0; i < line; i++) {
+            bytes.push_back(bits[i]);
+        }
+    }
+    return bytes;
+}
+
+std::unique_ptr<QPainter> new_image_painter(QImage &image) {
+    return std::make_unique<QPainter>(&image);
+}
+
+void painter_clear_rect(QPainter &p, QRectF const &rect) {
+    p.save();
+    p.setCompositionMode(QPainter::CompositionMode_Source);
+    p.fillRect(rect, Qt::transparent);
+    p.restore();
+}
+
+void painter_draw_layer(QPainter &p, QImage const &image, double opacity,
+                        QPainterCompositionMode mode) {
+    p.save();
+    p.resetTransform();
+    p.setOpacity(opacity);
+    p.setCompositionMode(mode);
+    p.drawImage(QPointF{}, image);
+    p.restore();
+}
+
+static QFont new_font(rust::Str families, double size, int weight,
+                      int stretch, bool italic, bool underline,
+                      bool strikeout, double letter_spacing) {
+    auto font = QFont{};
+    font_apply(font, families, weight, stretch, italic, underline, strikeout,
+               letter_spacing);
     font.setPixelSize(std::max((int)size, 1));
-    p.setFont(font);
+    return font;
+}
+
+void painter_set_font(QPainter &p, rust::Str families, double size, int weight,
+                      int stretch, bool italic, bool underline, bool strikeout,
+                      double letter_spacing) {
+    p.setFont(new_font(families, size, weight, stretch, italic, underline,
+                       strikeout, letter_spacing));
 }
 
 QSizeF painter_measure_text(QPainter &p, QRectF rect, rust::Str text) {
@@ -113,6 +289,169 @@ void painter_draw_text(QPainter &p, QRectF rect, rust::Str text) {
     p.drawText(rect, QString::fromUtf8(text.data(), text.size()), option);
 }
 
+static void text_layout_add_format(QTextLayout &l, int start, int len,
+                                   QTextCharFormat const &format) {
+    auto formats = l.formats();
+    formats.append(QTextLayout::FormatRange{start, len, format});
+    l.setFormats(formats);
+}
+
+std::unique_ptr<QTextLayout>
+new_text_layout(rust::Str text, rust::Str families, double size, int weight,
+                int stretch, bool italic, bool underline, bool strikeout,
+                double letter_spacing) {
+    auto str = QString::fromUtf8(text.data(), text.size());
+    // Line breaks are only recognized as line separators. They are both one
+    // UTF-16 unit, so the indices are kept.
+    str.replace(QChar::LineFeed, QChar::LineSeparator);
+    return std::make_unique<QTextLayout>(
+        str, new_font(families, size, weight, stretch, italic, underline,
+                      strikeout, letter_spacing));
+}
+
+void text_layout_add_font(QTextLayout &l, int start, int len,
+                          rust::Str families, double size, int weight,
+                          int stretch, bool italic, bool underline,
+                          bool strikeout, double letter_spacing) {
+    QTextCharFormat format{};
+    format.setFont(new_font(families, size, weight, stretch, italic, underline,
+                            strikeout, letter_spacing));
+    text_layout_add_format(l, start, len, format);
+}
+
+void text_layout_add_color(QTextLayout &l, int start, int len,
+                           QColor const &c) {
+    QTextCharFormat format{};
+    format.setForeground(QBrush{c});
+    text_layout_add_format(l, start, len, format);
+}
+
+void text_layout_add_underline(QTextLayout &l, int start, int len,
+                               bool underline) {
+    QTextCharFormat format{};
+    format.setFontUnderline(underline);
+    text_layout_add_format(l, start, len, format);
+}
+
+static void text_layout_create_lines(QTextLayout &l, double width,
+                                     double line_spacing, int max_lines) {
+    l.beginLayout();
+    qreal y = 0.0;
+    for (int i = 0; max_lines < 0 || i < max_lines; i++) {
+        auto line = l.createLine();
+        if (!line.isValid()) {
+            break;
+        }
+        if (width >= 0.0) {
+            line.setLineWidth(width);
+        }
+        line.setPosition(QPointF{0.0, y});
+        y += line.height() * line_spacing;
+    }
+    l.endLayout();
+}
+
+static double text_layout_natural_width(QTextLayout const &l) {
+    qreal width = 0.0;
+    for (int i = 0; i < l.lineCount(); i++) {
+        width = std::max(width, l.lineAt(i).naturalTextWidth());
+    }
+    return width;
+}
+
+void text_layout_build(QTextLayout &l, double max_width, QtAlignmentFlag align,
+                       double line_spacing, int max_lines, bool ellipsis) {
+    QTextOption option{};
+    option.setAlignment(align);
+    option.setWrapMode(max_width >= 0.0
+                           ? QTextOption::WrapAtWordBoundaryOrAnywhere
+                           : QTextOption::NoWrap);
+    l.setTextOption(option);
+    text_layout_create_lines(l, max_width, line_spacing, max_lines);
+    auto count = l.lineCount();
+    if (ellipsis && count > 0) {
+        auto last = l.lineAt(count - 1);
+        auto start = last.textStart();
+        auto text = l.text();
+        if (start + last.textLength() < text.size()) {
+            auto width =
+                max_width >= 0.0 ? max_width : last.naturalTextWidth();
+            auto rest = text.mid(start);
+            rest.replace(QChar::LineSeparator, QChar::Space);
+            QFontMetricsF metrics{l.font()};
+            auto elided = metrics.elidedText(rest, Qt::ElideRight, width);
+            if (elided == rest) {
+                elided = metrics.elidedText(rest + QChar{0x2026},
+                                            Qt::ElideRight, width);
+            }
+            l.setText(text.left(start) + elided);
+            text_layout_create_lines(l, max_width, line_spacing, max_lines);
+        }
+    }
+    // Align the lines to the widest one if there is no max width.
+    if (max_width < 0.0) {
+        text_layout_create_lines(l, text_layout_natural_width(l),
+                                 line_spacing, max_lines);
+    }
+}
+
+QSizeF text_layout_size(QTextLayout const &l) {
+    QSizeF size{};
+    for (int i = 0; i < l.lineCount(); i++) {
+        auto rect = l.lineAt(i).naturalTextRect();
+        size.setWidth(std::max(size.width(), rect.right()));
+        size.setHeight(std::max(size.height(), rect.bottom()));
+    }
+    return size;
+}
+
+rust::Vec<WLineMetrics> text_layout_lines(QTextLayout const &l) {
+    rust::Vec<WLineMetrics> lines{};
+    for (int i = 0; i < l.lineCount(); i++) {
+        auto line = l.lineAt(i);
+        auto rect = line.naturalTextRect();
+        lines.push_back(WLineMetrics{line.textStart(), line.textLength(),
+                                     rect.x(), rect.y(), rect.width(),
+                                     rect.height(), line.y() + line.ascent()});
+    }
+    return lines;
+}
+
+static QTextLine text_layout_line_at(QTextLayout const &l, double y) {
+    for (int i = 0; i < l.lineCount(); i++) {
+        auto line = l.lineAt(i);
+        if (y < line.y() + line.height()) {
+            return line;
+        }
+    }
+    return l.lineAt(l.lineCount() - 1);
+}
+
+int text_layout_hit_test(QTextLayout const &l, QPointF pos, bool &inside) {
+    inside = false;
+    if (l.lineCount() == 0) {
+        return 0;
+    }
+    auto line = text_layout_line_at(l, pos.y());
+    inside = line.naturalTextRect().contains(pos);
+    return line.xToCursor(pos.x(), QTextLine::CursorBetweenCharacters);
+}
+
+QRectF text_layout_caret_rect(QTextLayout const &l, int index) {
+    auto line = l.lineForTextPosition(index);
+    if (!line.isValid()) {
+        if (l.lineCount() == 0) {
+            return QRectF{};
+        }
+        line = l.lineAt(l.lineCount() - 1);
+    }
+    return QRectF{line.cursorToX(index), line.y(), 0.0, line.height()};
+}
+
+void painter_draw_text_layout(QPainter &p, QTextLayout const &l, QPointF pos) {
+    l.draw(&p, pos);
+}
+
 void painter_set_transform(QPainter &p, WTransform const &t) {
     p.setTransform(QTransform{t.m11, t.m12, t.m21, t.m22, t.m31, t.m32});
 }
@@ -122,6 +461,16 @@ WTransform painter_get_transform(QPainter const &p) {
     return WTransform{t.m11(), t.m12(), t.m21(), t.m22(), t.m31(), t.m32()};
 }
 
+void painter_clip_rect(QPainter &p, QRectF const &rect) {
+    p.setClipRect(rect,
+                  p.hasClipping() ? Qt::IntersectClip : Qt::ReplaceClip);
+}
+
+void painter_clip_path(QPainter &p, QPainterPath const &path) {
+    p.setClipPath(path,
+                  p.hasClipping() ? Qt::IntersectClip : Qt::ReplaceClip);
+}
+
 void color_transparent(QColor &c) noexcept { new (&c) QColor{Qt::transparent}; }
 
 bool color_accent(QColor &c) {
@@ -134,6 +483,25 @@ bool color_accent(QColor &c) {
 #endif
 }
 
+void pen_set_stroke(QPen &p, QtPenCapStyle cap, QtPenJoinStyle join,
+                    double miter_limit, rust::Slice<const double> dashes,
+                    double dash_offset) {
+    p.setCapStyle(cap);
+    p.setJoinStyle(join);
+    p.setMiterLimit(miter_limit);
+    if (dashes.empty()) {
+        p.setStyle(Qt::SolidLine);
+    } else {
+        QVector<qreal> pattern(dashes.begin(), dashes.end());
+        // Qt requires an even count; repeat the pattern like SVG.
+        if (pattern.size() % 2 != 0) {
+            pattern.append(pattern);
+        }
+        p.setDashPattern(pattern);
+        p.setDashOffset(dash_offset);
+    }
+}
+
 std::unique_ptr<QGradient> new_gradient_linear(QPointF start, QPointF end) {
     return std::make_unique<QLinearGradient>(start, end);
 }
@@ -143,6 +511,30 @@ std::unique_ptr<QGradient> new_gradient_radial(QPointF center, double radius,
     return std::make_unique<QRadialGradient>(center, radius, origin);
 }
 
+std::unique_ptr<QGradient> new_gradient_conical(QPointF center, double angle) {
+    return std::make_unique<QConicalGradient>(center, angle);
+}
+
+QBrush new_brush_image(QImage const &image, double opacity, bool reflect) {
+    int width = image.width();
+    int height = image.height();
+    // Reflection is emulated by tiling a texture with the mirrored copies.
+    QImage texture{reflect ? width * 2 : width, reflect ? height * 2 : height,
+                   QImage::Format_ARGB32_Premultiplied};
+    texture.fill(Qt::transparent);
+    {
+        QPainter p{&texture};
+        p.setOpacity(opacity);
+        p.drawImage(0, 0, image);
+        if (reflect) {
+            p.drawImage(width, 0, image.mirrored(true, false));
+            p.drawImage(0, height, image.mirrored(false, true));
+            p.drawImage(width, height, image.mirrored(true, true));
+        }
+    }
+    return QBrush(texture);
+}
+
 void brush_set_transform(QBrush &b, double m11, double m12, double m21,
                          double m22, double m31, double m32) {
     b.setTransform(QTransform{m11, m12, m21, m22, m31, m32});
@@ -161,3 +553,27 @@ void painter_draw_image(QPainter &p, QRectF const &target, QImage const &image,
 std::unique_ptr<QPainterPath> new_path() {
     return std::make_unique<QPainterPath>();
 }
+
+bool path_contains(QPainterPath const &path, QPointF p, QtFillRule rule) {
+    QPainterPath copy{path};
+    copy.setFillRule(rule);
+    return copy.contains(p);
+}
+
+bool path_stroke_contains(QPainterPath const &path, QPointF p, double width) {
+    QPainterPathStroker stroker{};
+    stroker.setWidth(width);
+    return stroker.createStroke(path).contains(p);
+}
+
+std::unique_ptr<QPainterPath> path_transform(QPainterPath const &path,
+                                             WTransform const &t) {
+    QTransform transform{t.m11, t.m12, t.m21, t.m22, t.m31, t.m32};
+    return std::make_unique<QPainterPath>(transform.map(path));
+}
+
+void painter_fill_path(QPainter &p, QPainterPath const &path, QtFillRule rule) {
+    QPainterPath copy{path};
+    copy.setFillRule(rule);
+    p.drawPath(copy);
+}
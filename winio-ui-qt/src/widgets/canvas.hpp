@@ -1,13 +1,15 @@
 #pragma once
 
 #include "../common.hpp"
+#include "widget.hpp"
 #include <QGradient>
 #include <QImage>
 #include <QMouseEvent>
 #include <QPaintEvent>
 #include <QPainter>
 #include <QPainterPath>
-#include <QPicture>
+#include <QResizeEvent>
+#include <QTextLayout>
 #include <QWheelEvent>
 #include <QWidget>
 #include <memory>
@@ -20,7 +22,13 @@
 
 using QtMouseButton = Qt::MouseButton;
 using QtSizeMode = Qt::SizeMode;
+using QtPenCapStyle = Qt::PenCapStyle;
+using QtPenJoinStyle = Qt::PenJoinStyle;
 using QImageFormat = QImage::Format;
+using QGradientSpread = QGradient::Spread;
+using QPainterCompositionMode = QPainter::CompositionMode;
+using QtFillRule = Qt::FillRule;
+using QtAlignmentFlag = Qt::Alignment;
 
 struct WinioCanvas :
 #ifdef WINIO_UI_QT_OPENGL
@@ -29,26 +37,42 @@ struct WinioCanvas :
     public QWidget
 #endif
 {
-    callback_t<void()> m_paint_callback;
+    callback_t<void(int, int)> m_resize_callback;
     callback_t<void(int, int)> m_move_callback;
     callback_t<void(QtMouseButton)> m_press_callback;
     callback_t<void(QtMouseButton)> m_release_callback;
     callback_t<void(int, int)> m_wheel_callback;
+    callback_t<void(QtMouseButton, int, int, int, int)> m_button_press_callback;
+    callback_t<void(QtMouseButton, int, int, int, int)>
+        m_button_release_callback;
+    callback_t<void(int, int)> m_enter_callback;
+    callback_t<void()> m_leave_callback;
+    int m_clicks;
 
-    QPicture m_buffer;
+    QImage m_buffer;
 
     WinioCanvas(QWidget *parent);
     ~WinioCanvas() override;
 
 protected:
     void paintEvent(QPaintEvent *event) override;
+    void resizeEvent(QResizeEvent *event) override;
     void mouseMoveEvent(QMouseEvent *event) override;
     void mousePressEvent(QMouseEvent *event) override;
     void mouseReleaseEvent(QMouseEvent *event) override;
     void wheelEvent(QWheelEvent *event) override;
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+    void enterEvent(QEnterEvent *event) override;
+#else
+    void enterEvent(QEvent *event) override;
+#endif
+    void leaveEvent(QEvent *event) override;
 };
 
 std::unique_ptr<QWidget> new_canvas(QWidget *parent);
+void canvas_register_resize_event(QWidget &w,
+                                  callback_fn_t<void(int, int)> callback,
+                                  std::uint8_t const *data);
 void canvas_register_move_event(QWidget &w,
                                 callback_fn_t<void(int, int)> callback,
                                 std::uint8_t const *data);
@@ -61,16 +85,65 @@ void canvas_register_release_event(QWidget &w,
 void canvas_register_wheel_event(QWidget &w,
                                  callback_fn_t<void(int, int)> callback,
                                  std::uint8_t const *data);
+void canvas_register_button_press_event(
+    QWidget &w,
+    callback_fn_t<void(QtMouseButton, int, int, int, int)> callback,
+    std::uint8_t const *data);
+void canvas_register_button_release_event(
+    QWidget &w,
+    callback_fn_t<void(QtMouseButton, int, int, int, int)> callback,
+    std::uint8_t const *data);
+void canvas_register_enter_event(QWidget &w,
+                                 callback_fn_t<void(int, int)> callback,
+                                 std::uint8_t const *data);
+void canvas_register_leave_event(QWidget &w, callback_fn_t<void()> callback,
+                                 std::uint8_t const *data);
 
-std::unique_ptr<QPainter> canvas_new_painter(QWidget &w);
-void painter_set_font(QPainter &p, rust::Str family, double size, bool italic,
-                      bool bold);
+bool canvas_buffer_valid(QWidget const &w);
+std::unique_ptr<QImage> canvas_new_buffer(QWidget &w, bool keep);
+void canvas_set_buffer(QWidget &w, QImage const &image);
+std::unique_ptr<QImage> canvas_new_layer(QWidget &w);
+std::unique_ptr<QImage> image_new_layer(QImage const &image);
+std::unique_ptr<QImage> new_bitmap(QImage const &image, double width,
+                                   double height);
+std::unique_ptr<QImage> image_unscaled(QImage const &image);
+rust::Vec<std::uint8_t> image_rgba_bytes(QImage const &image);
+std::unique_ptr<QPainter> new_image_painter(QImage &image);
+void painter_clear_rect(QPainter &p, QRectF const &rect);
+void painter_draw_layer(QPainter &p, QImage const &image, double opacity,
+                        QPainterCompositionMode mode);
+void painter_set_font(QPainter &p, rust::Str families, double size, int weight,
+                      int stretch, bool italic, bool underline, bool strikeout,
+                      double letter_spacing);
 QSizeF painter_measure_text(QPainter &p, QRectF rect, rust::Str text);
 void painter_draw_text(QPainter &p, QRectF rect, rust::Str text);
 
+std::unique_ptr<QTextLayout>
+new_text_layout(rust::Str text, rust::Str families, double size, int weight,
+                int stretch, bool italic, bool underline, bool strikeout,
+                double letter_spacing);
+void text_layout_add_font(QTextLayout &l, int start, int len,
+                          rust::Str families, double size, int weight,
+                          int stretch, bool italic, bool underline,
+                          bool strikeout, double letter_spacing);
+void text_layout_add_color(QTextLayout &l, int start, int len,
+                           QColor const &c);
+void text_layout_add_underline(QTextLayout &l, int start, int len,
+                               bool underline);
+void text_layout_build(QTextLayout &l, double max_width, QtAlignmentFlag align,
+                       double line_spacing, int max_lines, bool ellipsis);
+QSizeF text_layout_size(QTextLayout const &l);
+rust::Vec<WLineMetrics> text_layout_lines(QTextLayout const &l);
+int text_layout_hit_test(QTextLayout const &l, QPointF pos, bool &inside);
+QRectF text_layout_caret_rect(QTextLayout const &l, int index);
+void painter_draw_text_layout(QPainter &p, QTextLayout const &l, QPointF pos);
+
 void painter_set_transform(QPainter &p, WTransform const &t);
 WTransform painter_get_transform(QPainter const &p);
 
+void painter_clip_rect(QPainter &p, QRectF const &rect);
+void painter_clip_path(QPainter &p, QPainterPath const &path);
+
 void color_transparent(QColor &c) noexcept;
 bool color_accent(QColor &c);
 
@@ -85,14 +158,20 @@ static_assert(sizeof(QPen) == sizeof(std::size_t));
 inline QBrush new_brush(QColor const &c) { return QBrush(c); }
 inline QPen new_pen(QBrush const &b, double width) { return QPen(b, width); }
 
+void pen_set_stroke(QPen &p, QtPenCapStyle cap, QtPenJoinStyle join,
+                    double miter_limit, rust::Slice<const double> dashes,
+                    double dash_offset);
+
 inline void brush_drop(QBrush &b) noexcept { b.~QBrush(); }
 inline void pen_drop(QPen &p) noexcept { p.~QPen(); }
 
 std::unique_ptr<QGradient> new_gradient_linear(QPointF start, QPointF end);
 std::unique_ptr<QGradient> new_gradient_radial(QPointF center, double radius,
                                                QPointF origin);
+std::unique_ptr<QGradient> new_gradient_conical(QPointF center, double angle);
 
 inline QBrush new_brush_gradient(QGradient const &g) { return QBrush(g); }
+QBrush new_brush_image(QImage const &image, double opacity, bool reflect);
 void brush_set_transform(QBrush &b, double m11, double m12, double m21,
                          double m22, double m31, double m32);
 
@@ -102,3 +181,8 @@ void painter_draw_image(QPainter &p, QRectF const &target, QImage const &image,
                         QRectF const &source);
 
 std::unique_ptr<QPainterPath> new_path();
+bool path_contains(QPainterPath const &path, QPointF p, QtFillRule rule);
+bool path_stroke_contains(QPainterPath const &path, QPointF p, double width);
+std::unique_ptr<QPainterPath> path_transform(QPainterPath const &path,
+                                             WTransform const &t);
+void painter_fill_path(QPainter &p, QPainterPath const &path, QtFillRule rule);
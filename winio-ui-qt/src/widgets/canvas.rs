@@ -2,16 +2,22 @@ use std::{cell::RefCell, mem::MaybeUninit, pin::Pin};
 
 use compio_log::error;
 use cxx::{ExternType, UniquePtr, type_id};
-use image::{DynamicImage, Pixel, Rgb, Rgba};
+use image::{DynamicImage, Pixel, Rgb, Rgba, RgbaImage};
 use inherit_methods_macro::inherit_methods;
 use winio_callback::Callback;
 use winio_handle::AsContainer;
 use winio_primitive::{
-    BrushPen, Color, Font, LinearGradientBrush, MouseButton, Point, RadialGradientBrush, Rect,
-    RectBox, RelativePoint, RelativeToLogical, Size, SolidColorBrush, Transform, Vector,
+    BlendMode, BrushPen, Color, ConicGradientBrush, Cursor, FillRule, Font, GradientStop, HAlign,
+    ImageBrush, LineCap, LineJoin, LineMetrics, LinearGradientBrush, Modifiers, MouseButton,
+    MouseButtonEvent, Point, RadialGradientBrush, Rect, RectBox, RelativePoint, RelativeToLogical,
+    Size, SolidColorBrush, SpreadMode, StrokeStyle, TextFormat, TextHitTest, TextSpan, Transform,
+    Vector,
 };
 
-use crate::{Error, GlobalRuntime, Result, widgets::Widget};
+use crate::{
+    Error, GlobalRuntime, Result,
+    widgets::{QtAlignmentFlag, Widget, font_families},
+};
 
 #[derive(Debug)]
 pub struct Canvas {
@@ -19,6 +25,11 @@ pub struct Canvas {
     on_press: Box<Callback<MouseButton>>,
     on_release: Box<Callback<MouseButton>>,
     on_wheel: Box<Callback<Vector>>,
+    on_button_press: Box<Callback<MouseButtonEvent>>,
+    on_button_release: Box<Callback<MouseButtonEvent>>,
+    on_enter: Box<Callback<Point>>,
+    on_leave: Box<Callback>,
+    on_paint: Box<Callback<Rect>>,
     widget: Widget<ffi::QWidget>,
 }
 
@@ -31,7 +42,17 @@ impl Canvas {
         let on_press = Box::new(Callback::new());
         let on_release = Box::new(Callback::new());
         let on_wheel = Box::new(Callback::new());
+        let on_button_press = Box::new(Callback::new());
+        let on_button_release = Box::new(Callback::new());
+        let on_enter = Box::new(Callback::new());
+        let on_leave = Box::new(Callback::new());
+        let on_paint = Box::new(Callback::new());
         unsafe {
+            ffi::canvas_register_resize_event(
+                widget.pin_mut(),
+                Self::on_resize,
+                on_paint.as_ref() as *const _ as _,
+            )?;
             ffi::canvas_register_move_event(
                 widget.pin_mut(),
                 Self::on_move,
@@ -52,12 +73,37 @@ impl Canvas {
                 Self::on_wheel,
                 on_wheel.as_ref() as *const _ as _,
             )?;
+            ffi::canvas_register_button_press_event(
+                widget.pin_mut(),
+                Self::on_button,
+                on_button_press.as_ref() as *const _ as _,
+            )?;
+            ffi::canvas_register_button_release_event(
+                widget.pin_mut(),
+                Self::on_button,
+                on_button_release.as_ref() as *const _ as _,
+            )?;
+            ffi::canvas_register_enter_event(
+                widget.pin_mut(),
+                Self::on_enter,
+                on_enter.as_ref() as *const _ as _,
+            )?;
+            ffi::canvas_register_leave_event(
+                widget.pin_mut(),
+                Self::on_leave,
+                on_leave.as_ref() as *const _ as _,
+            )?;
         }
         Ok(Self {
             on_move,
             on_press,
             on_release,
             on_wheel,
+            on_button_press,
+            on_button_release,
+            on_enter,
+            on_leave,
+            on_paint,
             widget: Widget::new(widget)?,
         })
     }
@@ -82,6 +128,8 @@ impl Canvas {
 
     pub fn set_tooltip(&mut self, s: impl AsRef<str>) -> Result<()>;
 
+    pub fn set_cursor(&mut self, cursor: Cursor) -> Result<()>;
+
     fn on_move(c: *const u8, x: i32, y: i32) {
         let c = c as *const Callback<Point>;
         if let Some(c) = unsafe { c.as_ref() } {
@@ -110,8 +158,53 @@ impl Canvas {
         }
     }
 
-    pub fn context(&mut self) -> Result<DrawingContext<'_>> {
-        DrawingContext::new(ffi::canvas_new_painter(self.widget.pin_mut())?, self)
+    fn on_button(c: *const u8, m: QtMouseButton, x: i32, y: i32, modifiers: i32, clicks: i32) {
+        let c = c as *const Callback<MouseButtonEvent>;
+        if let Some(c) = unsafe { c.as_ref() } {
+            c.signal::<GlobalRuntime>(mouse_button_event(m, x, y, modifiers, clicks));
+        }
+    }
+
+    fn on_enter(c: *const u8, x: i32, y: i32) {
+        let c = c as *const Callback<Point>;
+        if let Some(c) = unsafe { c.as_ref() } {
+            c.signal::<GlobalRuntime>(Point::new(x as _, y as _));
+        }
+    }
+
+    fn on_leave(c: *const u8) {
+        let c = c as *const Callback;
+        if let Some(c) = unsafe { c.as_ref() } {
+            c.signal::<GlobalRuntime>(());
+        }
+    }
+
+    fn on_resize(c: *const u8, width: i32, height: i32) {
+        let c = c as *const Callback<Rect>;
+        if let Some(c) = unsafe { c.as_ref() } {
+            c.signal::<GlobalRuntime>(Rect::new(Point::zero(), Size::new(width as _, height as _)));
+        }
+    }
+
+    pub fn invalidate(&mut self, rect: Rect) -> Result<()> {
+        self.on_paint.signal::<()>(rect);
+        Ok(())
+    }
+
+    pub fn context(&mut self, dirty: Option<Rect>) -> Result<DrawingContext<'_>> {
+        let dirty = match dirty {
+            Some(dirty) if ffi::canvas_buffer_valid(self.widget.as_ref())? => Some(dirty),
+            _ => None,
+        };
+        let mut buffer = ffi::canvas_new_buffer(self.widget.pin_mut(), dirty.is_some())?;
+        let mut painter = ffi::new_image_painter(buffer.pin_mut())?;
+        // Keep the front buffer outside the dirty rectangle.
+        if let Some(dirty) = dirty {
+            ffi::painter_clear_rect(painter.pin_mut(), &QRectF(dirty))?;
+            ffi::painter_clip_rect(painter.pin_mut(), &QRectF(dirty))?;
+        }
+        let size = self.size()?;
+        DrawingContext::new(buffer, painter, size, DrawingTarget::Canvas(self))
     }
 
     pub async fn wait_mouse_down(&self) -> MouseButton {
@@ -129,23 +222,72 @@ impl Canvas {
     pub async fn wait_mouse_wheel(&self) -> Vector {
         self.on_wheel.wait().await
     }
+
+    pub async fn wait_mouse_button_down(&self) -> MouseButtonEvent {
+        self.on_button_press.wait().await
+    }
+
+    pub async fn wait_mouse_button_up(&self) -> MouseButtonEvent {
+        self.on_button_release.wait().await
+    }
+
+    pub async fn wait_mouse_enter(&self) -> Point {
+        self.on_enter.wait().await
+    }
+
+    pub async fn wait_mouse_leave(&self) {
+        self.on_leave.wait().await
+    }
+
+    pub async fn wait_paint(&self) -> Rect {
+        self.on_paint.wait().await
+    }
 }
 
 winio_handle::impl_as_widget!(Canvas, widget);
 
 pub struct DrawingContext<'a> {
+    buffer: UniquePtr<ffi::QImage>,
     painter: RefCell<UniquePtr<ffi::QPainter>>,
     size: Size,
-    canvas: &'a mut Canvas,
+    target: DrawingTarget<'a>,
+    clips: usize,
+    layers: Vec<Layer>,
     ended: bool,
 }
 
+/// The owner of a [`DrawingContext`].
+enum DrawingTarget<'a> {
+    /// The buffer is set to the canvas when ended.
+    Canvas(&'a mut Canvas),
+    /// The buffer is taken from the bitmap, and given back when ended.
+    Bitmap(&'a mut DrawingBitmap),
+}
+
+/// A pushed layer. The painter of the layer image is swapped with the parent
+/// painter.
+struct Layer {
+    image: UniquePtr<ffi::QImage>,
+    parent: UniquePtr<ffi::QPainter>,
+    clips: usize,
+    opacity: f64,
+    mode: QPainterCompositionMode,
+}
+
 impl<'a> DrawingContext<'a> {
-    fn new(painter: UniquePtr<ffi::QPainter>, canvas: &'a mut Canvas) -> Result<Self> {
+    fn new(
+        buffer: UniquePtr<ffi::QImage>,
+        painter: UniquePtr<ffi::QPainter>,
+        size: Size,
+        target: DrawingTarget<'a>,
+    ) -> Result<Self> {
         Ok(Self {
+            buffer,
             painter: RefCell::new(painter),
-            size: canvas.size()?,
-            canvas,
+            size,
+            target,
+            clips: 0,
+            layers: vec![],
             ended: false,
         })
     }
@@ -172,8 +314,22 @@ fn drawing_angle(angle: f64) -> i32 {
 impl DrawingContext<'_> {
     fn end(&mut self) -> Result<()> {
         if !self.ended {
-            self.painter.get_mut().pin_mut().end()?;
-            self.canvas.widget.pin_mut().update()?;
+            while !self.layers.is_empty() {
+                self.pop_layer()?;
+            }
+            let painter = self.painter.get_mut();
+            for _ in 0..std::mem::take(&mut self.clips) {
+                painter.pin_mut().restore()?;
+            }
+            painter.pin_mut().end()?;
+            match &mut self.target {
+                DrawingTarget::Canvas(canvas) => {
+                    ffi::canvas_set_buffer(canvas.widget.pin_mut(), &self.buffer)?
+                }
+                DrawingTarget::Bitmap(bitmap) => {
+                    bitmap.image = std::mem::replace(&mut self.buffer, UniquePtr::null())
+                }
+            }
             self.ended = true;
         }
         Ok(())
@@ -199,6 +355,83 @@ impl DrawingContext<'_> {
         Ok(t.into())
     }
 
+    pub fn push_clip_rect(&mut self, rect: Rect) -> Result<()> {
+        let painter = self.painter.get_mut();
+        painter.pin_mut().save()?;
+        ffi::painter_clip_rect(painter.pin_mut(), &QRectF(rect))?;
+        self.clips += 1;
+        Ok(())
+    }
+
+    pub fn push_clip_path(&mut self, path: &DrawingPath) -> Result<()> {
+        let painter = self.painter.get_mut();
+        painter.pin_mut().save()?;
+        ffi::painter_clip_path(painter.pin_mut(), &path.0)?;
+        self.clips += 1;
+        Ok(())
+    }
+
+    pub fn pop_clip(&mut self) -> Result<()> {
+        if self.clips == 0 {
+            return Ok(());
+        }
+        // Only the clip is restored; the transform is kept.
+        let transform = self.transform()?;
+        self.painter.get_mut().pin_mut().restore()?;
+        self.clips -= 1;
+        self.set_transform(transform)
+    }
+
+    pub fn push_layer(&mut self, opacity: f64, blend: BlendMode) -> Result<()> {
+        let transform = self.transform()?;
+        let mut image = ffi::image_new_layer(&self.buffer)?;
+        let painter = ffi::new_image_painter(image.pin_mut())?;
+        let parent = std::mem::replace(self.painter.get_mut(), painter);
+        let mode = match blend {
+            BlendMode::Multiply => QPainterCompositionMode::Multiply,
+            BlendMode::Screen => QPainterCompositionMode::Screen,
+            BlendMode::Overlay => QPainterCompositionMode::Overlay,
+            BlendMode::Darken => QPainterCompositionMode::Darken,
+            BlendMode::Lighten => QPainterCompositionMode::Lighten,
+            BlendMode::ColorDodge => QPainterCompositionMode::ColorDodge,
+            BlendMode::ColorBurn => QPainterCompositionMode::ColorBurn,
+            BlendMode::HardLight => QPainterCompositionMode::HardLight,
+            BlendMode::SoftLight => QPainterCompositionMode::SoftLight,
+            BlendMode::Difference => QPainterCompositionMode::Difference,
+            BlendMode::Exclusion => QPainterCompositionMode::Exclusion,
+            _ => QPainterCompositionMode::SourceOver,
+        };
+        self.layers.push(Layer {
+            image,
+            parent,
+            clips: std::mem::take(&mut self.clips),
+            opacity,
+            mode,
+        });
+        self.set_transform(transform)
+    }
+
+    pub fn pop_layer(&mut self) -> Result<()> {
+        let Some(layer) = self.layers.pop() else {
+            return Ok(());
+        };
+        // Only the layer is popped; the transform is kept.
+        let transform = self.transform()?;
+        let mut painter = std::mem::replace(self.painter.get_mut(), layer.parent);
+        for _ in 0..std::mem::replace(&mut self.clips, layer.clips) {
+            painter.pin_mut().restore()?;
+        }
+        painter.pin_mut().end()?;
+        drop(painter);
+        ffi::painter_draw_layer(
+            self.painter.get_mut().pin_mut(),
+            &layer.image,
+            layer.opacity,
+            layer.mode,
+        )?;
+        self.set_transform(transform)
+    }
+
     fn set_brush(&mut self, brush: impl Brush, rect: Rect) -> Result<()> {
         let painter = self.painter.get_mut();
         painter.pin_mut().setBrush(&brush.create(to_trans(rect))?)?;
@@ -222,10 +455,15 @@ impl DrawingContext<'_> {
         Ok(())
     }
 
-    pub fn fill_path(&mut self, brush: impl Brush, path: &DrawingPath) -> Result<()> {
+    pub fn fill_path(
+        &mut self,
+        brush: impl Brush,
+        path: &DrawingPath,
+        rule: FillRule,
+    ) -> Result<()> {
         let rect = path.0.boundingRect()?;
         self.set_brush(brush, rect.0)?;
-        self.painter.get_mut().pin_mut().drawPath(&path.0)?;
+        ffi::painter_fill_path(self.painter.get_mut().pin_mut(), &path.0, fill_rule(rule))?;
         Ok(())
     }
 
@@ -329,10 +567,14 @@ impl DrawingContext<'_> {
         let mut painter = self.painter.borrow_mut();
         ffi::painter_set_font(
             painter.pin_mut(),
-            &font.family,
+            &font_families(font),
             font.size,
+            font.weight.0 as _,
+            font.stretch.percent() as _,
             font.italic,
-            font.bold,
+            font.underline,
+            font.strikethrough,
+            font.letter_spacing,
         )?;
         let rect = Rect::new(Point::zero(), self.size);
         Ok(ffi::painter_measure_text(painter.pin_mut(), QRectF(rect), text)?.0)
@@ -360,6 +602,85 @@ impl DrawingContext<'_> {
         self.measure_str_impl(&font, text)
     }
 
+    pub fn create_text_layout(
+        &self,
+        text: &str,
+        font: Font,
+        spans: &[TextSpan],
+        format: &TextFormat,
+    ) -> Result<TextLayout> {
+        let mut layout = ffi::new_text_layout(
+            text,
+            &font_families(&font),
+            font.size,
+            font.weight.0 as _,
+            font.stretch.percent() as _,
+            font.italic,
+            font.underline,
+            font.strikethrough,
+            font.letter_spacing,
+        )?;
+        for span in spans {
+            let start = utf16_index(text, span.range.start);
+            let len = utf16_index(text, span.range.end) - start;
+            if let Some(font) = &span.font {
+                ffi::text_layout_add_font(
+                    layout.pin_mut(),
+                    start,
+                    len,
+                    &font_families(font),
+                    font.size,
+                    font.weight.0 as _,
+                    font.stretch.percent() as _,
+                    font.italic,
+                    font.underline,
+                    font.strikethrough,
+                    font.letter_spacing,
+                )?;
+            }
+            if let Some(color) = span.color {
+                ffi::text_layout_add_color(layout.pin_mut(), start, len, &QColor::from(color))?;
+            }
+            if let Some(underline) = span.underline {
+                ffi::text_layout_add_underline(layout.pin_mut(), start, len, underline)?;
+            }
+        }
+        let align = match format.align {
+            HAlign::Center => QtAlignmentFlag::AlignHCenter,
+            HAlign::Right => QtAlignmentFlag::AlignRight,
+            HAlign::Stretch => QtAlignmentFlag::AlignJustify,
+            _ => QtAlignmentFlag::AlignLeft,
+        };
+        ffi::text_layout_build(
+            layout.pin_mut(),
+            format.max_width.unwrap_or(-1.0),
+            align,
+            format.line_spacing,
+            format.max_lines.map(|n| n as i32).unwrap_or(-1),
+            format.ellipsis,
+        )?;
+        Ok(TextLayout {
+            text: text.to_string(),
+            layout,
+        })
+    }
+
+    pub fn draw_text_layout(
+        &mut self,
+        brush: impl Brush,
+        layout: &TextLayout,
+        pos: Point,
+    ) -> Result<()> {
+        let rect = Rect::new(pos, layout.size()?);
+        self.set_pen(BrushPen::new(brush, 1.0), rect)?;
+        ffi::painter_draw_text_layout(
+            self.painter.get_mut().pin_mut(),
+            &layout.layout,
+            QPointF(pos),
+        )?;
+        Ok(())
+    }
+
     pub fn create_image(&self, image: DynamicImage) -> Result<DrawingImage> {
         DrawingImage::new(image)
     }
@@ -386,10 +707,109 @@ impl DrawingContext<'_> {
     pub fn create_path_builder(&self, start: Point) -> Result<DrawingPathBuilder> {
         DrawingPathBuilder::new(start)
     }
+
+    pub fn create_bitmap(&self, size: Size) -> Result<DrawingBitmap> {
+        Ok(DrawingBitmap {
+            image: ffi::new_bitmap(&self.buffer, size.width, size.height)?,
+            size,
+        })
+    }
+}
+
+/// The UTF-16 index of a byte index.
+fn utf16_index(text: &str, index: usize) -> i32 {
+    text.char_indices()
+        .take_while(|(i, _)| *i < index)
+        .map(|(_, c)| c.len_utf16())
+        .sum::<usize>() as _
+}
+
+/// The byte index of a UTF-16 index.
+fn utf8_index(text: &str, index: i32) -> usize {
+    let mut offset = 0;
+    for (i, c) in text.char_indices() {
+        if offset >= index as usize {
+            return i;
+        }
+        offset += c.len_utf16();
+    }
+    text.len()
+}
+
+pub struct TextLayout {
+    text: String,
+    layout: UniquePtr<ffi::QTextLayout>,
+}
+
+impl TextLayout {
+    pub fn size(&self) -> Result<Size> {
+        Ok(ffi::text_layout_size(&self.layout)?.0)
+    }
+
+    pub fn line_metrics(&self) -> Result<Vec<LineMetrics>> {
+        Ok(ffi::text_layout_lines(&self.layout)?
+            .into_iter()
+            .map(|line| LineMetrics {
+                range: utf8_index(&self.text, line.start)
+                    ..utf8_index(&self.text, line.start + line.len),
+                rect: Rect::new(
+                    Point::new(line.x, line.y),
+                    Size::new(line.width, line.height),
+                ),
+                baseline: line.baseline,
+            })
+            .collect())
+    }
+
+    pub fn hit_test(&self, p: Point) -> Result<TextHitTest> {
+        let mut inside = false;
+        let index = ffi::text_layout_hit_test(&self.layout, QPointF(p), &mut inside)?;
+        Ok(TextHitTest {
+            index: utf8_index(&self.text, index),
+            inside,
+        })
+    }
+
+    pub fn caret_rect(&self, index: usize) -> Result<Rect> {
+        let index = utf16_index(&self.text, index);
+        Ok(ffi::text_layout_caret_rect(&self.layout, index)?.0)
+    }
 }
 
 pub struct DrawingPath(UniquePtr<ffi::QPainterPath>);
 
+fn fill_rule(rule: FillRule) -> QtFillRule {
+    match rule {
+        FillRule::EvenOdd => QtFillRule::OddEvenFill,
+        _ => QtFillRule::WindingFill,
+    }
+}
+
+impl DrawingPath {
+    pub fn bounds(&self) -> Result<Rect> {
+        Ok(self.0.boundingRect()?.0)
+    }
+
+    pub fn contains(&self, p: Point, rule: FillRule) -> Result<bool> {
+        Ok(ffi::path_contains(&self.0, QPointF(p), fill_rule(rule))?)
+    }
+
+    pub fn stroke_contains(&self, p: Point, width: f64) -> Result<bool> {
+        Ok(ffi::path_stroke_contains(&self.0, QPointF(p), width)?)
+    }
+
+    pub fn length(&self) -> Result<f64> {
+        Ok(self.0.length()?)
+    }
+
+    pub fn transform(&self, transform: &Transform) -> Result<DrawingPath> {
+        Ok(DrawingPath(ffi::path_transform(
+            &self.0,
+            &(*transform).into(),
+        )?))
+    }
+}
+
 pub struct DrawingPathBuilder(UniquePtr<ffi::QPainterPath>);
 
 impl DrawingPathBuilder {
@@ -404,6 +824,16 @@ impl DrawingPathBuilder {
         Ok(())
     }
 
+    pub fn move_to(&mut self, p: Point) -> Result<()> {
+        self.0.pin_mut().moveTo(p.x, p.y)?;
+        Ok(())
+    }
+
+    pub fn close_figure(&mut self) -> Result<()> {
+        self.0.pin_mut().closeSubpath()?;
+        Ok(())
+    }
+
     pub fn add_arc(
         &mut self,
         center: Point,
@@ -430,6 +860,21 @@ impl DrawingPathBuilder {
         Ok(())
     }
 
+    pub fn add_quad(&mut self, p1: Point, p2: Point) -> Result<()> {
+        self.0.pin_mut().quadTo(p1.x, p1.y, p2.x, p2.y)?;
+        Ok(())
+    }
+
+    pub fn add_rect(&mut self, rect: Rect) -> Result<()> {
+        self.0.pin_mut().addRect(&QRectF(rect))?;
+        Ok(())
+    }
+
+    pub fn add_ellipse(&mut self, rect: Rect) -> Result<()> {
+        self.0.pin_mut().addEllipse(&QRectF(rect))?;
+        Ok(())
+    }
+
     pub fn build(mut self, close: bool) -> Result<DrawingPath> {
         if close {
             self.0.pin_mut().closeSubpath()?;
@@ -452,20 +897,34 @@ impl<B: Brush> Brush for &'_ B {
 
 impl Brush for SolidColorBrush {
     fn create(&self, _trans: RelativeToLogical) -> Result<QBrush> {
-        Ok(ffi::new_brush(&self.color.into())?)
+        Ok(ffi::new_brush(&self.faded_color().into())?)
     }
 }
 
+fn gradient_set_stops(
+    mut g: Pin<&mut ffi::QGradient>,
+    stops: &[GradientStop],
+    spread: SpreadMode,
+) -> Result<()> {
+    for stop in stops {
+        g.as_mut().setColorAt(stop.pos, &QColor::from(stop.color))?;
+    }
+    let spread = match spread {
+        SpreadMode::Repeat => QGradientSpread::RepeatSpread,
+        SpreadMode::Reflect => QGradientSpread::ReflectSpread,
+        _ => QGradientSpread::PadSpread,
+    };
+    g.setSpread(spread)?;
+    Ok(())
+}
+
 impl Brush for LinearGradientBrush {
     fn create(&self, trans: RelativeToLogical) -> Result<QBrush> {
         let mut g = ffi::new_gradient_linear(
             QPointF(Point::new(self.start.x, self.start.y)),
             QPointF(Point::new(self.end.x, self.end.y)),
         )?;
-        for stop in &self.stops {
-            g.pin_mut()
-                .setColorAt(stop.pos, &QColor::from(stop.color))?;
-        }
+        gradient_set_stops(g.pin_mut(), &self.faded_stops(), self.spread)?;
         let mut brush = ffi::new_brush_gradient(&g)?;
         brush_set_transform(Pin::new(&mut brush), trans)?;
         Ok(brush)
@@ -480,16 +939,50 @@ impl Brush for RadialGradientBrush {
             self.radius.width,
             QPointF(Point::new(self.origin.x, self.origin.y)),
         )?;
-        for stop in &self.stops {
-            g.pin_mut()
-                .setColorAt(stop.pos, &QColor::from(stop.color))?;
-        }
+        gradient_set_stops(g.pin_mut(), &self.faded_stops(), self.spread)?;
+        let mut brush = ffi::new_brush_gradient(&g)?;
+        brush_set_transform(Pin::new(&mut brush), trans)?;
+        Ok(brush)
+    }
+}
+
+impl Brush for ConicGradientBrush {
+    fn create(&self, trans: RelativeToLogical) -> Result<QBrush> {
+        // Qt conical gradients go counter-clockwise.
+        let mut g = ffi::new_gradient_conical(
+            QPointF(Point::new(self.center.x, self.center.y)),
+            -self.angle.to_degrees(),
+        )?;
+        let stops = self
+            .faded_stops()
+            .into_iter()
+            .map(|stop| GradientStop::new(stop.color, 1.0 - stop.pos))
+            .collect::<Vec<_>>();
+        gradient_set_stops(g.pin_mut(), &stops, SpreadMode::Pad)?;
         let mut brush = ffi::new_brush_gradient(&g)?;
         brush_set_transform(Pin::new(&mut brush), trans)?;
         Ok(brush)
     }
 }
 
+/// Qt texture brushes always tile the image, so [`SpreadMode::Pad`] behaves
+/// like [`SpreadMode::Repeat`].
+impl<I: AsRef<DrawingImage>> Brush for ImageBrush<I> {
+    fn create(&self, trans: RelativeToLogical) -> Result<QBrush> {
+        let image = self.image.as_ref();
+        let mut brush = ffi::new_brush_image(
+            &image.pixmap,
+            self.opacity,
+            matches!(self.spread, SpreadMode::Reflect),
+        )?;
+        brush_set_transform(
+            Pin::new(&mut brush),
+            self.pixel_transform(image.size()?, trans),
+        )?;
+        Ok(brush)
+    }
+}
+
 fn brush_set_transform(b: Pin<&mut ffi::QBrush>, trans: RelativeToLogical) -> Result<()> {
     ffi::brush_set_transform(
         b, trans.m11, trans.m12, trans.m21, trans.m22, trans.m31, trans.m32,
@@ -512,10 +1005,33 @@ impl<P: Pen> Pen for &'_ P {
 impl<B: Brush> Pen for BrushPen<B> {
     fn create(&self, trans: RelativeToLogical) -> Result<QPen> {
         let brush = self.brush.create(trans)?;
-        Ok(ffi::new_pen(&brush, self.width)?)
+        let mut pen = ffi::new_pen(&brush, self.width)?;
+        pen_set_stroke(Pin::new(&mut pen), &self.stroke)?;
+        Ok(pen)
     }
 }
 
+fn pen_set_stroke(p: Pin<&mut QPen>, stroke: &StrokeStyle) -> Result<()> {
+    let cap = match stroke.cap {
+        LineCap::Round => QtPenCapStyle::RoundCap,
+        LineCap::Square => QtPenCapStyle::SquareCap,
+        _ => QtPenCapStyle::FlatCap,
+    };
+    let join = match stroke.join {
+        LineJoin::Round => QtPenJoinStyle::RoundJoin,
+        LineJoin::Bevel => QtPenJoinStyle::BevelJoin,
+        // Falls back to bevel when exceeding the limit, like other platforms.
+        _ => QtPenJoinStyle::SvgMiterJoin,
+    };
+    let dashes = if stroke.is_dashed() {
+        stroke.dashes.as_slice()
+    } else {
+        &[]
+    };
+    ffi::pen_set_stroke(p, cap, join, stroke.miter_limit, dashes, stroke.dash_offset)?;
+    Ok(())
+}
+
 pub struct DrawingImage {
     #[allow(dead_code)]
     buffer: Vec<u8>,
@@ -571,6 +1087,45 @@ impl DrawingImage {
     }
 }
 
+impl AsRef<DrawingImage> for DrawingImage {
+    fn as_ref(&self) -> &DrawingImage {
+        self
+    }
+}
+
+/// An offscreen image, scaled by the device pixel ratio of the canvas.
+pub struct DrawingBitmap {
+    image: UniquePtr<ffi::QImage>,
+    size: Size,
+}
+
+impl DrawingBitmap {
+    pub fn size(&self) -> Result<Size> {
+        Ok(self.size)
+    }
+
+    pub fn context(&mut self) -> Result<DrawingContext<'_>> {
+        let painter = ffi::new_image_painter(self.image.pin_mut())?;
+        let buffer = std::mem::replace(&mut self.image, UniquePtr::null());
+        let size = self.size;
+        DrawingContext::new(buffer, painter, size, DrawingTarget::Bitmap(self))
+    }
+
+    pub fn to_image(&self) -> Result<DrawingImage> {
+        Ok(DrawingImage {
+            buffer: vec![],
+            pixmap: ffi::image_unscaled(&self.image)?,
+        })
+    }
+
+    pub fn to_rgba(&self) -> Result<RgbaImage> {
+        let size = self.image.size()?;
+        let bytes = ffi::image_rgba_bytes(&self.image)?;
+        RgbaImage::from_raw(size.width as _, size.height as _, bytes)
+            .ok_or_else(|| std::io::Error::from(std::io::ErrorKind::InvalidData).into())
+    }
+}
+
 /// Get the accent color.
 pub fn accent_color() -> Result<Color> {
     Ok(QColor::accent()?.into())
@@ -597,6 +1152,33 @@ impl From<QtMouseButton> for MouseButton {
     }
 }
 
+/// Build [`MouseButtonEvent`] from the states of a `QMouseEvent`.
+pub(crate) fn mouse_button_event(
+    button: QtMouseButton,
+    x: i32,
+    y: i32,
+    modifiers: i32,
+    clicks: i32,
+) -> MouseButtonEvent {
+    // Qt::KeyboardModifier
+    const SHIFT: i32 = 0x02000000;
+    const CONTROL: i32 = 0x04000000;
+    const ALT: i32 = 0x08000000;
+    const META: i32 = 0x10000000;
+
+    let mut m = Modifiers::empty();
+    m.set(Modifiers::Shift, modifiers & SHIFT != 0);
+    m.set(Modifiers::Control, modifiers & CONTROL != 0);
+    m.set(Modifiers::Alt, modifiers & ALT != 0);
+    m.set(Modifiers::Meta, modifiers & META != 0);
+    MouseButtonEvent {
+        button: button.into(),
+        pos: Point::new(x as _, y as _),
+        modifiers: m,
+        clicks: clicks.max(1) as _,
+    }
+}
+
 unsafe impl ExternType for QtMouseButton {
     type Id = type_id!("QtMouseButton");
     type Kind = cxx::kind::Trivial;
@@ -614,6 +1196,33 @@ unsafe impl ExternType for QtSizeMode {
     type Kind = cxx::kind::Trivial;
 }
 
+#[repr(i32)]
+#[allow(dead_code, clippy::enum_variant_names)]
+pub(crate) enum QtPenCapStyle {
+    FlatCap   = 0x00,
+    SquareCap = 0x10,
+    RoundCap  = 0x20,
+}
+
+unsafe impl ExternType for QtPenCapStyle {
+    type Id = type_id!("QtPenCapStyle");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[repr(i32)]
+#[allow(dead_code, clippy::enum_variant_names)]
+pub(crate) enum QtPenJoinStyle {
+    MiterJoin    = 0x00,
+    BevelJoin    = 0x40,
+    RoundJoin    = 0x80,
+    SvgMiterJoin = 0x100,
+}
+
+unsafe impl ExternType for QtPenJoinStyle {
+    type Id = type_id!("QtPenJoinStyle");
+    type Kind = cxx::kind::Trivial;
+}
+
 #[derive(PartialEq, Eq, Clone, Copy)]
 #[repr(i32)]
 #[allow(dead_code)]
@@ -684,6 +1293,14 @@ impl QColor {
             Err(Error::NotSupported)
         }
     }
+
+    /// An invalid color, to be filled by the FFI functions.
+    pub(crate) const fn invalid() -> Self {
+        Self {
+            cspec: Spec::Invalid,
+            ct: [0; 5],
+        }
+    }
 }
 
 impl From<Color> for QColor {
@@ -742,6 +1359,53 @@ unsafe impl ExternType for QImageFormat {
     type Kind = cxx::kind::Trivial;
 }
 
+#[repr(i32)]
+#[allow(clippy::enum_variant_names)]
+pub(crate) enum QtFillRule {
+    OddEvenFill = 0,
+    WindingFill = 1,
+}
+
+unsafe impl ExternType for QtFillRule {
+    type Id = type_id!("QtFillRule");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[repr(i32)]
+#[allow(dead_code)]
+pub(crate) enum QPainterCompositionMode {
+    SourceOver = 0,
+    Multiply   = 13,
+    Screen     = 14,
+    Overlay    = 15,
+    Darken     = 16,
+    Lighten    = 17,
+    ColorDodge = 18,
+    ColorBurn  = 19,
+    HardLight  = 20,
+    SoftLight  = 21,
+    Difference = 22,
+    Exclusion  = 23,
+}
+
+unsafe impl ExternType for QPainterCompositionMode {
+    type Id = type_id!("QPainterCompositionMode");
+    type Kind = cxx::kind::Trivial;
+}
+
+#[repr(i32)]
+#[allow(dead_code, clippy::enum_variant_names)]
+pub(crate) enum QGradientSpread {
+    PadSpread     = 0,
+    ReflectSpread = 1,
+    RepeatSpread  = 2,
+}
+
+unsafe impl ExternType for QGradientSpread {
+    type Id = type_id!("QGradientSpread");
+    type Kind = cxx::kind::Trivial;
+}
+
 #[repr(C)]
 #[doc(hidden)]
 pub struct QPen {
@@ -806,6 +1470,16 @@ mod ffi {
         m32: f64,
     }
 
+    struct WLineMetrics {
+        start: i32,
+        len: i32,
+        x: f64,
+        y: f64,
+        width: f64,
+        height: f64,
+        baseline: f64,
+    }
+
     unsafe extern "C++-unwind" {
         include!("winio-ui-qt/src/widgets/canvas.hpp");
 
@@ -813,6 +1487,11 @@ mod ffi {
         type QtMouseButton = super::QtMouseButton;
 
         unsafe fn new_canvas(parent: *mut QWidget) -> Result<UniquePtr<QWidget>>;
+        unsafe fn canvas_register_resize_event(
+            w: Pin<&mut QWidget>,
+            callback: unsafe fn(*const u8, i32, i32),
+            data: *const u8,
+        ) -> Result<()>;
         unsafe fn canvas_register_move_event(
             w: Pin<&mut QWidget>,
             callback: unsafe fn(*const u8, i32, i32),
@@ -833,6 +1512,26 @@ mod ffi {
             callback: unsafe fn(*const u8, i32, i32),
             data: *const u8,
         ) -> Result<()>;
+        unsafe fn canvas_register_button_press_event(
+            w: Pin<&mut QWidget>,
+            callback: unsafe fn(*const u8, QtMouseButton, i32, i32, i32, i32),
+            data: *const u8,
+        ) -> Result<()>;
+        unsafe fn canvas_register_button_release_event(
+            w: Pin<&mut QWidget>,
+            callback: unsafe fn(*const u8, QtMouseButton, i32, i32, i32, i32),
+            data: *const u8,
+        ) -> Result<()>;
+        unsafe fn canvas_register_enter_event(
+            w: Pin<&mut QWidget>,
+            callback: unsafe fn(*const u8, i32, i32),
+            data: *const u8,
+        ) -> Result<()>;
+        unsafe fn canvas_register_leave_event(
+            w: Pin<&mut QWidget>,
+            callback: unsafe fn(*const u8),
+            data: *const u8,
+        ) -> Result<()>;
 
         type QPainter;
         type QColor = super::QColor;
@@ -840,6 +1539,8 @@ mod ffi {
         type QPointF = super::QPointF;
         type QSizeF = super::QSizeF;
         type QtSizeMode = super::QtSizeMode;
+        type QtPenCapStyle = super::QtPenCapStyle;
+        type QtPenJoinStyle = super::QtPenJoinStyle;
         type QSize = crate::widgets::QSize;
 
         fn alpha(self: &QColor) -> i32;
@@ -873,19 +1574,99 @@ mod ffi {
 
         fn end(self: Pin<&mut QPainter>) -> Result<bool>;
 
-        fn canvas_new_painter(w: Pin<&mut QWidget>) -> Result<UniquePtr<QPainter>>;
+        fn canvas_buffer_valid(w: &QWidget) -> Result<bool>;
+        fn canvas_new_buffer(w: Pin<&mut QWidget>, keep: bool) -> Result<UniquePtr<QImage>>;
+        fn canvas_set_buffer(w: Pin<&mut QWidget>, image: &QImage) -> Result<()>;
+        fn image_new_layer(image: &QImage) -> Result<UniquePtr<QImage>>;
+        fn new_bitmap(image: &QImage, width: f64, height: f64) -> Result<UniquePtr<QImage>>;
+        fn image_unscaled(image: &QImage) -> Result<UniquePtr<QImage>>;
+        fn image_rgba_bytes(image: &QImage) -> Result<Vec<u8>>;
+        fn new_image_painter(image: Pin<&mut QImage>) -> Result<UniquePtr<QPainter>>;
+        fn painter_clear_rect(p: Pin<&mut QPainter>, rect: &QRectF) -> Result<()>;
+        fn painter_draw_layer(
+            p: Pin<&mut QPainter>,
+            image: &QImage,
+            opacity: f64,
+            mode: QPainterCompositionMode,
+        ) -> Result<()>;
         fn painter_set_font(
             p: Pin<&mut QPainter>,
-            family: &str,
+            families: &str,
             size: f64,
+            weight: i32,
+            stretch: i32,
             italic: bool,
-            bold: bool,
+            underline: bool,
+            strikeout: bool,
+            letter_spacing: f64,
         ) -> Result<()>;
         fn painter_measure_text(p: Pin<&mut QPainter>, rect: QRectF, text: &str) -> Result<QSizeF>;
         fn painter_draw_text(p: Pin<&mut QPainter>, rect: QRectF, text: &str) -> Result<()>;
+
+        type QTextLayout;
+        type QtAlignmentFlag = crate::widgets::QtAlignmentFlag;
+
+        fn new_text_layout(
+            text: &str,
+            families: &str,
+            size: f64,
+            weight: i32,
+            stretch: i32,
+            italic: bool,
+            underline: bool,
+            strikeout: bool,
+            letter_spacing: f64,
+        ) -> Result<UniquePtr<QTextLayout>>;
+        fn text_layout_add_font(
+            l: Pin<&mut QTextLayout>,
+            start: i32,
+            len: i32,
+            families: &str,
+            size: f64,
+            weight: i32,
+            stretch: i32,
+            italic: bool,
+            underline: bool,
+            strikeout: bool,
+            letter_spacing: f64,
+        ) -> Result<()>;
+        fn text_layout_add_color(
+            l: Pin<&mut QTextLayout>,
+            start: i32,
+            len: i32,
+            c: &QColor,
+        ) -> Result<()>;
+        fn text_layout_add_underline(
+            l: Pin<&mut QTextLayout>,
+            start: i32,
+            len: i32,
+            underline: bool,
+        ) -> Result<()>;
+        fn text_layout_build(
+            l: Pin<&mut QTextLayout>,
+            max_width: f64,
+            align: QtAlignmentFlag,
+            line_spacing: f64,
+            max_lines: i32,
+            ellipsis: bool,
+        ) -> Result<()>;
+        fn text_layout_size(l: &QTextLayout) -> Result<QSizeF>;
+        fn text_layout_lines(l: &QTextLayout) -> Result<Vec<WLineMetrics>>;
+        fn text_layout_hit_test(l: &QTextLayout, pos: QPointF, inside: &mut bool) -> Result<i32>;
+        fn text_layout_caret_rect(l: &QTextLayout, index: i32) -> Result<QRectF>;
+        fn painter_draw_text_layout(
+            p: Pin<&mut QPainter>,
+            l: &QTextLayout,
+            pos: QPointF,
+        ) -> Result<()>;
+
         fn painter_set_transform(p: Pin<&mut QPainter>, t: &WTransform) -> Result<()>;
         fn painter_get_transform(p: &QPainter) -> Result<WTransform>;
         fn resetTransform(self: Pin<&mut QPainter>) -> Result<()>;
+        fn save(self: Pin<&mut QPainter>) -> Result<()>;
+        fn restore(self: Pin<&mut QPainter>) -> Result<()>;
+        fn painter_clip_rect(p: Pin<&mut QPainter>, rect: &QRectF) -> Result<()>;
+        fn painter_clip_path(p: Pin<&mut QPainter>, path: &QPainterPath) -> Result<()>;
 
         type QBrush = super::QBrush;
         type QPen = super::QPen;
@@ -899,11 +1680,21 @@ mod ffi {
         fn color_accent(c: Pin<&mut QColor>) -> Result<bool>;
         fn new_brush(c: &QColor) -> Result<QBrush>;
         fn new_pen(b: &QBrush, width: f64) -> Result<QPen>;
+        fn pen_set_stroke(
+            p: Pin<&mut QPen>,
+            cap: QtPenCapStyle,
+            join: QtPenJoinStyle,
+            miter_limit: f64,
+            dashes: &[f64],
+            dash_offset: f64,
+        ) -> Result<()>;
 
         fn brush_drop(b: Pin<&mut QBrush>);
         fn pen_drop(p: Pin<&mut QPen>);
 
         type QGradient;
+        type QGradientSpread = super::QGradientSpread;
+        type QPainterCompositionMode = super::QPainterCompositionMode;
 
         fn new_gradient_linear(start: QPointF, end: QPointF) -> Result<UniquePtr<QGradient>>;
         fn new_gradient_radial(
@@ -911,9 +1702,12 @@ mod ffi {
             radius: f64,
             origin: QPointF,
         ) -> Result<UniquePtr<QGradient>>;
+        fn new_gradient_conical(center: QPointF, angle: f64) -> Result<UniquePtr<QGradient>>;
         fn setColorAt(self: Pin<&mut QGradient>, pos: f64, c: &QColor) -> Result<()>;
+        fn setSpread(self: Pin<&mut QGradient>, spread: QGradientSpread) -> Result<()>;
 
         fn new_brush_gradient(g: &QGradient) -> Result<QBrush>;
+        fn new_brush_image(image: &QImage, opacity: f64, reflect: bool) -> Result<QBrush>;
         fn brush_set_transform(
             b: Pin<&mut QBrush>,
             m11: f64,
@@ -967,7 +1761,22 @@ mod ffi {
             x3: f64,
             y3: f64,
         ) -> Result<()>;
+        fn quadTo(self: Pin<&mut QPainterPath>, cx: f64, cy: f64, x: f64, y: f64) -> Result<()>;
+        fn addRect(self: Pin<&mut QPainterPath>, rect: &QRectF) -> Result<()>;
+        fn addEllipse(self: Pin<&mut QPainterPath>, rect: &QRectF) -> Result<()>;
         fn closeSubpath(self: Pin<&mut QPainterPath>) -> Result<()>;
         fn boundingRect(self: &QPainterPath) -> Result<QRectF>;
+        fn length(self: &QPainterPath) -> Result<f64>;
+
+        type QtFillRule = super::QtFillRule;
+
+        fn path_contains(path: &QPainterPath, p: QPointF, rule: QtFillRule) -> Result<bool>;
+        fn path_stroke_contains(path: &QPainterPath, p: QPointF, width: f64) -> Result<bool>;
+        fn path_transform(path: &QPainterPath, t: &WTransform) -> Result<UniquePtr<QPainterPath>>;
+        fn painter_fill_path(
+            p: Pin<&mut QPainter>,
+            path: &QPainterPath,
+            rule: QtFillRule,
+        ) -> Result<()>;
     }
 }
@@ -42,6 +42,8 @@ impl Edit {
 
     pub fn preferred_size(&self) -> Result<Size>;
 
+    pub fn baseline(&self) -> Result<Option<f64>>;
+
     pub fn loc(&self) -> Result<Point>;
 
     pub fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -97,10 +99,10 @@ impl Edit {
         let mut flag = self.widget.as_ref().alignment()?;
         flag &= QtAlignmentFlag::from_bits_retain(0xFFF0);
         match align {
-            HAlign::Left => flag |= QtAlignmentFlag::AlignLeft,
             HAlign::Center => flag |= QtAlignmentFlag::AlignHCenter,
             HAlign::Right => flag |= QtAlignmentFlag::AlignRight,
             HAlign::Stretch => flag |= QtAlignmentFlag::AlignJustify,
+            _ => flag |= QtAlignmentFlag::AlignLeft,
         }
         self.widget.pin_mut().setAlignment(flag)?;
         Ok(())
@@ -209,10 +211,10 @@ impl TextBox {
         let mut flag = self.widget.as_ref().alignment()?;
         flag &= QtAlignmentFlag::from_bits_retain(0xFFF0);
         match align {
-            HAlign::Left => flag |= QtAlignmentFlag::AlignLeft,
             HAlign::Center => flag |= QtAlignmentFlag::AlignHCenter,
             HAlign::Right => flag |= QtAlignmentFlag::AlignRight,
             HAlign::Stretch => flag |= QtAlignmentFlag::AlignJustify,
+            _ => flag |= QtAlignmentFlag::AlignLeft,
         }
         self.widget.pin_mut().setAlignment(flag)?;
         Ok(())
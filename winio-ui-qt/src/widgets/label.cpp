@@ -14,12 +14,12 @@ void label_connect_link_activated(QLabel &w, callback_fn_t<void()> callback,
                      });
 }
 
-void label_set_font(QLabel &w, rust::Str family, double size, bool bold,
-                    bool italic) {
+void label_set_font(QLabel &w, rust::Str families, double size, int weight,
+                    int stretch, bool italic, bool underline, bool strikeout,
+                    double letter_spacing) {
     auto font = w.font();
-    font.setFamily(QString::fromUtf8(family.data(), family.size()));
+    font_apply(font, families, weight, stretch, italic, underline, strikeout,
+               letter_spacing);
     font.setPointSizeF(size);
-    font.setBold(bold);
-    font.setItalic(italic);
     w.setFont(font);
 }
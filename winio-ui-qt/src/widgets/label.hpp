@@ -2,6 +2,7 @@
 
 #include "../common.hpp"
 #include "edit.hpp"
+#include "widget.hpp"
 #include <QLabel>
 
 STATIC_CAST_ASSERT(QLabel, QWidget);
@@ -11,5 +12,6 @@ std::unique_ptr<QLabel> new_label(QWidget *parent);
 void label_connect_link_activated(QLabel &w, callback_fn_t<void()> callback,
                                   std::uint8_t const *data);
 
-void label_set_font(QLabel &w, rust::Str family, double size, bool bold,
-                    bool italic);
+void label_set_font(QLabel &w, rust::Str families, double size, int weight,
+                    int stretch, bool italic, bool underline, bool strikeout,
+                    double letter_spacing);
@@ -1,11 +1,11 @@
 use inherit_methods_macro::inherit_methods;
 use winio_callback::Callback;
 use winio_handle::AsContainer;
-use winio_primitive::{Font, HAlign, Point, Size};
+use winio_primitive::{Font, FontStretch, FontWeight, HAlign, Point, Size};
 
 use crate::{
     GlobalRuntime, Result,
-    widgets::{QtAlignmentFlag, Widget, impl_static_cast},
+    widgets::{QtAlignmentFlag, Widget, font_families, impl_static_cast},
 };
 
 #[derive(Debug)]
@@ -32,6 +32,8 @@ impl Label {
 
     pub fn preferred_size(&self) -> Result<Size>;
 
+    pub fn baseline(&self) -> Result<Option<f64>>;
+
     pub fn loc(&self) -> Result<Point>;
 
     pub fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -71,10 +73,10 @@ impl Label {
         let mut flag = self.widget.as_ref().alignment()?;
         flag &= QtAlignmentFlag::from_bits_retain(0xFFF0);
         match align {
-            HAlign::Left => flag |= QtAlignmentFlag::AlignLeft,
             HAlign::Center => flag |= QtAlignmentFlag::AlignHCenter,
             HAlign::Right => flag |= QtAlignmentFlag::AlignRight,
             HAlign::Stretch => flag |= QtAlignmentFlag::AlignJustify,
+            _ => flag |= QtAlignmentFlag::AlignLeft,
         }
         self.widget.pin_mut().setAlignment(flag)?;
         Ok(())
@@ -82,21 +84,40 @@ impl Label {
 
     pub fn font(&self) -> Result<Font> {
         let font = self.widget.as_ref().font()?;
-        Ok(Font {
-            family: font.family()?.try_into()?,
-            size: font.pointSizeF()?,
-            bold: font.bold()?,
-            italic: font.italic()?,
-        })
+        let stretch = match font.stretch()? {
+            // `QFont::AnyStretch`
+            0 => 100,
+            s => s,
+        };
+        let family: String = font.family()?.try_into()?;
+        Ok(Font::builder()
+            .family(family)
+            .fallback(
+                ffi::font_fallback(font)?
+                    .split(',')
+                    .filter(|s| !s.is_empty()),
+            )
+            .size(font.pointSizeF()?)
+            .italic(font.italic()?)
+            .weight(FontWeight(ffi::font_weight(font)? as _))
+            .stretch(FontStretch::from_percent(stretch as _))
+            .underline(font.underline()?)
+            .strikethrough(font.strikeOut()?)
+            .letter_spacing(font.letterSpacing()?)
+            .build())
     }
 
     pub fn set_font(&mut self, font: Font) -> Result<()> {
         ffi::label_set_font(
             self.widget.pin_mut(),
-            font.family.as_str(),
+            &font_families(&font),
             font.size,
-            font.bold,
+            font.weight.0 as _,
+            font.stretch.percent() as _,
             font.italic,
+            font.underline,
+            font.strikethrough,
+            font.letter_spacing,
         )?;
         Ok(())
     }
@@ -145,6 +166,8 @@ impl LinkLabel {
 
     pub fn preferred_size(&self) -> Result<Size>;
 
+    pub fn baseline(&self) -> Result<Option<f64>>;
+
     pub fn loc(&self) -> Result<Point>;
 
     pub fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -237,15 +260,25 @@ mod ffi {
 
         fn label_set_font(
             w: Pin<&mut QLabel>,
-            family: &str,
+            families: &str,
             size: f64,
-            bold: bool,
+            weight: i32,
+            stretch: i32,
             italic: bool,
+            underline: bool,
+            strikeout: bool,
+            letter_spacing: f64,
         ) -> Result<()>;
 
+        fn font_weight(f: &QFont) -> Result<i32>;
+        fn font_fallback(f: &QFont) -> Result<String>;
+
         fn family(self: &QFont) -> Result<QString>;
         fn pointSizeF(self: &QFont) -> Result<f64>;
-        fn bold(self: &QFont) -> Result<bool>;
         fn italic(self: &QFont) -> Result<bool>;
+        fn stretch(self: &QFont) -> Result<i32>;
+        fn underline(self: &QFont) -> Result<bool>;
+        fn strikeOut(self: &QFont) -> Result<bool>;
+        fn letterSpacing(self: &QFont) -> Result<f64>;
     }
 }
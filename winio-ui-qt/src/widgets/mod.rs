@@ -45,6 +45,9 @@ pub use webview::*;
 mod tab_view;
 pub use tab_view::*;
 
+mod splitter;
+pub use splitter::*;
+
 #[cfg(feature = "wgpu")]
 mod wgpu;
 #[cfg(feature = "wgpu")]
@@ -114,7 +117,7 @@ pub(crate) fn static_cast_mut<T>(p: Pin<&mut impl StaticCastTo<T>>) -> Pin<&mut
 
 use std::pin::Pin;
 
-use winio_primitive::ColorTheme;
+use winio_primitive::{ColorTheme, Font};
 
 pub fn color_theme() -> crate::Result<ColorTheme> {
     if is_dark()? {
@@ -123,3 +126,12 @@ pub fn color_theme() -> crate::Result<ColorTheme> {
         Ok(ColorTheme::Light)
     }
 }
+
+/// Join the family and the fallback families with commas.
+pub(crate) fn font_families(font: &Font) -> String {
+    std::iter::once(&font.family)
+        .chain(&font.fallback)
+        .map(String::as_str)
+        .collect::<Vec<_>>()
+        .join(",")
+}
@@ -0,0 +1,29 @@
+#include "splitter.hpp"
+
+std::unique_ptr<QSplitter> new_splitter(QWidget *parent) {
+    auto widget = std::make_unique<QSplitter>(parent);
+    widget->setChildrenCollapsible(false);
+    return widget;
+}
+
+void splitter_connect_moved(QSplitter &w, callback_fn_t<void()> callback,
+                            std::uint8_t const *data) {
+    QObject::connect(&w, &QSplitter::splitterMoved,
+                     [callback, data](int, int) { callback(data); });
+}
+
+rust::Vec<int> splitter_sizes(QSplitter const &w) {
+    rust::Vec<int> res{};
+    for (int s : w.sizes()) {
+        res.push_back(s);
+    }
+    return res;
+}
+
+void splitter_set_sizes(QSplitter &w, rust::Slice<int const> sizes) {
+    QList<int> list{};
+    for (int s : sizes) {
+        list.append(s);
+    }
+    w.setSizes(list);
+}
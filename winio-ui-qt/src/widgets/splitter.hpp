@@ -0,0 +1,18 @@
+#pragma once
+
+#include "../common.hpp"
+#include <QSplitter>
+#include <memory>
+
+using QtOrientation = Qt::Orientation;
+
+STATIC_CAST_ASSERT(QSplitter, QWidget);
+
+std::unique_ptr<QSplitter> new_splitter(QWidget *parent);
+
+void splitter_connect_moved(QSplitter &w, callback_fn_t<void()> callback,
+                            std::uint8_t const *data);
+
+rust::Vec<int> splitter_sizes(QSplitter const &w);
+
+void splitter_set_sizes(QSplitter &w, rust::Slice<int const> sizes);
@@ -0,0 +1,204 @@
+use inherit_methods_macro::inherit_methods;
+use winio_callback::Callback;
+use winio_handle::{AsContainer, AsWidget, BorrowedContainer};
+use winio_primitive::{Orient, Point, Size};
+
+use crate::{
+    GlobalRuntime, QtOrientation, Result, View,
+    widgets::{Widget, impl_static_cast},
+};
+
+#[derive(Debug)]
+pub struct Splitter {
+    on_change: Box<Callback>,
+    widget: Widget<ffi::QSplitter>,
+    panes: Vec<View>,
+}
+
+#[inherit_methods(from = "self.widget")]
+impl Splitter {
+    pub fn new(parent: impl AsContainer) -> Result<Self> {
+        let on_change = Box::new(Callback::new());
+        let mut widget = unsafe { ffi::new_splitter(parent.as_container().as_qt()) }?;
+        unsafe {
+            ffi::splitter_connect_moved(
+                widget.pin_mut(),
+                Self::on_change,
+                on_change.as_ref() as *const _ as _,
+            )?;
+        }
+        let mut widget = Widget::new(widget)?;
+        widget.set_visible(true)?;
+        Ok(Self {
+            on_change,
+            widget,
+            panes: vec![],
+        })
+    }
+
+    pub fn is_visible(&self) -> Result<bool>;
+
+    pub fn set_visible(&mut self, v: bool) -> Result<()>;
+
+    pub fn is_enabled(&self) -> Result<bool>;
+
+    pub fn set_enabled(&mut self, v: bool) -> Result<()>;
+
+    pub fn loc(&self) -> Result<Point>;
+
+    pub fn set_loc(&mut self, p: Point) -> Result<()>;
+
+    pub fn size(&self) -> Result<Size>;
+
+    pub fn set_size(&mut self, s: Size) -> Result<()>;
+
+    fn on_change(c: *const u8) {
+        let c = c as *const Callback<()>;
+        if let Some(c) = unsafe { c.as_ref() } {
+            c.signal::<GlobalRuntime>(());
+        }
+    }
+
+    pub async fn wait_change(&self) {
+        self.on_change.wait().await
+    }
+
+    pub fn orient(&self) -> Result<Orient> {
+        match self.widget.as_ref().orientation()? {
+            QtOrientation::Horizontal => Ok(Orient::Horizontal),
+            QtOrientation::Vertical => Ok(Orient::Vertical),
+        }
+    }
+
+    pub fn set_orient(&mut self, v: Orient) -> Result<()> {
+        let v = match v {
+            Orient::Horizontal => QtOrientation::Horizontal,
+            Orient::Vertical => QtOrientation::Vertical,
+        };
+        self.widget.pin_mut().setOrientation(v)?;
+        Ok(())
+    }
+
+    pub fn len(&self) -> Result<usize> {
+        Ok(self.panes.len())
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    pub fn insert(&mut self, i: usize) -> Result<()> {
+        let mut view = View::new_standalone()?;
+        unsafe {
+            self.widget
+                .pin_mut()
+                .insertWidget(i as _, view.widget.pin_mut().get_unchecked_mut())?;
+        }
+        self.panes.insert(i, view);
+        Ok(())
+    }
+
+    pub fn remove(&mut self, i: usize) -> Result<()> {
+        // The pane is deleted with its children.
+        self.panes.remove(i);
+        Ok(())
+    }
+
+    pub fn pane(&self, i: usize) -> BorrowedContainer<'_> {
+        self.panes[i].as_container()
+    }
+
+    pub fn pane_size(&self, i: usize) -> Result<Size> {
+        self.panes[i].size()
+    }
+
+    pub fn pane_min_size(&self, i: usize) -> Result<Size> {
+        self.panes[i].widget.min_size()
+    }
+
+    pub fn set_pane_min_size(&mut self, i: usize, s: Size) -> Result<()> {
+        self.panes[i].widget.set_min_size(s)
+    }
+
+    fn handle_width(&self) -> Result<f64> {
+        Ok(self.widget.as_ref().handleWidth()? as _)
+    }
+
+    fn length(&self) -> Result<f64> {
+        let size = self.size()?;
+        Ok(match self.orient()? {
+            Orient::Horizontal => size.width,
+            Orient::Vertical => size.height,
+        })
+    }
+
+    pub fn pos(&self) -> Result<Vec<f64>> {
+        let handle = self.handle_width()?;
+        let sizes = ffi::splitter_sizes(self.widget.as_ref())?;
+        let mut offset = 0.0;
+        Ok(sizes
+            .iter()
+            .take(sizes.len().saturating_sub(1))
+            .map(|s| {
+                offset += *s as f64;
+                let pos = offset;
+                offset += handle;
+                pos
+            })
+            .collect())
+    }
+
+    pub fn set_pos(&mut self, pos: &[f64]) -> Result<()> {
+        let handle = self.handle_width()?;
+        let length = self.length()?;
+        let mut start = 0.0;
+        let sizes = pos
+            .iter()
+            .copied()
+            .chain(std::iter::once(length))
+            .take(self.panes.len())
+            .map(|end| {
+                let size = (end - start).max(0.0);
+                start = end + handle;
+                size as i32
+            })
+            .collect::<Vec<_>>();
+        ffi::splitter_set_sizes(self.widget.pin_mut(), &sizes)?;
+        Ok(())
+    }
+}
+
+winio_handle::impl_as_widget!(Splitter, widget);
+
+impl_static_cast!(ffi::QSplitter, ffi::QWidget);
+
+#[cxx::bridge]
+mod ffi {
+    unsafe extern "C++-unwind" {
+        include!("winio-ui-qt/src/widgets/splitter.hpp");
+
+        type QWidget = crate::widgets::QWidget;
+        type QSplitter;
+        type QtOrientation = crate::widgets::QtOrientation;
+
+        unsafe fn new_splitter(parent: *mut QWidget) -> Result<UniquePtr<QSplitter>>;
+
+        unsafe fn splitter_connect_moved(
+            w: Pin<&mut QSplitter>,
+            callback: unsafe fn(*const u8),
+            data: *const u8,
+        ) -> Result<()>;
+
+        fn splitter_sizes(w: &QSplitter) -> Result<Vec<i32>>;
+        fn splitter_set_sizes(w: Pin<&mut QSplitter>, sizes: &[i32]) -> Result<()>;
+
+        unsafe fn insertWidget(
+            self: Pin<&mut QSplitter>,
+            index: i32,
+            widget: *mut QWidget,
+        ) -> Result<()>;
+        fn orientation(self: &QSplitter) -> Result<QtOrientation>;
+        fn setOrientation(self: Pin<&mut QSplitter>, v: QtOrientation) -> Result<()>;
+        fn handleWidth(self: &QSplitter) -> Result<i32>;
+    }
+}
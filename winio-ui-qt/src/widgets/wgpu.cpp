@@ -1,4 +1,5 @@
 #include "wgpu.hpp"
+#include <QCursor>
 #include <QGuiApplication>
 #include <QMouseEvent>
 #include <QWheelEvent>
@@ -8,7 +9,10 @@
 WinioWgpuCanvas::WinioWgpuCanvas(QWidget *parent)
     : QWidget(parent), m_move_callback(std::nullopt),
       m_press_callback(std::nullopt), m_release_callback(std::nullopt),
-      m_wheel_callback(std::nullopt) {
+      m_wheel_callback(std::nullopt),
+      m_button_press_callback(std::nullopt),
+      m_button_release_callback(std::nullopt), m_enter_callback(std::nullopt),
+      m_leave_callback(std::nullopt), m_clicks(1) {
     setMouseTracking(true);
     QCoreApplication::setAttribute(Qt::AA_DontCreateNativeWidgetSiblings);
     setAttribute(Qt::WA_DontCreateNativeAncestors);
@@ -29,10 +33,18 @@ void WinioWgpuCanvas::mouseMoveEvent(QMouseEvent *event) {
 }
 
 void WinioWgpuCanvas::mousePressEvent(QMouseEvent *event) {
+    // The default mouseDoubleClickEvent calls this method.
+    m_clicks = event->type() == QEvent::MouseButtonDblClick ? 2 : 1;
     if (m_press_callback) {
         auto &[callback, data] = *m_press_callback;
         callback(data, event->button());
     }
+    if (m_button_press_callback) {
+        auto &[callback, data] = *m_button_press_callback;
+        auto pos = event->pos();
+        callback(data, event->button(), pos.x(), pos.y(),
+                 static_cast<int>(event->modifiers()), m_clicks);
+    }
 }
 
 void WinioWgpuCanvas::mouseReleaseEvent(QMouseEvent *event) {
@@ -40,6 +52,12 @@ void WinioWgpuCanvas::mouseReleaseEvent(QMouseEvent *event) {
         auto &[callback, data] = *m_release_callback;
         callback(data, event->button());
     }
+    if (m_button_release_callback) {
+        auto &[callback, data] = *m_button_release_callback;
+        auto pos = event->pos();
+        callback(data, event->button(), pos.x(), pos.y(),
+                 static_cast<int>(event->modifiers()), m_clicks);
+    }
 }
 
 void WinioWgpuCanvas::wheelEvent(QWheelEvent *event) {
@@ -51,6 +69,26 @@ void WinioWgpuCanvas::wheelEvent(QWheelEvent *event) {
     }
 }
 
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+void WinioWgpuCanvas::enterEvent(QEnterEvent *event) {
+    auto pos = event->position().toPoint();
+#else
+void WinioWgpuCanvas::enterEvent(QEvent *) {
+    auto pos = mapFromGlobal(QCursor::pos());
+#endif
+    if (m_enter_callback) {
+        auto &[callback, data] = *m_enter_callback;
+        callback(data, pos.x(), pos.y());
+    }
+}
+
+void WinioWgpuCanvas::leaveEvent(QEvent *) {
+    if (m_leave_callback) {
+        auto &[callback, data] = *m_leave_callback;
+        callback(data);
+    }
+}
+
 std::unique_ptr<QWidget> new_wgpu_canvas(QWidget *parent) {
     return std::make_unique<WinioWgpuCanvas>(parent);
 }
@@ -83,6 +121,36 @@ void wgpu_canvas_register_wheel_event(QWidget &w,
         std::make_tuple(std::move(callback), data);
 }
 
+void wgpu_canvas_register_button_press_event(
+    QWidget &w,
+    callback_fn_t<void(QtMouseButton, int, int, int, int)> callback,
+    std::uint8_t const *data) {
+    static_cast<WinioWgpuCanvas &>(w).m_button_press_callback =
+        std::make_tuple(std::move(callback), data);
+}
+
+void wgpu_canvas_register_button_release_event(
+    QWidget &w,
+    callback_fn_t<void(QtMouseButton, int, int, int, int)> callback,
+    std::uint8_t const *data) {
+    static_cast<WinioWgpuCanvas &>(w).m_button_release_callback =
+        std::make_tuple(std::move(callback), data);
+}
+
+void wgpu_canvas_register_enter_event(QWidget &w,
+                                      callback_fn_t<void(int, int)> callback,
+                                      std::uint8_t const *data) {
+    static_cast<WinioWgpuCanvas &>(w).m_enter_callback =
+        std::make_tuple(std::move(callback), data);
+}
+
+void wgpu_canvas_register_leave_event(QWidget &w,
+                                      callback_fn_t<void()> callback,
+                                      std::uint8_t const *data) {
+    static_cast<WinioWgpuCanvas &>(w).m_leave_callback =
+        std::make_tuple(std::move(callback), data);
+}
+
 WaylandDescriptor wgpu_canvas_wayland_descriptor(QWidget const &w) {
     auto interface = QGuiApplication::platformNativeInterface();
     auto display = static_cast<wl_display *>(
@@ -17,6 +17,12 @@ struct WinioWgpuCanvas : public QWidget {
     callback_t<void(QtMouseButton)> m_press_callback;
     callback_t<void(QtMouseButton)> m_release_callback;
     callback_t<void(int, int)> m_wheel_callback;
+    callback_t<void(QtMouseButton, int, int, int, int)> m_button_press_callback;
+    callback_t<void(QtMouseButton, int, int, int, int)>
+        m_button_release_callback;
+    callback_t<void(int, int)> m_enter_callback;
+    callback_t<void()> m_leave_callback;
+    int m_clicks;
 
     WinioWgpuCanvas(QWidget *parent);
     ~WinioWgpuCanvas() override;
@@ -28,6 +34,12 @@ protected:
     void mousePressEvent(QMouseEvent *event) override;
     void mouseReleaseEvent(QMouseEvent *event) override;
     void wheelEvent(QWheelEvent *event) override;
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+    void enterEvent(QEnterEvent *event) override;
+#else
+    void enterEvent(QEvent *event) override;
+#endif
+    void leaveEvent(QEvent *event) override;
 };
 
 std::unique_ptr<QWidget> new_wgpu_canvas(QWidget *parent);
@@ -43,6 +55,20 @@ void wgpu_canvas_register_release_event(
 void wgpu_canvas_register_wheel_event(QWidget &w,
                                       callback_fn_t<void(int, int)> callback,
                                       std::uint8_t const *data);
+void wgpu_canvas_register_button_press_event(
+    QWidget &w,
+    callback_fn_t<void(QtMouseButton, int, int, int, int)> callback,
+    std::uint8_t const *data);
+void wgpu_canvas_register_button_release_event(
+    QWidget &w,
+    callback_fn_t<void(QtMouseButton, int, int, int, int)> callback,
+    std::uint8_t const *data);
+void wgpu_canvas_register_enter_event(QWidget &w,
+                                      callback_fn_t<void(int, int)> callback,
+                                      std::uint8_t const *data);
+void wgpu_canvas_register_leave_event(QWidget &w,
+                                      callback_fn_t<void()> callback,
+                                      std::uint8_t const *data);
 
 WaylandDescriptor wgpu_canvas_wayland_descriptor(QWidget const &w);
 XcbDescriptor wgpu_canvas_xcb_descriptor(QWidget const &w);
@@ -11,9 +11,9 @@ use wgpu::{
 };
 use winio_callback::Callback;
 use winio_handle::AsContainer;
-use winio_primitive::{MouseButton, Point, Size, Vector};
+use winio_primitive::{Cursor, MouseButton, MouseButtonEvent, Point, Size, Vector};
 
-use crate::{GlobalRuntime, QtMouseButton, Result, Widget};
+use crate::{GlobalRuntime, QtMouseButton, Result, Widget, widgets::mouse_button_event};
 
 #[derive(Debug)]
 pub struct WgpuCanvas {
@@ -21,6 +21,10 @@ pub struct WgpuCanvas {
     on_press: Box<Callback<MouseButton>>,
     on_release: Box<Callback<MouseButton>>,
     on_wheel: Box<Callback<Vector>>,
+    on_button_press: Box<Callback<MouseButtonEvent>>,
+    on_button_release: Box<Callback<MouseButtonEvent>>,
+    on_enter: Box<Callback<Point>>,
+    on_leave: Box<Callback>,
     widget: Widget<ffi::QWidget>,
 }
 
@@ -33,6 +37,10 @@ impl WgpuCanvas {
         let on_press = Box::new(Callback::new());
         let on_release = Box::new(Callback::new());
         let on_wheel = Box::new(Callback::new());
+        let on_button_press = Box::new(Callback::new());
+        let on_button_release = Box::new(Callback::new());
+        let on_enter = Box::new(Callback::new());
+        let on_leave = Box::new(Callback::new());
         unsafe {
             ffi::wgpu_canvas_register_move_event(
                 widget.pin_mut(),
@@ -54,12 +62,36 @@ impl WgpuCanvas {
                 Self::on_wheel,
                 on_wheel.as_ref() as *const _ as _,
             )?;
+            ffi::wgpu_canvas_register_button_press_event(
+                widget.pin_mut(),
+                Self::on_button,
+                on_button_press.as_ref() as *const _ as _,
+            )?;
+            ffi::wgpu_canvas_register_button_release_event(
+                widget.pin_mut(),
+                Self::on_button,
+                on_button_release.as_ref() as *const _ as _,
+            )?;
+            ffi::wgpu_canvas_register_enter_event(
+                widget.pin_mut(),
+                Self::on_enter,
+                on_enter.as_ref() as *const _ as _,
+            )?;
+            ffi::wgpu_canvas_register_leave_event(
+                widget.pin_mut(),
+                Self::on_leave,
+                on_leave.as_ref() as *const _ as _,
+            )?;
         }
         Ok(Self {
             on_move,
             on_press,
             on_release,
             on_wheel,
+            on_button_press,
+            on_button_release,
+            on_enter,
+            on_leave,
             widget: Widget::new(widget)?,
         })
     }
@@ -84,6 +116,8 @@ impl WgpuCanvas {
 
     pub fn set_tooltip(&mut self, s: impl AsRef<str>) -> Result<()>;
 
+    pub fn set_cursor(&mut self, cursor: Cursor) -> Result<()>;
+
     fn on_move(c: *const u8, x: i32, y: i32) {
         let c = c as *const Callback<Point>;
         if let Some(c) = unsafe { c.as_ref() } {
@@ -112,6 +146,27 @@ impl WgpuCanvas {
         }
     }
 
+    fn on_button(c: *const u8, m: QtMouseButton, x: i32, y: i32, modifiers: i32, clicks: i32) {
+        let c = c as *const Callback<MouseButtonEvent>;
+        if let Some(c) = unsafe { c.as_ref() } {
+            c.signal::<GlobalRuntime>(mouse_button_event(m, x, y, modifiers, clicks));
+        }
+    }
+
+    fn on_enter(c: *const u8, x: i32, y: i32) {
+        let c = c as *const Callback<Point>;
+        if let Some(c) = unsafe { c.as_ref() } {
+            c.signal::<GlobalRuntime>(Point::new(x as _, y as _));
+        }
+    }
+
+    fn on_leave(c: *const u8) {
+        let c = c as *const Callback;
+        if let Some(c) = unsafe { c.as_ref() } {
+            c.signal::<GlobalRuntime>(());
+        }
+    }
+
     pub async fn wait_mouse_down(&self) -> MouseButton {
         self.on_press.wait().await
     }
@@ -128,6 +183,22 @@ impl WgpuCanvas {
         self.on_wheel.wait().await
     }
 
+    pub async fn wait_mouse_button_down(&self) -> MouseButtonEvent {
+        self.on_button_press.wait().await
+    }
+
+    pub async fn wait_mouse_button_up(&self) -> MouseButtonEvent {
+        self.on_button_release.wait().await
+    }
+
+    pub async fn wait_mouse_enter(&self) -> Point {
+        self.on_enter.wait().await
+    }
+
+    pub async fn wait_mouse_leave(&self) {
+        self.on_leave.wait().await
+    }
+
     fn wayland(&self) -> Option<WindowHandleWrapper> {
         let desc = ffi::wgpu_canvas_wayland_descriptor(self.widget.as_ref()).ok()?;
         let display = NonNull::new(desc.display)?;
@@ -226,6 +297,26 @@ mod ffi {
             callback: unsafe fn(*const u8, i32, i32),
             data: *const u8,
         ) -> Result<()>;
+        unsafe fn wgpu_canvas_register_button_press_event(
+            w: Pin<&mut QWidget>,
+            callback: unsafe fn(*const u8, QtMouseButton, i32, i32, i32, i32),
+            data: *const u8,
+        ) -> Result<()>;
+        unsafe fn wgpu_canvas_register_button_release_event(
+            w: Pin<&mut QWidget>,
+            callback: unsafe fn(*const u8, QtMouseButton, i32, i32, i32, i32),
+            data: *const u8,
+        ) -> Result<()>;
+        unsafe fn wgpu_canvas_register_enter_event(
+            w: Pin<&mut QWidget>,
+            callback: unsafe fn(*const u8, i32, i32),
+            data: *const u8,
+        ) -> Result<()>;
+        unsafe fn wgpu_canvas_register_leave_event(
+            w: Pin<&mut QWidget>,
+            callback: unsafe fn(*const u8),
+            data: *const u8,
+        ) -> Result<()>;
 
         fn wgpu_canvas_wayland_descriptor(w: &QWidget) -> Result<WaylandDescriptor>;
         fn wgpu_canvas_xcb_descriptor(w: &QWidget) -> Result<XcbDescriptor>;
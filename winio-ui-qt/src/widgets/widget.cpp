@@ -1,5 +1,14 @@
 #include "widget.hpp"
 #include <QApplication>
+#include <QCheckBox>
+#include <QFontMetrics>
+#include <QLabel>
+#include <QLineEdit>
+#include <QPushButton>
+#include <QRadioButton>
+#include <QStyle>
+#include <QStyleOption>
+#include <algorithm>
 
 bool is_dark() {
     auto back = QApplication::palette().color(QPalette::Window);
@@ -13,3 +22,125 @@ std::unique_ptr<QWidget> new_widget(QWidget *parent) {
 }
 
 QPointer<QWidget> widget_weak(QWidget *w) { return QPointer(w); }
+
+// The baseline of a line of text vertically centered in the rectangle.
+static int centered_baseline(const QRect &r, const QFontMetrics &metrics) {
+    return r.y() + (r.height() - metrics.height() + 1) / 2 + metrics.ascent();
+}
+
+// The baseline of a button, with the contents rect of the style.
+template <typename Button>
+static int button_baseline(const Button &button, const QRect &rect,
+                           QStyle::SubElement contents) {
+    QStyleOptionButton opt;
+    opt.initFrom(&button);
+    opt.rect = rect;
+    opt.text = button.text();
+    opt.icon = button.icon();
+    opt.iconSize = button.iconSize();
+    auto r = button.style()->subElementRect(contents, &opt, &button);
+    return centered_baseline(r, button.fontMetrics());
+}
+
+int widget_baseline(const QWidget &w) {
+    // The baseline is calculated for the widget in its size hint, which is
+    // the size used by the layout containers to align the baselines.
+    auto metrics = w.fontMetrics();
+    auto rect = QRect{QPoint{}, w.sizeHint()};
+    auto style = w.style();
+    if (auto edit = qobject_cast<const QLineEdit *>(&w)) {
+        QStyleOptionFrame opt;
+        opt.initFrom(edit);
+        opt.rect = rect;
+        opt.lineWidth =
+            edit->hasFrame()
+                ? style->pixelMetric(QStyle::PM_DefaultFrameWidth, &opt, edit)
+                : 0;
+        opt.midLineWidth = 0;
+        opt.state |= QStyle::State_Sunken;
+        if (edit->isReadOnly()) {
+            opt.state |= QStyle::State_ReadOnly;
+        }
+        auto r = style->subElementRect(QStyle::SE_LineEditContents, &opt, edit)
+                     .marginsRemoved(edit->textMargins());
+        // Same as QLineEdit::paintEvent, with the vertical margin of 1.
+        switch (static_cast<int>(edit->alignment() & Qt::AlignVertical_Mask)) {
+        case Qt::AlignTop:
+            return r.y() + 1 + metrics.ascent();
+        case Qt::AlignBottom:
+            return r.y() + r.height() - metrics.height() - 1 +
+                   metrics.ascent();
+        default:
+            return centered_baseline(r, metrics);
+        }
+    }
+    if (auto button = qobject_cast<const QCheckBox *>(&w)) {
+        return button_baseline(*button, rect, QStyle::SE_CheckBoxContents);
+    }
+    if (auto button = qobject_cast<const QRadioButton *>(&w)) {
+        return button_baseline(*button, rect, QStyle::SE_RadioButtonContents);
+    }
+    if (auto button = qobject_cast<const QPushButton *>(&w)) {
+        return button_baseline(*button, rect, QStyle::SE_PushButtonContents);
+    }
+    if (auto label = qobject_cast<const QLabel *>(&w)) {
+        // Same as QFrame::contentsRect, with the extra margin of the label.
+        auto fw = label->frameWidth();
+        auto r = rect.marginsRemoved(label->contentsMargins())
+                     .adjusted(fw, fw, -fw, -fw);
+        auto margin = label->margin();
+        r.adjust(margin, margin, -margin, -margin);
+        auto flags = static_cast<int>(label->alignment());
+        if (label->wordWrap()) {
+            flags |= Qt::TextWordWrap;
+        }
+        return metrics.boundingRect(r, flags, label->text()).y() +
+               metrics.ascent();
+    }
+    return centered_baseline(rect, metrics);
+}
+
+void widget_set_cursor(QWidget &w, QtCursorShape shape) {
+    w.setCursor(QCursor{shape});
+}
+
+#if QT_VERSION < QT_VERSION_CHECK(6, 0, 0)
+// Qt 5 weights of CSS 100 to 900.
+static const int QT5_WEIGHTS[] = {0, 12, 25, 50, 57, 63, 75, 81, 87};
+#endif
+
+void font_apply(QFont &font, rust::Str families, int weight, int stretch,
+                bool italic, bool underline, bool strikeout,
+                double letter_spacing) {
+    auto list = QString::fromUtf8(families.data(), families.size()).split(',');
+    font.setFamily(list.first());
+    font.setFamilies(list);
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+    font.setWeight(static_cast<QFont::Weight>(std::clamp(weight, 1, 1000)));
+#else
+    font.setWeight(QT5_WEIGHTS[std::clamp((weight + 50) / 100, 1, 9) - 1]);
+#endif
+    font.setStretch(stretch);
+    font.setItalic(italic);
+    font.setUnderline(underline);
+    font.setStrikeOut(strikeout);
+    font.setLetterSpacing(QFont::AbsoluteSpacing, letter_spacing);
+}
+
+int font_weight(const QFont &font) {
+#if QT_VERSION >= QT_VERSION_CHECK(6, 0, 0)
+    return font.weight();
+#else
+    auto weight = font.weight();
+    auto it = std::min_element(
+        std::begin(QT5_WEIGHTS), std::end(QT5_WEIGHTS),
+        [weight](int a, int b) { return std::abs(a - weight) < std::abs(b - weight); });
+    return (int)(it - std::begin(QT5_WEIGHTS) + 1) * 100;
+#endif
+}
+
+rust::String font_fallback(const QFont &font) {
+    auto families = font.families();
+    families.removeAll(font.family());
+    return rust::String{families.join(',').toStdString()};
+}
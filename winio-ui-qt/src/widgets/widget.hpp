@@ -1,6 +1,8 @@
 #pragma once
 
 #include "../common.hpp"
+#include <QCursor>
+#include <QFont>
 #include <QPointer>
 #include <QString>
 #include <QWidget>
@@ -20,3 +22,17 @@ template <> struct IsRelocatable<QWidgetPointer> : std::true_type {};
 static_assert(sizeof(QWidgetPointer) == 2 * sizeof(std::size_t));
 
 QWidgetPointer widget_weak(QWidget *w);
+
+int widget_baseline(const QWidget &w);
+
+using QtCursorShape = Qt::CursorShape;
+
+void widget_set_cursor(QWidget &w, QtCursorShape shape);
+
+void font_apply(QFont &font, rust::Str families, int weight, int stretch,
+                bool italic, bool underline, bool strikeout,
+                double letter_spacing);
+
+int font_weight(const QFont &font);
+
+rust::String font_fallback(const QFont &font);
@@ -9,7 +9,7 @@ use cxx::{ExternType, UniquePtr, memory::UniquePtrTarget, type_id};
 pub use ffi::{QWidget, is_dark};
 use inherit_methods_macro::inherit_methods;
 use winio_handle::{AsContainer, AsWidget, BorrowedContainer, BorrowedWidget};
-use winio_primitive::{Point, Size};
+use winio_primitive::{Cursor, Point, Size};
 
 use crate::{Result, widgets::StaticCastTo};
 
@@ -83,11 +83,22 @@ where
         Ok(Size::new(s.width as _, s.height as _))
     }
 
+    pub fn baseline(&self) -> Result<Option<f64>> {
+        let b = ffi::widget_baseline(self.as_ref_qwidget())?;
+        Ok((b >= 0).then_some(b as _))
+    }
+
     pub fn min_size(&self) -> Result<Size> {
         let s = self.as_ref_qwidget().minimumSize()?;
         Ok(Size::new(s.width as _, s.height as _))
     }
 
+    pub fn set_min_size(&mut self, s: Size) -> Result<()> {
+        self.pin_mut_qwidget()
+            .setMinimumSize(s.width as _, s.height as _)?;
+        Ok(())
+    }
+
     pub fn loc(&self) -> Result<Point> {
         let rect = self.as_ref_qwidget().rect()?;
         Ok(Point::new(rect.x1 as _, rect.y1 as _))
@@ -119,6 +130,28 @@ where
         self.pin_mut_qwidget().setToolTip(&s.as_ref().try_into()?)?;
         Ok(())
     }
+
+    pub fn set_cursor(&mut self, cursor: Cursor) -> Result<()> {
+        set_widget_cursor(self.pin_mut_qwidget(), cursor)
+    }
+}
+
+pub(crate) fn set_widget_cursor(w: Pin<&mut ffi::QWidget>, cursor: Cursor) -> Result<()> {
+    let shape = match cursor {
+        Cursor::IBeam => QtCursorShape::IBeamCursor,
+        Cursor::Hand => QtCursorShape::PointingHandCursor,
+        Cursor::Crosshair => QtCursorShape::CrossCursor,
+        Cursor::Wait => QtCursorShape::WaitCursor,
+        Cursor::Move => QtCursorShape::SizeAllCursor,
+        Cursor::NotAllowed => QtCursorShape::ForbiddenCursor,
+        Cursor::ResizeHorizontal => QtCursorShape::SizeHorCursor,
+        Cursor::ResizeVertical => QtCursorShape::SizeVerCursor,
+        Cursor::ResizeNwse => QtCursorShape::SizeFDiagCursor,
+        Cursor::ResizeNesw => QtCursorShape::SizeBDiagCursor,
+        _ => QtCursorShape::ArrowCursor,
+    };
+    ffi::widget_set_cursor(w, shape)?;
+    Ok(())
 }
 
 impl<T> Drop for Widget<T>
@@ -194,6 +227,8 @@ impl View {
     pub fn size(&self) -> Result<Size>;
 
     pub fn set_size(&mut self, s: Size) -> Result<()>;
+
+    pub fn set_cursor(&mut self, cursor: Cursor) -> Result<()>;
 }
 
 winio_handle::impl_as_widget!(View, widget);
@@ -223,6 +258,27 @@ unsafe impl ExternType for QRect {
     type Kind = cxx::kind::Trivial;
 }
 
+#[repr(i32)]
+#[allow(dead_code, clippy::enum_variant_names)]
+pub(crate) enum QtCursorShape {
+    ArrowCursor        = 0,
+    CrossCursor        = 2,
+    WaitCursor         = 3,
+    IBeamCursor        = 4,
+    SizeVerCursor      = 5,
+    SizeHorCursor      = 6,
+    SizeBDiagCursor    = 7,
+    SizeFDiagCursor    = 8,
+    SizeAllCursor      = 9,
+    PointingHandCursor = 13,
+    ForbiddenCursor    = 14,
+}
+
+unsafe impl ExternType for QtCursorShape {
+    type Id = type_id!("QtCursorShape");
+    type Kind = cxx::kind::Trivial;
+}
+
 #[repr(C)]
 pub struct QWidgetPointer {
     _data: MaybeUninit<[usize; 2]>,
@@ -245,9 +301,12 @@ mod ffi {
         type QRect = super::QRect;
         type QString = crate::common::QString;
         type QWidgetPointer = super::QWidgetPointer;
+        type QtCursorShape = super::QtCursorShape;
 
         unsafe fn new_widget(parent: *mut QWidget) -> Result<UniquePtr<QWidget>>;
         unsafe fn widget_weak(w: *mut QWidget) -> Result<QWidgetPointer>;
+        fn widget_baseline(w: &QWidget) -> Result<i32>;
+        fn widget_set_cursor(w: Pin<&mut QWidget>, shape: QtCursorShape) -> Result<()>;
 
         fn isNull(self: &QWidgetPointer) -> bool;
 
@@ -259,6 +318,7 @@ mod ffi {
         fn geometry(self: &QWidget) -> Result<&QRect>;
         fn sizeHint(self: &QWidget) -> Result<QSize>;
         fn minimumSize(self: &QWidget) -> Result<QSize>;
+        fn setMinimumSize(self: Pin<&mut QWidget>, w: i32, h: i32) -> Result<()>;
         fn update(self: Pin<&mut QWidget>) -> Result<()>;
         fn isVisible(self: &QWidget) -> Result<bool>;
         fn setVisible(self: Pin<&mut QWidget>, v: bool) -> Result<()>;
@@ -3,9 +3,12 @@ use std::{fmt::Debug, pin::Pin};
 use cxx::UniquePtr;
 use winio_callback::Callback;
 use winio_handle::{AsContainer, AsWindow, BorrowedContainer, BorrowedWindow};
-use winio_primitive::{Point, Size};
+use winio_primitive::{Cursor, Point, Size};
 
-use crate::{GlobalRuntime, Result, StaticCastTo, widgets::impl_static_cast};
+use crate::{
+    GlobalRuntime, Result, StaticCastTo,
+    widgets::{impl_static_cast, set_widget_cursor},
+};
 
 pub struct Window {
     on_resize: Box<Callback<Size>>,
@@ -111,6 +114,10 @@ impl Window {
         Ok(())
     }
 
+    pub fn set_cursor(&mut self, cursor: Cursor) -> Result<()> {
+        set_widget_cursor(self.pin_mut_qwidget(), cursor)
+    }
+
     fn on_resize(c: *const u8, width: i32, height: i32) {
         let c = c as *const Callback<Size>;
         if let Some(c) = unsafe { c.as_ref() } {
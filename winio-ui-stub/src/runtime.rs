@@ -14,6 +14,14 @@ impl App {
         not_impl()
     }
 
+    #[cfg(not(windows))]
+    pub fn set_layout_direction(
+        &mut self,
+        _direction: winio_primitive::LayoutDirection,
+    ) -> Result<()> {
+        not_impl()
+    }
+
     pub fn block_on<F: Future>(&self, _future: F) -> F::Output {
         not_impl()
     }
@@ -1,9 +1,10 @@
-use image::DynamicImage;
+use image::{DynamicImage, RgbaImage};
 use inherit_methods_macro::inherit_methods;
 use winio_handle::AsContainer;
 use winio_primitive::{
-    BrushPen, Font, LinearGradientBrush, MouseButton, Point, RadialGradientBrush, Rect,
-    RelativePoint, Size, SolidColorBrush, Transform, Vector,
+    BlendMode, BrushPen, ConicGradientBrush, Cursor, FillRule, Font, ImageBrush, LineMetrics,
+    LinearGradientBrush, MouseButton, Point, RadialGradientBrush, Rect, RelativePoint, Size,
+    SolidColorBrush, TextFormat, TextHitTest, TextSpan, Transform, Vector,
 };
 
 use crate::{Result, Widget, not_impl};
@@ -39,11 +40,19 @@ impl Canvas {
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        not_impl()
+    }
+
     pub fn tooltip(&self) -> Result<String>;
 
     pub fn set_tooltip(&mut self, s: impl AsRef<str>) -> Result<()>;
 
-    pub fn context(&mut self) -> Result<DrawingContext<'_>> {
+    pub fn invalidate(&mut self, _rect: Rect) -> Result<()> {
+        not_impl()
+    }
+
+    pub fn context(&mut self, _dirty: Option<Rect>) -> Result<DrawingContext<'_>> {
         not_impl()
     }
 
@@ -62,6 +71,10 @@ impl Canvas {
     pub async fn wait_mouse_wheel(&self) -> Vector {
         not_impl()
     }
+
+    pub async fn wait_paint(&self) -> Rect {
+        not_impl()
+    }
 }
 
 winio_handle::impl_as_widget!(Canvas, handle);
@@ -76,6 +89,10 @@ impl Brush for LinearGradientBrush {}
 
 impl Brush for RadialGradientBrush {}
 
+impl Brush for ConicGradientBrush {}
+
+impl<I: AsRef<DrawingImage>> Brush for ImageBrush<I> {}
+
 pub trait Pen {}
 
 impl<P: Pen> Pen for &P {}
@@ -101,7 +118,12 @@ impl DrawingContext<'_> {
         not_impl()
     }
 
-    pub fn fill_path(&mut self, _brush: impl Brush, _path: &DrawingPath) -> Result<()> {
+    pub fn fill_path(
+        &mut self,
+        _brush: impl Brush,
+        _path: &DrawingPath,
+        _rule: FillRule,
+    ) -> Result<()> {
         not_impl()
     }
 
@@ -179,6 +201,49 @@ impl DrawingContext<'_> {
         not_impl()
     }
 
+    pub fn push_clip_rect(&mut self, _rect: Rect) -> Result<()> {
+        not_impl()
+    }
+
+    pub fn push_clip_path(&mut self, _path: &DrawingPath) -> Result<()> {
+        not_impl()
+    }
+
+    pub fn pop_clip(&mut self) -> Result<()> {
+        not_impl()
+    }
+
+    pub fn push_layer(&mut self, _opacity: f64, _blend: BlendMode) -> Result<()> {
+        not_impl()
+    }
+
+    pub fn pop_layer(&mut self) -> Result<()> {
+        not_impl()
+    }
+
+    pub fn create_text_layout(
+        &self,
+        _text: &str,
+        _font: Font,
+        _spans: &[TextSpan],
+        _format: &TextFormat,
+    ) -> Result<TextLayout> {
+        not_impl()
+    }
+
+    pub fn draw_text_layout(
+        &mut self,
+        _brush: impl Brush,
+        _layout: &TextLayout,
+        _pos: Point,
+    ) -> Result<()> {
+        not_impl()
+    }
+
+    pub fn create_bitmap(&self, _size: Size) -> Result<DrawingBitmap> {
+        not_impl()
+    }
+
     pub fn create_path_builder(&self, _start: Point) -> Result<DrawingPathBuilder> {
         not_impl()
     }
@@ -186,6 +251,12 @@ impl DrawingContext<'_> {
 
 pub struct DrawingImage;
 
+impl AsRef<DrawingImage> for DrawingImage {
+    fn as_ref(&self) -> &DrawingImage {
+        self
+    }
+}
+
 impl DrawingImage {
     pub fn size(&self) -> Result<Size> {
         not_impl()
@@ -194,6 +265,68 @@ impl DrawingImage {
 
 pub struct DrawingPath;
 
+impl DrawingPath {
+    pub fn bounds(&self) -> Result<Rect> {
+        not_impl()
+    }
+
+    pub fn contains(&self, _p: Point, _rule: FillRule) -> Result<bool> {
+        not_impl()
+    }
+
+    pub fn stroke_contains(&self, _p: Point, _width: f64) -> Result<bool> {
+        not_impl()
+    }
+
+    pub fn length(&self) -> Result<f64> {
+        not_impl()
+    }
+
+    pub fn transform(&self, _transform: &Transform) -> Result<DrawingPath> {
+        not_impl()
+    }
+}
+
+pub struct TextLayout;
+
+impl TextLayout {
+    pub fn size(&self) -> Result<Size> {
+        not_impl()
+    }
+
+    pub fn line_metrics(&self) -> Result<Vec<LineMetrics>> {
+        not_impl()
+    }
+
+    pub fn hit_test(&self, _p: Point) -> Result<TextHitTest> {
+        not_impl()
+    }
+
+    pub fn caret_rect(&self, _index: usize) -> Result<Rect> {
+        not_impl()
+    }
+}
+
+pub struct DrawingBitmap;
+
+impl DrawingBitmap {
+    pub fn size(&self) -> Result<Size> {
+        not_impl()
+    }
+
+    pub fn context(&mut self) -> Result<DrawingContext<'_>> {
+        not_impl()
+    }
+
+    pub fn to_image(&self) -> Result<DrawingImage> {
+        not_impl()
+    }
+
+    pub fn to_rgba(&self) -> Result<RgbaImage> {
+        not_impl()
+    }
+}
+
 pub struct DrawingPathBuilder;
 
 impl DrawingPathBuilder {
@@ -201,6 +334,14 @@ impl DrawingPathBuilder {
         not_impl()
     }
 
+    pub fn move_to(&mut self, _p: Point) -> Result<()> {
+        not_impl()
+    }
+
+    pub fn close_figure(&mut self) -> Result<()> {
+        not_impl()
+    }
+
     pub fn add_arc(
         &mut self,
         _center: Point,
@@ -216,6 +357,18 @@ impl DrawingPathBuilder {
         not_impl()
     }
 
+    pub fn add_quad(&mut self, _p1: Point, _p2: Point) -> Result<()> {
+        not_impl()
+    }
+
+    pub fn add_rect(&mut self, _rect: Rect) -> Result<()> {
+        not_impl()
+    }
+
+    pub fn add_ellipse(&mut self, _rect: Rect) -> Result<()> {
+        not_impl()
+    }
+
     pub fn build(self, _close: bool) -> Result<DrawingPath> {
         not_impl()
     }
@@ -1,6 +1,6 @@
 use inherit_methods_macro::inherit_methods;
 use winio_handle::AsContainer;
-use winio_primitive::{MouseButton, Point, Size, Vector};
+use winio_primitive::{Cursor, MouseButton, Point, Size, Vector};
 
 use crate::{Result, Widget, not_impl};
 
@@ -35,6 +35,10 @@ impl WgpuCanvas {
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        not_impl()
+    }
+
     pub fn tooltip(&self) -> Result<String>;
 
     pub fn set_tooltip(&mut self, s: impl AsRef<str>) -> Result<()>;
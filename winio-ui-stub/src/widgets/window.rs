@@ -1,6 +1,6 @@
 use inherit_methods_macro::inherit_methods;
 use winio_handle::AsContainer;
-use winio_primitive::{Point, Size};
+use winio_primitive::{Cursor, Point, Size};
 #[cfg(windows)]
 pub use winio_ui_windows_common::Backdrop;
 
@@ -29,6 +29,10 @@ impl Window {
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        not_impl()
+    }
+
     pub fn client_size(&self) -> Result<Size> {
         not_impl()
     }
@@ -94,6 +98,10 @@ impl View {
     pub fn size(&self) -> Result<Size>;
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
+
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        not_impl()
+    }
 }
 
 winio_handle::impl_as_container!(View, handle);
@@ -4,7 +4,7 @@ use std::{
 };
 
 use compio_log::*;
-use image::DynamicImage;
+use image::{DynamicImage, RgbaImage};
 use inherit_methods_macro::inherit_methods;
 use objc2::{
     DeclaredClass, MainThreadOnly, define_class, msg_send,
@@ -21,7 +21,8 @@ use objc2_ui_kit::{UIEvent, UIGraphicsGetCurrentContext, UITouch, UIView};
 use winio_callback::Callback;
 use winio_handle::AsContainer;
 use winio_primitive::{
-    ColorTheme, Font, MouseButton, Point, Rect, RelativePoint, Size, Transform, Vector,
+    BlendMode, ColorTheme, Cursor, FillRule, Font, LineMetrics, MouseButton, Point, Rect,
+    RelativePoint, Size, TextFormat, TextHitTest, TextSpan, Transform, Vector,
 };
 
 use crate::{
@@ -96,6 +97,7 @@ winio_handle::impl_as_widget!(CanvasImpl, handle);
 
 #[derive(Debug)]
 pub struct Canvas {
+    on_paint: Callback<Rect>,
     handle: CanvasImpl,
 }
 
@@ -103,7 +105,10 @@ pub struct Canvas {
 impl Canvas {
     pub fn new(parent: impl AsContainer) -> Result<Self> {
         let handle = CanvasImpl::new(parent, true)?;
-        Ok(Self { handle })
+        Ok(Self {
+            on_paint: Callback::new(),
+            handle,
+        })
     }
 
     pub fn is_visible(&self) -> Result<bool>;
@@ -122,11 +127,20 @@ impl Canvas {
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
     pub fn tooltip(&self) -> Result<String>;
 
     pub fn set_tooltip(&mut self, s: impl AsRef<str>) -> Result<()>;
 
-    pub fn context(&mut self) -> Result<DrawingContext<'_>> {
+    pub fn invalidate(&mut self, rect: Rect) -> Result<()> {
+        self.on_paint.signal::<()>(rect);
+        Ok(())
+    }
+
+    pub fn context(&mut self, _dirty: Option<Rect>) -> Result<DrawingContext<'_>> {
         Ok(DrawingContext {
             size: self.size()?,
             actions: self.handle.view.ivars().take_buffer(),
@@ -151,6 +165,10 @@ impl Canvas {
     pub async fn wait_mouse_wheel(&self) -> Vector {
         self.handle.wait_mouse_wheel().await
     }
+
+    pub async fn wait_paint(&self) -> Rect {
+        self.on_paint.wait().await
+    }
 }
 
 winio_handle::impl_as_widget!(Canvas, handle);
@@ -314,8 +332,15 @@ impl DrawingContext<'_> {
         self.draw(pen, path.0.clone())
     }
 
-    pub fn fill_path(&mut self, brush: impl Brush, path: &DrawingPath) -> Result<()> {
-        self.fill(brush, path.0.clone())
+    pub fn fill_path(
+        &mut self,
+        brush: impl Brush,
+        path: &DrawingPath,
+        rule: FillRule,
+    ) -> Result<()> {
+        self.actions
+            .push(brush.create_action(path.0.clone())?.with_fill_rule(rule));
+        Ok(())
     }
 
     pub fn draw_arc(&mut self, pen: impl Pen, rect: Rect, start: f64, end: f64) -> Result<()> {
@@ -416,6 +441,49 @@ impl DrawingContext<'_> {
         Ok(())
     }
 
+    pub fn push_clip_rect(&mut self, _rect: Rect) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn push_clip_path(&mut self, _path: &DrawingPath) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn pop_clip(&mut self) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn push_layer(&mut self, _opacity: f64, _blend: BlendMode) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn pop_layer(&mut self) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn create_text_layout(
+        &self,
+        _text: &str,
+        _font: Font,
+        _spans: &[TextSpan],
+        _format: &TextFormat,
+    ) -> Result<TextLayout> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn draw_text_layout(
+        &mut self,
+        _brush: impl Brush,
+        layout: &TextLayout,
+        _pos: Point,
+    ) -> Result<()> {
+        match *layout {}
+    }
+
+    pub fn create_bitmap(&self, _size: Size) -> Result<DrawingBitmap> {
+        Err(Error::NotSupported)
+    }
+
     pub fn create_path_builder(&self, start: Point) -> Result<DrawingPathBuilder> {
         Ok(DrawingPathBuilder::new(self.size, start))
     }
@@ -423,6 +491,70 @@ impl DrawingContext<'_> {
 
 pub struct DrawingPath(CFRetained<CGPath>);
 
+impl DrawingPath {
+    pub fn bounds(&self) -> Result<Rect> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn contains(&self, _p: Point, _rule: FillRule) -> Result<bool> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn stroke_contains(&self, _p: Point, _width: f64) -> Result<bool> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn length(&self) -> Result<f64> {
+        Err(Error::NotSupported)
+    }
+
+    pub fn transform(&self, _transform: &Transform) -> Result<DrawingPath> {
+        Err(Error::NotSupported)
+    }
+}
+
+/// Text layouts are not supported yet.
+pub enum TextLayout {}
+
+impl TextLayout {
+    pub fn size(&self) -> Result<Size> {
+        match *self {}
+    }
+
+    pub fn line_metrics(&self) -> Result<Vec<LineMetrics>> {
+        match *self {}
+    }
+
+    pub fn hit_test(&self, _p: Point) -> Result<TextHitTest> {
+        match *self {}
+    }
+
+    pub fn caret_rect(&self, _index: usize) -> Result<Rect> {
+        match *self {}
+    }
+}
+
+/// Bitmaps are not supported yet.
+pub enum DrawingBitmap {}
+
+impl DrawingBitmap {
+    pub fn size(&self) -> Result<Size> {
+        match *self {}
+    }
+
+    pub fn context(&mut self) -> Result<DrawingContext<'_>> {
+        match *self {}
+    }
+
+    pub fn to_image(&self) -> Result<DrawingImage> {
+        match *self {}
+    }
+
+    pub fn to_rgba(&self) -> Result<RgbaImage> {
+        match *self {}
+    }
+}
+
 pub struct DrawingPathBuilder {
     size: Size,
     matrix: CGAffineTransform,
@@ -448,6 +580,21 @@ impl DrawingPathBuilder {
         Ok(())
     }
 
+    pub fn move_to(&mut self, p: Point) -> Result<()> {
+        let p = to_cgpoint(p);
+        unsafe {
+            CGMutablePath::move_to_point(Some(&self.path), &self.matrix, p.x, p.y);
+        }
+        Ok(())
+    }
+
+    pub fn close_figure(&mut self) -> Result<()> {
+        unsafe {
+            CGMutablePath::close_subpath(Some(&self.path));
+        }
+        Ok(())
+    }
+
     pub fn add_arc(
         &mut self,
         center: Point,
@@ -501,6 +648,38 @@ impl DrawingPathBuilder {
         Ok(())
     }
 
+    pub fn add_quad(&mut self, p1: Point, p2: Point) -> Result<()> {
+        let p1 = to_cgpoint(p1);
+        let p2 = to_cgpoint(p2);
+        unsafe {
+            CGMutablePath::add_quad_curve_to_point(
+                Some(&self.path),
+                &self.matrix,
+                p1.x,
+                p1.y,
+                p2.x,
+                p2.y,
+            );
+        }
+        Ok(())
+    }
+
+    pub fn add_rect(&mut self, rect: Rect) -> Result<()> {
+        let rect = to_cgrect(rect);
+        unsafe {
+            CGMutablePath::add_rect(Some(&self.path), &self.matrix, rect);
+        }
+        Ok(())
+    }
+
+    pub fn add_ellipse(&mut self, rect: Rect) -> Result<()> {
+        let rect = to_cgrect(rect);
+        unsafe {
+            CGMutablePath::add_ellipse_in_rect(Some(&self.path), &self.matrix, rect);
+        }
+        Ok(())
+    }
+
     pub fn build(self, close: bool) -> Result<DrawingPath> {
         unsafe {
             if close {
@@ -101,10 +101,10 @@ impl Edit {
 
     pub fn set_halign(&mut self, align: HAlign) -> Result<()> {
         let raw = match align {
-            HAlign::Left => NSTextAlignment::Left,
             HAlign::Center => NSTextAlignment::Center,
             HAlign::Right => NSTextAlignment::Right,
             HAlign::Stretch => NSTextAlignment::Justified,
+            _ => NSTextAlignment::Left,
         };
         catch(|| self.view.setTextAlignment(raw))
     }
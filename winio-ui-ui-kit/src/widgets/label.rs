@@ -79,10 +79,10 @@ impl Label {
 
     pub fn set_halign(&mut self, align: HAlign) -> Result<()> {
         let align = match align {
-            HAlign::Left => NSTextAlignment::Left,
             HAlign::Center => NSTextAlignment::Center,
             HAlign::Right => NSTextAlignment::Right,
             HAlign::Stretch => NSTextAlignment::Justified,
+            _ => NSTextAlignment::Left,
         };
         catch(|| self.view.setTextAlignment(align))
     }
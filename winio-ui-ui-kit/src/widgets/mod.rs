@@ -88,12 +88,12 @@ pub(crate) fn first_ui_window_scene() -> crate::Result<Option<Retained<UIWindowS
 pub(crate) fn uifont_to_font(font: &UIFont) -> Font {
     unsafe {
         let traits = font.fontDescriptor().symbolicTraits();
-        Font {
-            family: crate::from_nsstring(&font.familyName()),
-            size: font.pointSize(),
-            bold: traits.contains(UIFontDescriptorSymbolicTraits::TraitBold),
-            italic: traits.contains(UIFontDescriptorSymbolicTraits::TraitItalic),
-        }
+        Font::builder()
+            .family(crate::from_nsstring(&font.familyName()))
+            .size(font.pointSize())
+            .italic(traits.contains(UIFontDescriptorSymbolicTraits::TraitItalic))
+            .bold(traits.contains(UIFontDescriptorSymbolicTraits::TraitBold))
+            .build()
     }
 }
 
@@ -103,7 +103,7 @@ pub(crate) fn font_to_uifont(font: &Font) -> Retained<UIFont> {
         let base = UIFont::fontWithName_size(&NSString::from_str(&font.family), font.size)
             .unwrap_or_else(|| UIFont::systemFontOfSize(font.size));
         let mut traits = UIFontDescriptorSymbolicTraits::empty();
-        if font.bold {
+        if font.weight.is_bold() {
             traits |= UIFontDescriptorSymbolicTraits::TraitBold;
         }
         if font.italic {
@@ -10,9 +10,9 @@ use wgpu::{
     },
 };
 use winio_handle::{AsContainer, AsWidget};
-use winio_primitive::{MouseButton, Point, Size, Vector};
+use winio_primitive::{Cursor, MouseButton, Point, Size, Vector};
 
-use crate::{CanvasImpl, Result};
+use crate::{CanvasImpl, Error, Result};
 
 #[derive(Debug)]
 pub struct WgpuCanvas {
@@ -42,6 +42,10 @@ impl WgpuCanvas {
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
     pub fn tooltip(&self) -> Result<String>;
 
     pub fn set_tooltip(&mut self, s: impl AsRef<str>) -> Result<()>;
@@ -12,7 +12,7 @@ use winio_callback::Callback;
 use winio_handle::{
     AsContainer, AsWidget, AsWindow, BorrowedContainer, BorrowedWidget, BorrowedWindow,
 };
-use winio_primitive::{Point, Size};
+use winio_primitive::{Cursor, Point, Size};
 use winio_ui_apple_common::from_nsstring;
 
 use crate::{
@@ -128,6 +128,10 @@ impl Window {
         Ok(())
     }
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(Error::NotSupported)
+    }
+
     pub fn client_size(&self) -> Result<Size> {
         catch(|| from_cgsize(self.content_view.frame().size))
     }
@@ -313,6 +317,10 @@ impl View {
     pub fn size(&self) -> Result<Size>;
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
+
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(Error::NotSupported)
+    }
 }
 
 winio_handle::impl_as_widget!(View, handle);
@@ -23,7 +23,7 @@ use windows_sys::Win32::{
         },
     },
 };
-use winio_primitive::{Font, Size};
+use winio_primitive::{Font, FontWeight, Size};
 use winio_ui_windows_common::syscall;
 
 use super::dpi::{DpiAware, get_dpi_for_window};
@@ -160,11 +160,12 @@ pub(crate) fn font_to_hfont(hwnd: HWND, font: &Font, underline: bool) -> Result<
     let dpi = get_dpi_for_window(hwnd);
     create_font(dpi, |f| {
         f.lfHeight = -(font.size.to_device(dpi) as i32);
-        f.lfWeight = if font.bold { 700 } else { 400 };
+        f.lfWeight = font.weight.0 as _;
         f.lfItalic = font.italic as u8;
-        if underline {
+        if underline || font.underline {
             f.lfUnderline = 1;
         }
+        f.lfStrikeOut = font.strikethrough as u8;
         let mut chars = font.family.encode_utf16();
         for slot in f.lfFaceName.iter_mut() {
             *slot = chars.next().unwrap_or(0);
@@ -183,12 +184,18 @@ pub(crate) fn hfont_to_font(hwnd: HWND, hfont: HFONT) -> Result<Font> {
     let lf = unsafe { lf.assume_init() };
     let dpi = get_dpi_for_window(hwnd);
     let family = unsafe { U16CStr::from_ptr_str(lf.lfFaceName.as_ptr()) }.to_string_lossy();
-    Ok(Font {
-        family,
-        size: (lf.lfHeight.abs() as f64).to_logical(dpi),
-        bold: lf.lfWeight >= 600,
-        italic: lf.lfItalic != 0,
-    })
+    Ok(Font::builder()
+        .family(family)
+        .size((lf.lfHeight.abs() as f64).to_logical(dpi))
+        .italic(lf.lfItalic != 0)
+        // `FW_DONTCARE` is 0.
+        .weight(if lf.lfWeight > 0 {
+            FontWeight(lf.lfWeight as _)
+        } else {
+            FontWeight::NORMAL
+        })
+        .strikethrough(lf.lfStrikeOut != 0)
+        .build())
 }
 
 /// Store the font of a window, and return the created [`HFONT`].
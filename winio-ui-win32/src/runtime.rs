@@ -32,7 +32,7 @@ use windows_sys::{
         },
         System::Threading::{GetCurrentThread, INFINITE},
         UI::{
-            Controls::{MARGINS, NMHDR},
+            Controls::{DRAWITEMSTRUCT, MARGINS, NMHDR},
             Shell::GetWindowSubclass,
             WindowsAndMessaging::{
                 DefWindowProcW, DispatchMessageW, EnumChildWindows, GA_ROOT, GCLP_HBRBACKGROUND,
@@ -40,7 +40,7 @@ use windows_sys::{
                 MsgWaitForMultipleObjectsEx, PM_REMOVE, PeekMessageW, PostQuitMessage, QS_ALLINPUT,
                 SWP_NOACTIVATE, SWP_NOZORDER, SendMessageW, SetWindowPos, TranslateMessage,
                 WM_COMMAND, WM_CTLCOLORBTN, WM_CTLCOLOREDIT, WM_CTLCOLORLISTBOX, WM_CTLCOLORSTATIC,
-                WM_DPICHANGED, WM_NOTIFY, WM_SETFONT, WM_SETTINGCHANGE,
+                WM_DPICHANGED, WM_DRAWITEM, WM_NOTIFY, WM_SETFONT, WM_SETTINGCHANGE,
             },
         },
     },
@@ -76,6 +76,8 @@ pub(crate) enum WindowMessage {
     },
     Command(WindowMessageCommand),
     Notify(WindowMessageNotify),
+    /// The handle of the owner-drawn control.
+    DrawItem(HWND),
 }
 
 impl WindowMessage {
@@ -106,6 +108,13 @@ impl WindowMessage {
             _ => unreachable!(),
         }
     }
+
+    pub fn draw_item(self) -> HWND {
+        match self {
+            Self::DrawItem(h) => h,
+            _ => unreachable!(),
+        }
+    }
 }
 
 #[derive(Clone, Copy)]
@@ -164,6 +173,9 @@ impl Registry {
                     code: header.code,
                 }
             }),
+            WM_DRAWITEM => {
+                WindowMessage::DrawItem(unsafe { (*(lparam as *const DRAWITEMSTRUCT)).hwndItem })
+            }
             _ => WindowMessage::General { wparam, lparam },
         };
         let mut registry = self.registry.borrow_mut();
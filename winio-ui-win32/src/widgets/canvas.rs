@@ -1,8 +1,8 @@
-use std::ptr::null_mut;
+use std::ptr::{null, null_mut};
 
 use compio_log::error;
 use futures_util::FutureExt;
-use image::DynamicImage;
+use image::{DynamicImage, RgbaImage};
 use inherit_methods_macro::inherit_methods;
 use windows::Win32::{
     Foundation::D2DERR_RECREATE_TARGET,
@@ -18,23 +18,28 @@ use windows::Win32::{
 };
 use windows_sys::Win32::{
     Foundation::{HWND, LPARAM, POINT, SetLastError},
-    Graphics::Gdi::MapWindowPoints,
+    Graphics::Gdi::{InvalidateRect, MapWindowPoints},
     System::SystemServices::SS_OWNERDRAW,
     UI::{
         Controls::WC_STATICW,
         WindowsAndMessaging::{
-            GA_ROOT, GetAncestor, GetParent, WM_LBUTTONDOWN, WM_LBUTTONUP, WM_MBUTTONDOWN,
-            WM_MBUTTONUP, WM_MOUSEHWHEEL, WM_MOUSEMOVE, WM_MOUSEWHEEL, WM_RBUTTONDOWN,
-            WM_RBUTTONUP, WS_CHILD, WS_VISIBLE,
+            GA_ROOT, GetAncestor, GetParent, WM_DRAWITEM, WM_LBUTTONDOWN, WM_LBUTTONUP,
+            WM_MBUTTONDOWN, WM_MBUTTONUP, WM_MOUSEHWHEEL, WM_MOUSEMOVE, WM_MOUSEWHEEL,
+            WM_RBUTTONDOWN, WM_RBUTTONUP, WS_CHILD, WS_VISIBLE,
         },
     },
 };
 use winio_handle::{AsContainer, AsWidget};
 use winio_primitive::{
-    Font, MouseButton, Orient, Point, Rect, RelativePoint, Size, Transform, Vector,
+    BlendMode, Cursor, FillRule, Font, MouseButton, Orient, Point, Rect, RelativePoint, Size,
+    TextFormat, TextSpan, Transform, Vector,
+};
+use winio_ui_windows_common::{
+    Backdrop, d2d1_factory, is_dark_mode_allowed_for_app, not_supported, syscall,
+};
+pub use winio_ui_windows_common::{
+    Brush, DrawingImage, DrawingPath, DrawingPathBuilder, Pen, TextLayout,
 };
-use winio_ui_windows_common::{Backdrop, d2d1_factory, is_dark_mode_allowed_for_app, syscall};
-pub use winio_ui_windows_common::{Brush, DrawingImage, DrawingPath, DrawingPathBuilder, Pen};
 
 use crate::{Result, get_backdrop, platform::font::dwrite_factory, widgets::Widget};
 
@@ -210,11 +215,24 @@ impl Canvas {
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(not_supported())
+    }
+
     pub fn tooltip(&self) -> Result<String>;
 
     pub fn set_tooltip(&mut self, s: impl AsRef<str>) -> Result<()>;
 
-    pub fn context(&mut self) -> Result<DrawingContext<'_>> {
+    pub fn invalidate(&mut self, _rect: Rect) -> Result<()> {
+        // The whole render target is redrawn, so the rectangle is ignored.
+        syscall!(
+            BOOL,
+            InvalidateRect(self.handle.as_widget().as_win32(), null(), 0)
+        )?;
+        Ok(())
+    }
+
+    pub fn context(&mut self, _dirty: Option<Rect>) -> Result<DrawingContext<'_>> {
         unsafe {
             let size = self.handle.handle.size_l2d(self.handle.size()?);
             loop {
@@ -273,6 +291,16 @@ impl Canvas {
     pub async fn wait_mouse_wheel(&self) -> Vector {
         self.handle.wait_mouse_wheel().await
     }
+
+    pub async fn wait_paint(&self) -> Rect {
+        let handle = self.handle.as_widget().as_win32();
+        loop {
+            let msg = self.handle.handle.wait_parent(WM_DRAWITEM).await;
+            if msg.draw_item() == handle {
+                break Rect::new(Point::zero(), self.size().unwrap_or_default());
+            }
+        }
+    }
 }
 
 winio_handle::impl_as_widget!(Canvas, handle);
@@ -307,6 +335,7 @@ impl<'a> DrawingContext<'a> {
 
     fn end_draw(&mut self) -> Result<()> {
         if !self.ended {
+            self.ctx.pop_all_clips()?;
             unsafe {
                 match self.ctx.render_target().EndDraw(None, None) {
                     Ok(()) => {}
@@ -327,9 +356,24 @@ impl<'a> DrawingContext<'a> {
 
     pub fn transform(&self) -> Result<Transform>;
 
+    pub fn push_clip_rect(&mut self, rect: Rect) -> Result<()>;
+
+    pub fn push_clip_path(&mut self, path: &DrawingPath) -> Result<()>;
+
+    pub fn pop_clip(&mut self) -> Result<()>;
+
+    pub fn push_layer(&mut self, opacity: f64, blend: BlendMode) -> Result<()>;
+
+    pub fn pop_layer(&mut self) -> Result<()>;
+
     pub fn draw_path(&mut self, pen: impl Pen, path: &DrawingPath) -> Result<()>;
 
-    pub fn fill_path(&mut self, brush: impl Brush, path: &DrawingPath) -> Result<()>;
+    pub fn fill_path(
+        &mut self,
+        brush: impl Brush,
+        path: &DrawingPath,
+        rule: FillRule,
+    ) -> Result<()>;
 
     pub fn draw_arc(&mut self, pen: impl Pen, rect: Rect, start: f64, end: f64) -> Result<()>;
 
@@ -371,5 +415,45 @@ impl<'a> DrawingContext<'a> {
         clip: Option<Rect>,
     ) -> Result<()>;
 
+    pub fn create_text_layout(
+        &self,
+        text: &str,
+        font: Font,
+        spans: &[TextSpan],
+        format: &TextFormat,
+    ) -> Result<TextLayout>;
+
+    pub fn draw_text_layout(
+        &mut self,
+        brush: impl Brush,
+        layout: &TextLayout,
+        pos: Point,
+    ) -> Result<()>;
+
     pub fn create_path_builder(&self, start: Point) -> Result<DrawingPathBuilder>;
+
+    pub fn create_bitmap(&self, _size: Size) -> Result<DrawingBitmap> {
+        Err(not_supported())
+    }
+}
+
+/// Bitmaps are not supported yet.
+pub enum DrawingBitmap {}
+
+impl DrawingBitmap {
+    pub fn size(&self) -> Result<Size> {
+        match *self {}
+    }
+
+    pub fn context(&mut self) -> Result<DrawingContext<'_>> {
+        match *self {}
+    }
+
+    pub fn to_image(&self) -> Result<DrawingImage> {
+        match *self {}
+    }
+
+    pub fn to_rgba(&self) -> Result<RgbaImage> {
+        match *self {}
+    }
 }
@@ -9,7 +9,8 @@ use wgpu::{
     },
 };
 use winio_handle::{AsContainer, AsWidget};
-use winio_primitive::{MouseButton, Point, Size, Vector};
+use winio_primitive::{Cursor, MouseButton, Point, Size, Vector};
+use winio_ui_windows_common::not_supported;
 
 use crate::{CanvasImpl, Result};
 
@@ -41,6 +42,10 @@ impl WgpuCanvas {
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(not_supported())
+    }
+
     pub fn tooltip(&self) -> Result<String>;
 
     pub fn set_tooltip(&mut self, s: impl AsRef<str>) -> Result<()>;
@@ -28,9 +28,9 @@ use windows_sys::Win32::{
 use winio_handle::{
     AsContainer, AsWidget, AsWindow, BorrowedContainer, BorrowedWidget, BorrowedWindow,
 };
-use winio_primitive::{Point, Size};
+use winio_primitive::{Cursor, Point, Size};
 use winio_ui_windows_common::{
-    Backdrop, PreferredAppMode, control_use_dark_mode, get_current_module_handle,
+    Backdrop, PreferredAppMode, control_use_dark_mode, get_current_module_handle, not_supported,
     set_preferred_app_mode, syscall, window_use_dark_mode,
 };
 
@@ -446,6 +446,10 @@ impl Window {
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(not_supported())
+    }
+
     pub fn client_size(&self) -> Result<Size> {
         let handle = self.as_window().as_win32();
         let mut rect = MaybeUninit::uninit();
@@ -559,6 +563,10 @@ impl View {
     pub fn size(&self) -> Result<Size>;
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
+
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(not_supported())
+    }
 }
 
 winio_handle::impl_as_widget!(View, handle);
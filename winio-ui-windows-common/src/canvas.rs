@@ -1,6 +1,6 @@
 use std::{
     cell::{Ref, RefCell},
-    mem::MaybeUninit,
+    mem::{ManuallyDrop, MaybeUninit},
 };
 
 use image::{DynamicImage, Pixel, Rgba, RgbaImage};
@@ -10,23 +10,30 @@ use windows::{
         Direct2D::{
             Common::{
                 D2D_RECT_F, D2D_SIZE_F, D2D_SIZE_U, D2D1_ALPHA_MODE_PREMULTIPLIED,
-                D2D1_BEZIER_SEGMENT, D2D1_COLOR_F, D2D1_FIGURE_BEGIN_HOLLOW,
-                D2D1_FIGURE_END_CLOSED, D2D1_FIGURE_END_OPEN, D2D1_GRADIENT_STOP,
+                D2D1_BEZIER_SEGMENT, D2D1_COLOR_F, D2D1_FIGURE_BEGIN_FILLED,
+                D2D1_FIGURE_BEGIN_HOLLOW, D2D1_FIGURE_END_CLOSED, D2D1_FIGURE_END_OPEN,
+                D2D1_FILL_MODE_ALTERNATE, D2D1_FILL_MODE_WINDING, D2D1_GRADIENT_STOP,
                 D2D1_PIXEL_FORMAT,
             },
-            D2D1_ARC_SEGMENT, D2D1_ARC_SIZE_LARGE, D2D1_ARC_SIZE_SMALL,
-            D2D1_BITMAP_INTERPOLATION_MODE_NEAREST_NEIGHBOR, D2D1_BITMAP_PROPERTIES,
-            D2D1_BRUSH_PROPERTIES, D2D1_DEFAULT_FLATTENING_TOLERANCE,
-            D2D1_DRAW_TEXT_OPTIONS_ENABLE_COLOR_FONT, D2D1_ELLIPSE, D2D1_EXTEND_MODE_CLAMP,
-            D2D1_GAMMA_2_2, D2D1_LINEAR_GRADIENT_BRUSH_PROPERTIES,
-            D2D1_RADIAL_GRADIENT_BRUSH_PROPERTIES, D2D1_ROUNDED_RECT,
+            D2D1_ANTIALIAS_MODE_PER_PRIMITIVE, D2D1_ARC_SEGMENT, D2D1_ARC_SIZE_LARGE,
+            D2D1_ARC_SIZE_SMALL, D2D1_BITMAP_BRUSH_PROPERTIES,
+            D2D1_BITMAP_INTERPOLATION_MODE_LINEAR, D2D1_BITMAP_INTERPOLATION_MODE_NEAREST_NEIGHBOR,
+            D2D1_BITMAP_PROPERTIES, D2D1_BRUSH_PROPERTIES, D2D1_CAP_STYLE_FLAT,
+            D2D1_CAP_STYLE_ROUND, D2D1_CAP_STYLE_SQUARE, D2D1_DASH_STYLE_CUSTOM,
+            D2D1_DASH_STYLE_SOLID, D2D1_DEFAULT_FLATTENING_TOLERANCE,
+            D2D1_DRAW_TEXT_OPTIONS_ENABLE_COLOR_FONT, D2D1_ELLIPSE, D2D1_EXTEND_MODE,
+            D2D1_EXTEND_MODE_CLAMP, D2D1_EXTEND_MODE_MIRROR, D2D1_EXTEND_MODE_WRAP, D2D1_GAMMA_2_2,
+            D2D1_LAYER_OPTIONS_NONE, D2D1_LAYER_PARAMETERS, D2D1_LINE_JOIN_BEVEL,
+            D2D1_LINE_JOIN_MITER_OR_BEVEL, D2D1_LINE_JOIN_ROUND,
+            D2D1_LINEAR_GRADIENT_BRUSH_PROPERTIES, D2D1_QUADRATIC_BEZIER_SEGMENT,
+            D2D1_RADIAL_GRADIENT_BRUSH_PROPERTIES, D2D1_ROUNDED_RECT, D2D1_STROKE_STYLE_PROPERTIES,
             D2D1_SWEEP_DIRECTION_CLOCKWISE, D2D1_SWEEP_DIRECTION_COUNTER_CLOCKWISE, ID2D1Bitmap,
-            ID2D1Brush, ID2D1Factory, ID2D1Geometry, ID2D1GeometrySink, ID2D1PathGeometry,
-            ID2D1RenderTarget,
+            ID2D1Brush, ID2D1Factory, ID2D1Geometry, ID2D1GeometrySink, ID2D1Layer,
+            ID2D1PathGeometry, ID2D1RenderTarget, ID2D1StrokeStyle,
         },
         DirectWrite::{
-            DWRITE_FONT_STRETCH_NORMAL, DWRITE_FONT_STYLE_ITALIC, DWRITE_FONT_STYLE_NORMAL,
-            DWRITE_FONT_WEIGHT_BOLD, DWRITE_FONT_WEIGHT_NORMAL, IDWriteFactory, IDWriteTextLayout,
+            DWRITE_FONT_STRETCH, DWRITE_FONT_STYLE_ITALIC, DWRITE_FONT_STYLE_NORMAL,
+            DWRITE_FONT_WEIGHT, IDWriteFactory, IDWriteTextLayout,
         },
         Dxgi::Common::DXGI_FORMAT_R8G8B8A8_UNORM,
     },
@@ -34,11 +41,13 @@ use windows::{
 };
 use windows_numerics::{Matrix3x2, Vector2};
 use winio_primitive::{
-    BrushPen, Color, Font, GradientStop, LinearGradientBrush, Point, RadialGradientBrush, Rect,
-    RectBox, RelativePoint, RelativeToLogical, Size, SolidColorBrush, Transform, Vector,
+    BlendMode, BrushPen, Color, ConicGradientBrush, FillRule, Font, GradientStop, ImageBrush,
+    LineCap, LineJoin, LineMetrics, LinearGradientBrush, Point, RadialGradientBrush, Rect, RectBox,
+    RelativePoint, RelativeToLogical, Size, SolidColorBrush, SpreadMode, StrokeStyle, TextFormat,
+    TextHitTest, TextSpan, Transform, Vector,
 };
 
-use crate::Result;
+use crate::{Result, not_supported};
 
 fn color_f(c: Color) -> D2D1_COLOR_F {
     D2D1_COLOR_F {
@@ -83,6 +92,52 @@ fn matrix_f(m: Transform) -> Matrix3x2 {
     }
 }
 
+fn stroke_style(d2d: &ID2D1Factory, stroke: &StrokeStyle) -> Result<Option<ID2D1StrokeStyle>> {
+    if *stroke == StrokeStyle::solid() {
+        return Ok(None);
+    }
+    let cap = match stroke.cap {
+        LineCap::Round => D2D1_CAP_STYLE_ROUND,
+        LineCap::Square => D2D1_CAP_STYLE_SQUARE,
+        _ => D2D1_CAP_STYLE_FLAT,
+    };
+    let join = match stroke.join {
+        LineJoin::Round => D2D1_LINE_JOIN_ROUND,
+        LineJoin::Bevel => D2D1_LINE_JOIN_BEVEL,
+        _ => D2D1_LINE_JOIN_MITER_OR_BEVEL,
+    };
+    let dashes = if stroke.is_dashed() {
+        stroke.dashes.iter().map(|d| *d as f32).collect::<Vec<_>>()
+    } else {
+        vec![]
+    };
+    let props = D2D1_STROKE_STYLE_PROPERTIES {
+        startCap: cap,
+        endCap: cap,
+        dashCap: cap,
+        lineJoin: join,
+        miterLimit: stroke.miter_limit as f32,
+        dashStyle: if dashes.is_empty() {
+            D2D1_DASH_STYLE_SOLID
+        } else {
+            D2D1_DASH_STYLE_CUSTOM
+        },
+        dashOffset: stroke.dash_offset as f32,
+    };
+    let style = unsafe {
+        d2d.CreateStrokeStyle(&props, (!dashes.is_empty()).then_some(dashes.as_slice()))?
+    };
+    Ok(Some(style))
+}
+
+fn extend_mode(spread: SpreadMode) -> D2D1_EXTEND_MODE {
+    match spread {
+        SpreadMode::Repeat => D2D1_EXTEND_MODE_WRAP,
+        SpreadMode::Reflect => D2D1_EXTEND_MODE_MIRROR,
+        _ => D2D1_EXTEND_MODE_CLAMP,
+    }
+}
+
 fn gradient_stop(s: &GradientStop) -> D2D1_GRADIENT_STOP {
     D2D1_GRADIENT_STOP {
         position: s.pos as f32,
@@ -90,10 +145,17 @@ fn gradient_stop(s: &GradientStop) -> D2D1_GRADIENT_STOP {
     }
 }
 
+/// The kind of a pushed clip or layer, which decides how to pop it.
+enum Clip {
+    AxisAligned,
+    Layer,
+}
+
 pub struct DrawingContext {
     d2d: ID2D1Factory,
     dwrite: IDWriteFactory,
     target: ID2D1RenderTarget,
+    clips: Vec<Clip>,
 }
 
 #[inline]
@@ -124,6 +186,7 @@ impl DrawingContext {
             d2d,
             dwrite,
             target,
+            clips: vec![],
         }
     }
 
@@ -136,9 +199,14 @@ impl DrawingContext {
         brush.create(&self.target, to_trans(rect))
     }
 
-    #[inline]
-    fn get_pen(&self, pen: impl Pen, rect: Rect) -> Result<(ID2D1Brush, f32)> {
-        pen.create(&self.target, to_trans(rect))
+    fn get_pen(
+        &self,
+        pen: impl Pen,
+        rect: Rect,
+    ) -> Result<(ID2D1Brush, f32, Option<ID2D1StrokeStyle>)> {
+        let style = stroke_style(&self.d2d, pen.stroke())?;
+        let (brush, width) = pen.create(&self.target, to_trans(rect))?;
+        Ok((brush, width, style))
     }
 
     fn get_arc_geo(&self, rect: Rect, start: f64, end: f64, close: bool) -> Result<ID2D1Geometry> {
@@ -183,17 +251,14 @@ impl DrawingContext {
             let format = self.dwrite.CreateTextFormat(
                 windows::core::PCWSTR::from_raw(f.as_ptr()),
                 None,
-                if font.bold {
-                    DWRITE_FONT_WEIGHT_BOLD
-                } else {
-                    DWRITE_FONT_WEIGHT_NORMAL
-                },
+                DWRITE_FONT_WEIGHT(font.weight.0 as _),
                 if font.italic {
                     DWRITE_FONT_STYLE_ITALIC
                 } else {
                     DWRITE_FONT_STYLE_NORMAL
                 },
-                DWRITE_FONT_STRETCH_NORMAL,
+                // The stretches of DirectWrite start from 1.
+                DWRITE_FONT_STRETCH(font.stretch as i32 + 1),
                 font.size as f32,
                 windows::core::w!(""),
             )?;
@@ -237,13 +302,85 @@ impl DrawingContext {
         ))
     }
 
+    pub fn push_clip_rect(&mut self, rect: Rect) -> Result<()> {
+        let transform = self.transform()?;
+        if transform.m12 == 0.0 && transform.m21 == 0.0 {
+            unsafe {
+                self.target
+                    .PushAxisAlignedClip(&rect_f(rect), D2D1_ANTIALIAS_MODE_PER_PRIMITIVE);
+            }
+            self.clips.push(Clip::AxisAligned);
+            Ok(())
+        } else {
+            // The axis-aligned clip becomes the bounding box under a rotation.
+            let geo = unsafe { self.d2d.CreateRectangleGeometry(&rect_f(rect))? };
+            self.push_d2d_layer(Some(geo.cast()?), 1.0)
+        }
+    }
+
+    pub fn push_clip_path(&mut self, path: &DrawingPath) -> Result<()> {
+        self.push_d2d_layer(Some(path.geo.clone()), 1.0)
+    }
+
+    /// Only [`BlendMode::Normal`] is supported by the render target.
+    pub fn push_layer(&mut self, opacity: f64, blend: BlendMode) -> Result<()> {
+        if blend != BlendMode::Normal {
+            return Err(not_supported());
+        }
+        self.push_d2d_layer(None, opacity)
+    }
+
+    pub fn pop_layer(&mut self) -> Result<()> {
+        self.pop_clip()
+    }
+
+    fn push_d2d_layer(&mut self, geo: Option<ID2D1Geometry>, opacity: f64) -> Result<()> {
+        let params = D2D1_LAYER_PARAMETERS {
+            contentBounds: D2D_RECT_F {
+                left: f32::MIN,
+                top: f32::MIN,
+                right: f32::MAX,
+                bottom: f32::MAX,
+            },
+            geometricMask: ManuallyDrop::new(geo),
+            maskAntialiasMode: D2D1_ANTIALIAS_MODE_PER_PRIMITIVE,
+            maskTransform: Matrix3x2::identity(),
+            opacity: opacity as f32,
+            opacityBrush: ManuallyDrop::new(None),
+            layerOptions: D2D1_LAYER_OPTIONS_NONE,
+        };
+        unsafe {
+            self.target.PushLayer(&params, None::<&ID2D1Layer>);
+        }
+        drop(ManuallyDrop::into_inner(params.geometricMask));
+        self.clips.push(Clip::Layer);
+        Ok(())
+    }
+
+    pub fn pop_clip(&mut self) -> Result<()> {
+        match self.clips.pop() {
+            Some(Clip::AxisAligned) => unsafe { self.target.PopAxisAlignedClip() },
+            Some(Clip::Layer) => unsafe { self.target.PopLayer() },
+            None => {}
+        }
+        Ok(())
+    }
+
+    /// Pop the clips left, which is required before `EndDraw`.
+    pub fn pop_all_clips(&mut self) -> Result<()> {
+        while !self.clips.is_empty() {
+            self.pop_clip()?;
+        }
+        Ok(())
+    }
+
     pub fn draw_path(&mut self, pen: impl Pen, path: &DrawingPath) -> Result<()> {
         let width = pen.width();
         let rect = unsafe {
             path.geo
                 .GetWidenedBounds(width, None, None, D2D1_DEFAULT_FLATTENING_TOLERANCE)?
         };
-        let (b, width) = self.get_pen(
+        let (b, width, style) = self.get_pen(
             pen,
             RectBox::new(
                 Point::new(rect.left as _, rect.top as _),
@@ -252,12 +389,19 @@ impl DrawingContext {
             .to_rect(),
         )?;
         unsafe {
-            self.target.DrawGeometry(&path.geo, &b, width, None);
+            self.target
+                .DrawGeometry(&path.geo, &b, width, style.as_ref());
         }
         Ok(())
     }
 
-    pub fn fill_path(&mut self, brush: impl Brush, path: &DrawingPath) -> Result<()> {
+    pub fn fill_path(
+        &mut self,
+        brush: impl Brush,
+        path: &DrawingPath,
+        rule: FillRule,
+    ) -> Result<()> {
+        let geo = path.with_fill_rule(rule)?;
         let rect = unsafe { path.geo.GetBounds(None)? };
         let b = self.get_brush(
             brush,
@@ -268,25 +412,25 @@ impl DrawingContext {
             .to_rect(),
         )?;
         unsafe {
-            self.target.FillGeometry(&path.geo, &b, None);
+            self.target.FillGeometry(&geo, &b, None);
         }
         Ok(())
     }
 
     pub fn draw_arc(&mut self, pen: impl Pen, rect: Rect, start: f64, end: f64) -> Result<()> {
         let geo = self.get_arc_geo(rect, start, end, false)?;
-        let (b, width) = self.get_pen(pen, rect)?;
+        let (b, width, style) = self.get_pen(pen, rect)?;
         unsafe {
-            self.target.DrawGeometry(&geo, &b, width, None);
+            self.target.DrawGeometry(&geo, &b, width, style.as_ref());
         }
         Ok(())
     }
 
     pub fn draw_pie(&mut self, pen: impl Pen, rect: Rect, start: f64, end: f64) -> Result<()> {
         let geo = self.get_arc_geo(rect, start, end, true)?;
-        let (b, width) = self.get_pen(pen, rect)?;
+        let (b, width, style) = self.get_pen(pen, rect)?;
         unsafe {
-            self.target.DrawGeometry(&geo, &b, width, None);
+            self.target.DrawGeometry(&geo, &b, width, style.as_ref());
         }
         Ok(())
     }
@@ -302,9 +446,9 @@ impl DrawingContext {
 
     pub fn draw_ellipse(&mut self, pen: impl Pen, rect: Rect) -> Result<()> {
         let e = ellipse(rect);
-        let (b, width) = self.get_pen(pen, rect)?;
+        let (b, width, style) = self.get_pen(pen, rect)?;
         unsafe {
-            self.target.DrawEllipse(&e, &b, width, None);
+            self.target.DrawEllipse(&e, &b, width, style.as_ref());
         }
         Ok(())
     }
@@ -324,18 +468,19 @@ impl DrawingContext {
             Point::new(start.x.max(end.x), start.y.max(end.y)),
         )
         .to_rect();
-        let (b, width) = self.get_pen(pen, rect)?;
+        let (b, width, style) = self.get_pen(pen, rect)?;
         unsafe {
             self.target
-                .DrawLine(point_2f(start), point_2f(end), &b, width, None);
+                .DrawLine(point_2f(start), point_2f(end), &b, width, style.as_ref());
         }
         Ok(())
     }
 
     pub fn draw_rect(&mut self, pen: impl Pen, rect: Rect) -> Result<()> {
-        let (b, width) = self.get_pen(pen, rect)?;
+        let (b, width, style) = self.get_pen(pen, rect)?;
         unsafe {
-            self.target.DrawRectangle(&rect_f(rect), &b, width, None);
+            self.target
+                .DrawRectangle(&rect_f(rect), &b, width, style.as_ref());
         }
         Ok(())
     }
@@ -349,7 +494,7 @@ impl DrawingContext {
     }
 
     pub fn draw_round_rect(&mut self, pen: impl Pen, rect: Rect, round: Size) -> Result<()> {
-        let (b, width) = self.get_pen(pen, rect)?;
+        let (b, width, style) = self.get_pen(pen, rect)?;
         unsafe {
             self.target.DrawRoundedRectangle(
                 &D2D1_ROUNDED_RECT {
@@ -359,7 +504,7 @@ impl DrawingContext {
                 },
                 &b,
                 width,
-                None,
+                style.as_ref(),
             );
         }
         Ok(())
@@ -430,24 +575,128 @@ impl DrawingContext {
         Ok(())
     }
 
+    pub fn create_text_layout(
+        &self,
+        _text: &str,
+        _font: Font,
+        _spans: &[TextSpan],
+        _format: &TextFormat,
+    ) -> Result<TextLayout> {
+        Err(not_supported())
+    }
+
+    pub fn draw_text_layout(
+        &mut self,
+        _brush: impl Brush,
+        layout: &TextLayout,
+        _pos: Point,
+    ) -> Result<()> {
+        match *layout {}
+    }
+
     pub fn create_path_builder(&self, start: Point) -> Result<DrawingPathBuilder> {
         DrawingPathBuilder::new(&self.d2d, start)
     }
 }
 
+/// Text layouts are not supported yet.
+pub enum TextLayout {}
+
+impl TextLayout {
+    pub fn size(&self) -> Result<Size> {
+        match *self {}
+    }
+
+    pub fn line_metrics(&self) -> Result<Vec<LineMetrics>> {
+        match *self {}
+    }
+
+    pub fn hit_test(&self, _p: Point) -> Result<TextHitTest> {
+        match *self {}
+    }
+
+    pub fn caret_rect(&self, _index: usize) -> Result<Rect> {
+        match *self {}
+    }
+}
+
 pub struct DrawingPath {
+    d2d: ID2D1Factory,
     geo: ID2D1Geometry,
 }
 
 impl DrawingPath {
-    fn new(geo: ID2D1Geometry) -> Self {
-        Self { geo }
+    fn new(d2d: ID2D1Factory, geo: ID2D1Geometry) -> Self {
+        Self { d2d, geo }
+    }
+
+    /// The fill mode of a path geometry is fixed when building, so it is
+    /// wrapped in a group with the required mode.
+    fn with_fill_rule(&self, rule: FillRule) -> Result<ID2D1Geometry> {
+        let mode = match rule {
+            FillRule::EvenOdd => D2D1_FILL_MODE_ALTERNATE,
+            _ => D2D1_FILL_MODE_WINDING,
+        };
+        unsafe {
+            self.d2d
+                .CreateGeometryGroup(mode, &[Some(self.geo.clone())])?
+                .cast()
+        }
+    }
+
+    pub fn bounds(&self) -> Result<Rect> {
+        let rect = unsafe { self.geo.GetBounds(None)? };
+        Ok(RectBox::new(
+            Point::new(rect.left as _, rect.top as _),
+            Point::new(rect.right as _, rect.bottom as _),
+        )
+        .to_rect())
+    }
+
+    pub fn contains(&self, p: Point, rule: FillRule) -> Result<bool> {
+        let geo = self.with_fill_rule(rule)?;
+        let res =
+            unsafe { geo.FillContainsPoint(point_2f(p), None, D2D1_DEFAULT_FLATTENING_TOLERANCE)? };
+        Ok(res.as_bool())
+    }
+
+    pub fn stroke_contains(&self, p: Point, width: f64) -> Result<bool> {
+        let res = unsafe {
+            self.geo.StrokeContainsPoint(
+                point_2f(p),
+                width as f32,
+                None,
+                None,
+                D2D1_DEFAULT_FLATTENING_TOLERANCE,
+            )?
+        };
+        Ok(res.as_bool())
+    }
+
+    pub fn length(&self) -> Result<f64> {
+        let len = unsafe {
+            self.geo
+                .ComputeLength(None, D2D1_DEFAULT_FLATTENING_TOLERANCE)?
+        };
+        Ok(len as f64)
+    }
+
+    pub fn transform(&self, transform: &Transform) -> Result<DrawingPath> {
+        let geo = unsafe {
+            self.d2d
+                .CreateTransformedGeometry(&self.geo, &matrix_f(*transform))?
+        };
+        Ok(DrawingPath::new(self.d2d.clone(), geo.cast()?))
     }
 }
 
 pub struct DrawingPathBuilder {
+    d2d: ID2D1Factory,
     geo: ID2D1PathGeometry,
     sink: ID2D1GeometrySink,
+    start: Point,
+    current: Point,
+    open: bool,
 }
 
 impl DrawingPathBuilder {
@@ -455,15 +704,62 @@ impl DrawingPathBuilder {
         unsafe {
             let geo = d2d.CreatePathGeometry()?;
             let sink = geo.Open()?;
-            sink.BeginFigure(point_2f(start), D2D1_FIGURE_BEGIN_HOLLOW);
-            Ok(Self { geo, sink })
+            let mut builder = Self {
+                d2d: d2d.clone(),
+                geo,
+                sink,
+                start,
+                current: start,
+                open: false,
+            };
+            builder.begin_figure();
+            Ok(builder)
+        }
+    }
+
+    /// Begin a figure at the current point, if there isn't an open one.
+    fn begin_figure(&mut self) {
+        if !self.open {
+            unsafe {
+                self.sink
+                    .BeginFigure(point_2f(self.current), D2D1_FIGURE_BEGIN_FILLED);
+            }
+            self.start = self.current;
+            self.open = true;
+        }
+    }
+
+    fn end_figure(&mut self, close: bool) {
+        if self.open {
+            unsafe {
+                self.sink.EndFigure(if close {
+                    D2D1_FIGURE_END_CLOSED
+                } else {
+                    D2D1_FIGURE_END_OPEN
+                });
+            }
+            self.open = false;
         }
     }
 
     pub fn add_line(&mut self, p: Point) -> Result<()> {
+        self.begin_figure();
         unsafe {
             self.sink.AddLine(point_2f(p));
         }
+        self.current = p;
+        Ok(())
+    }
+
+    pub fn move_to(&mut self, p: Point) -> Result<()> {
+        self.end_figure(false);
+        self.current = p;
+        Ok(())
+    }
+
+    pub fn close_figure(&mut self) -> Result<()> {
+        self.end_figure(true);
+        self.current = self.start;
         Ok(())
     }
 
@@ -475,11 +771,10 @@ impl DrawingPathBuilder {
         end: f64,
         clockwise: bool,
     ) -> Result<()> {
+        let startp = center + Vector::new(radius.width * start.cos(), radius.height * start.sin());
+        let endp = center + Vector::new(radius.width * end.cos(), radius.height * end.sin());
+        self.add_line(startp)?;
         unsafe {
-            let startp =
-                center + Vector::new(radius.width * start.cos(), radius.height * start.sin());
-            let endp = center + Vector::new(radius.width * end.cos(), radius.height * end.sin());
-            self.add_line(startp)?;
             self.sink.AddArc(&D2D1_ARC_SEGMENT {
                 point: point_2f(endp),
                 size: size_f(radius),
@@ -496,10 +791,12 @@ impl DrawingPathBuilder {
                 },
             });
         }
+        self.current = endp;
         Ok(())
     }
 
     pub fn add_bezier(&mut self, p1: Point, p2: Point, p3: Point) -> Result<()> {
+        self.begin_figure();
         unsafe {
             self.sink.AddBezier(&D2D1_BEZIER_SEGMENT {
                 point1: point_2f(p1),
@@ -507,18 +804,67 @@ impl DrawingPathBuilder {
                 point3: point_2f(p3),
             });
         }
+        self.current = p3;
         Ok(())
     }
 
-    pub fn build(self, close: bool) -> Result<DrawingPath> {
+    pub fn add_quad(&mut self, p1: Point, p2: Point) -> Result<()> {
+        self.begin_figure();
+        unsafe {
+            self.sink
+                .AddQuadraticBezier(&D2D1_QUADRATIC_BEZIER_SEGMENT {
+                    point1: point_2f(p1),
+                    point2: point_2f(p2),
+                });
+        }
+        self.current = p2;
+        Ok(())
+    }
+
+    pub fn add_rect(&mut self, rect: Rect) -> Result<()> {
+        self.end_figure(false);
+        let rect = rect.to_box2d();
+        self.current = rect.min;
+        self.begin_figure();
+        unsafe {
+            self.sink
+                .AddLine(point_2f(Point::new(rect.max.x, rect.min.y)));
+            self.sink.AddLine(point_2f(rect.max));
+            self.sink
+                .AddLine(point_2f(Point::new(rect.min.x, rect.max.y)));
+        }
+        self.end_figure(true);
+        Ok(())
+    }
+
+    pub fn add_ellipse(&mut self, rect: Rect) -> Result<()> {
+        self.end_figure(false);
+        let radius = rect.size / 2.0;
+        let center = rect.center();
+        let right = Point::new(center.x + radius.width, center.y);
+        let left = Point::new(center.x - radius.width, center.y);
+        self.current = right;
+        self.begin_figure();
+        for p in [left, right] {
+            unsafe {
+                self.sink.AddArc(&D2D1_ARC_SEGMENT {
+                    point: point_2f(p),
+                    size: size_f(radius),
+                    rotationAngle: 0.0,
+                    sweepDirection: D2D1_SWEEP_DIRECTION_CLOCKWISE,
+                    arcSize: D2D1_ARC_SIZE_SMALL,
+                });
+            }
+        }
+        self.end_figure(true);
+        Ok(())
+    }
+
+    pub fn build(mut self, close: bool) -> Result<DrawingPath> {
+        self.end_figure(close);
         unsafe {
-            self.sink.EndFigure(if close {
-                D2D1_FIGURE_END_CLOSED
-            } else {
-                D2D1_FIGURE_END_OPEN
-            });
             self.sink.Close()?;
-            Ok(DrawingPath::new(self.geo.cast()?))
+            Ok(DrawingPath::new(self.d2d, self.geo.cast()?))
         }
     }
 }
@@ -532,10 +878,12 @@ const MATRIX_IDENTITY: Matrix3x2 = Matrix3x2 {
     M32: 0.0,
 };
 
-const BRUSH_PROPERTIES_DEFAULT: D2D1_BRUSH_PROPERTIES = D2D1_BRUSH_PROPERTIES {
-    opacity: 1.0,
-    transform: MATRIX_IDENTITY,
-};
+const fn brush_properties(opacity: f64) -> D2D1_BRUSH_PROPERTIES {
+    D2D1_BRUSH_PROPERTIES {
+        opacity: opacity as f32,
+        transform: MATRIX_IDENTITY,
+    }
+}
 
 /// Drawing brush.
 pub trait Brush {
@@ -553,7 +901,7 @@ impl Brush for SolidColorBrush {
     fn create(&self, target: &ID2D1RenderTarget, _trans: RelativeToLogical) -> Result<ID2D1Brush> {
         unsafe {
             target
-                .CreateSolidColorBrush(&color_f(self.color), Some(&BRUSH_PROPERTIES_DEFAULT))?
+                .CreateSolidColorBrush(&color_f(self.color), Some(&brush_properties(self.opacity)))?
                 .cast()
         }
     }
@@ -570,12 +918,12 @@ impl Brush for LinearGradientBrush {
             let stop_collection = target.CreateGradientStopCollection(
                 &stops,
                 D2D1_GAMMA_2_2,
-                D2D1_EXTEND_MODE_CLAMP,
+                extend_mode(self.spread),
             )?;
             target
                 .CreateLinearGradientBrush(
                     &props,
-                    Some(&BRUSH_PROPERTIES_DEFAULT),
+                    Some(&brush_properties(self.opacity)),
                     &stop_collection,
                 )?
                 .cast()
@@ -600,12 +948,12 @@ impl Brush for RadialGradientBrush {
             let stop_collection = target.CreateGradientStopCollection(
                 &stops,
                 D2D1_GAMMA_2_2,
-                D2D1_EXTEND_MODE_CLAMP,
+                extend_mode(self.spread),
             )?;
             target
                 .CreateRadialGradientBrush(
                     &props,
-                    Some(&BRUSH_PROPERTIES_DEFAULT),
+                    Some(&brush_properties(self.opacity)),
                     &stop_collection,
                 )?
                 .cast()
@@ -613,6 +961,49 @@ impl Brush for RadialGradientBrush {
     }
 }
 
+impl Brush for ConicGradientBrush {
+    fn create(&self, target: &ID2D1RenderTarget, trans: RelativeToLogical) -> Result<ID2D1Brush> {
+        // Direct2D has no conic gradient, so it is rasterized for the area.
+        let width = trans.m11.abs().ceil().clamp(1.0, 4096.0) as u32;
+        let height = trans.m22.abs().ceil().clamp(1.0, 4096.0) as u32;
+        let image = RgbaImage::from_fn(width, height, |x, y| {
+            let p = RelativePoint::new(
+                (x as f64 + 0.5) / width as f64,
+                (y as f64 + 0.5) / height as f64,
+            );
+            let c = self.color_at(p);
+            Rgba([c.r, c.g, c.b, c.a])
+        });
+        let image = DrawingImage::new(target, DynamicImage::ImageRgba8(image))?;
+        ImageBrush::new(&image).create(target, trans)
+    }
+}
+
+impl<I: AsRef<DrawingImage>> Brush for ImageBrush<I> {
+    fn create(&self, target: &ID2D1RenderTarget, trans: RelativeToLogical) -> Result<ID2D1Brush> {
+        let image = self.image.as_ref();
+        let extend = extend_mode(self.spread);
+        let bitmap_props = D2D1_BITMAP_BRUSH_PROPERTIES {
+            extendModeX: extend,
+            extendModeY: extend,
+            interpolationMode: D2D1_BITMAP_INTERPOLATION_MODE_LINEAR,
+        };
+        let props = D2D1_BRUSH_PROPERTIES {
+            opacity: self.opacity as f32,
+            transform: matrix_f(self.pixel_transform(image.size()?, trans).with_source()),
+        };
+        unsafe {
+            target
+                .CreateBitmapBrush(
+                    &*image.get_bitmap(target)?,
+                    Some(&bitmap_props),
+                    Some(&props),
+                )?
+                .cast()
+        }
+    }
+}
+
 /// Drawing pen.
 pub trait Pen {
     #[doc(hidden)]
@@ -623,6 +1014,8 @@ pub trait Pen {
     ) -> Result<(ID2D1Brush, f32)>;
     #[doc(hidden)]
     fn width(&self) -> f32;
+    #[doc(hidden)]
+    fn stroke(&self) -> &StrokeStyle;
 }
 
 impl<P: Pen> Pen for &'_ P {
@@ -637,6 +1030,10 @@ impl<P: Pen> Pen for &'_ P {
     fn width(&self) -> f32 {
         (**self).width()
     }
+
+    fn stroke(&self) -> &StrokeStyle {
+        (**self).stroke()
+    }
 }
 
 impl<B: Brush> Pen for BrushPen<B> {
@@ -652,6 +1049,10 @@ impl<B: Brush> Pen for BrushPen<B> {
     fn width(&self) -> f32 {
         self.width as _
     }
+
+    fn stroke(&self) -> &StrokeStyle {
+        &self.stroke
+    }
 }
 
 pub struct DrawingImage {
@@ -660,6 +1061,12 @@ pub struct DrawingImage {
     bitmap: RefCell<ID2D1Bitmap>,
 }
 
+impl AsRef<DrawingImage> for DrawingImage {
+    fn as_ref(&self) -> &DrawingImage {
+        self
+    }
+}
+
 impl DrawingImage {
     fn new(target: &ID2D1RenderTarget, image: DynamicImage) -> Result<Self> {
         let (mut image, has_alpha) = match image {
@@ -5,7 +5,9 @@
 #![cfg_attr(feature = "once_cell_try", feature(once_cell_try))]
 #![cfg(windows)]
 
+use windows::core::HRESULT;
 pub use windows::core::{Error, Result};
+use windows_sys::Win32::Foundation::E_NOTIMPL;
 
 mod version;
 pub(crate) use version::*;
@@ -40,6 +42,11 @@ pub use runtime::*;
 mod timer;
 pub use timer::*;
 
+/// The error of the features not supported on Windows.
+pub fn not_supported() -> Error {
+    Error::from_hresult(HRESULT(E_NOTIMPL))
+}
+
 pub(crate) async fn spawn_blocking<F, R>(f: F) -> R
 where
     F: FnOnce() -> R + Send + 'static,
@@ -1,7 +1,7 @@
 use std::{cell::Cell, mem::ManuallyDrop, ops::Deref, ptr::null_mut, rc::Rc};
 
 use compio_log::error;
-use image::DynamicImage;
+use image::{DynamicImage, RgbaImage};
 use inherit_methods_macro::inherit_methods;
 use send_wrapper::SendWrapper;
 use windows::{
@@ -40,10 +40,13 @@ use windows::{
 use winio_callback::Callback;
 use winio_handle::AsContainer;
 use winio_primitive::{
-    ColorTheme, Font, MouseButton, Point, Rect, RelativePoint, Size, Transform, Vector,
+    BlendMode, ColorTheme, Cursor, FillRule, Font, MouseButton, Point, Rect, RelativePoint, Size,
+    TextFormat, TextSpan, Transform, Vector,
 };
-use winio_ui_windows_common::d2d1_factory;
-pub use winio_ui_windows_common::{Brush, DrawingImage, DrawingPath, DrawingPathBuilder, Pen};
+pub use winio_ui_windows_common::{
+    Brush, DrawingImage, DrawingPath, DrawingPathBuilder, Pen, TextLayout,
+};
+use winio_ui_windows_common::{d2d1_factory, not_supported};
 use winui3::{
     ISwapChainPanelNative,
     Microsoft::UI::{
@@ -51,6 +54,7 @@ use winui3::{
         Xaml::{
             Controls::{self as MUXC, SwapChainPanel},
             Input::{PointerEventHandler, PointerRoutedEventArgs},
+            SizeChangedEventHandler,
         },
     },
 };
@@ -377,6 +381,7 @@ impl SwapChain {
 
 #[derive(Debug)]
 pub struct Canvas {
+    on_paint: SendWrapper<Rc<Callback<Rect>>>,
     handle: CanvasImpl,
     dwrite: IDWriteFactory,
     swap_chain: SwapChain,
@@ -390,7 +395,21 @@ impl Canvas {
         let swap_chain = SwapChain::new()?;
         swap_chain.set_to_panel(&handle)?;
 
+        let on_paint = SendWrapper::new(Rc::new(Callback::new()));
+        {
+            let on_paint = on_paint.clone();
+            handle.SizeChanged(&SizeChangedEventHandler::new(move |_, args| {
+                let args = args.ok()?;
+                on_paint.signal::<GlobalRuntime>(Rect::new(
+                    Point::zero(),
+                    Size::from_native(args.NewSize()?),
+                ));
+                Ok(())
+            }))?;
+        }
+
         Ok(Self {
+            on_paint,
             handle,
             dwrite,
             swap_chain,
@@ -413,11 +432,20 @@ impl Canvas {
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(not_supported())
+    }
+
     pub fn tooltip(&self) -> Result<String>;
 
     pub fn set_tooltip(&mut self, s: impl AsRef<str>) -> Result<()>;
 
-    pub fn context(&mut self) -> Result<DrawingContext<'_>> {
+    pub fn invalidate(&mut self, rect: Rect) -> Result<()> {
+        self.on_paint.signal::<()>(rect);
+        Ok(())
+    }
+
+    pub fn context(&mut self, _dirty: Option<Rect>) -> Result<DrawingContext<'_>> {
         let size = self.size()?;
         let scalex = self.handle.CompositionScaleX()?;
         let scaley = self.handle.CompositionScaleY()?;
@@ -455,6 +483,10 @@ impl Canvas {
     pub async fn wait_mouse_wheel(&self) -> Vector {
         self.handle.wait_mouse_wheel().await
     }
+
+    pub async fn wait_paint(&self) -> Rect {
+        self.on_paint.wait().await
+    }
 }
 
 winio_handle::impl_as_widget!(Canvas, handle);
@@ -489,6 +521,7 @@ impl<'a> DrawingContext<'a> {
 
     fn end_draw(&mut self) -> Result<()> {
         if !self.ended {
+            self.ctx.pop_all_clips()?;
             match self.canvas.swap_chain.end_draw() {
                 Ok(()) => {}
                 Err(e) if is_lost(&e) => self.canvas.handle_lost()?,
@@ -507,9 +540,24 @@ impl<'a> DrawingContext<'a> {
 
     pub fn transform(&self) -> Result<Transform>;
 
+    pub fn push_clip_rect(&mut self, rect: Rect) -> Result<()>;
+
+    pub fn push_clip_path(&mut self, path: &DrawingPath) -> Result<()>;
+
+    pub fn pop_clip(&mut self) -> Result<()>;
+
+    pub fn push_layer(&mut self, opacity: f64, blend: BlendMode) -> Result<()>;
+
+    pub fn pop_layer(&mut self) -> Result<()>;
+
     pub fn draw_path(&mut self, pen: impl Pen, path: &DrawingPath) -> Result<()>;
 
-    pub fn fill_path(&mut self, brush: impl Brush, path: &DrawingPath) -> Result<()>;
+    pub fn fill_path(
+        &mut self,
+        brush: impl Brush,
+        path: &DrawingPath,
+        rule: FillRule,
+    ) -> Result<()>;
 
     pub fn draw_arc(&mut self, pen: impl Pen, rect: Rect, start: f64, end: f64) -> Result<()>;
 
@@ -551,5 +599,45 @@ impl<'a> DrawingContext<'a> {
         clip: Option<Rect>,
     ) -> Result<()>;
 
+    pub fn create_text_layout(
+        &self,
+        text: &str,
+        font: Font,
+        spans: &[TextSpan],
+        format: &TextFormat,
+    ) -> Result<TextLayout>;
+
+    pub fn draw_text_layout(
+        &mut self,
+        brush: impl Brush,
+        layout: &TextLayout,
+        pos: Point,
+    ) -> Result<()>;
+
     pub fn create_path_builder(&self, start: Point) -> Result<DrawingPathBuilder>;
+
+    pub fn create_bitmap(&self, _size: Size) -> Result<DrawingBitmap> {
+        Err(not_supported())
+    }
+}
+
+/// Bitmaps are not supported yet.
+pub enum DrawingBitmap {}
+
+impl DrawingBitmap {
+    pub fn size(&self) -> Result<Size> {
+        match *self {}
+    }
+
+    pub fn context(&mut self) -> Result<DrawingContext<'_>> {
+        match *self {}
+    }
+
+    pub fn to_image(&self) -> Result<DrawingImage> {
+        match *self {}
+    }
+
+    pub fn to_rgba(&self) -> Result<RgbaImage> {
+        match *self {}
+    }
 }
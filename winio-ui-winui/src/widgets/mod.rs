@@ -12,12 +12,12 @@ use winui3::Microsoft::UI::Xaml::{
 
 /// Read the font of a [`MUXC::TextBlock`].
 pub(crate) fn text_block_to_font(block: &MUXC::TextBlock) -> Result<Font> {
-    Ok(Font {
-        family: block.FontFamily()?.Source()?.to_string_lossy(),
-        size: block.FontSize()?,
-        bold: block.FontWeight()?.Weight >= 600,
-        italic: block.FontStyle()? == FontStyle::Italic,
-    })
+    Ok(Font::builder()
+        .family(block.FontFamily()?.Source()?.to_string_lossy())
+        .size(block.FontSize()?)
+        .italic(block.FontStyle()? == FontStyle::Italic)
+        .weight(winio_primitive::FontWeight(block.FontWeight()?.Weight))
+        .build())
 }
 
 /// Set the font of a [`MUXC::TextBlock`].
@@ -31,7 +31,7 @@ pub(crate) fn font_to_text_block(block: &MUXC::TextBlock, font: &Font) -> Result
         FontStyle::Normal
     })?;
     block.SetFontWeight(FontWeight {
-        Weight: if font.bold { 700 } else { 400 },
+        Weight: font.weight.0,
     })?;
     Ok(())
 }
@@ -106,10 +106,10 @@ impl Convertible<TextAlignment> for HAlign {
 
     fn to_native(self) -> TextAlignment {
         match self {
-            HAlign::Left => TextAlignment::Left,
             HAlign::Center => TextAlignment::Center,
             HAlign::Right => TextAlignment::Right,
             HAlign::Stretch => TextAlignment::Justify,
+            _ => TextAlignment::Left,
         }
     }
 }
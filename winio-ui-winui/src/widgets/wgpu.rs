@@ -3,7 +3,8 @@ use inherit_methods_macro::inherit_methods;
 use wgpu::{CreateSurfaceError, Instance, Surface, SurfaceTargetUnsafe};
 use windows::core::Interface;
 use winio_handle::AsContainer;
-use winio_primitive::{MouseButton, Point, Size, Vector};
+use winio_primitive::{Cursor, MouseButton, Point, Size, Vector};
+use winio_ui_windows_common::not_supported;
 use winui3::ISwapChainPanelNative;
 
 use crate::{CanvasImpl, Result};
@@ -38,6 +39,10 @@ impl WgpuCanvas {
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(not_supported())
+    }
+
     pub fn tooltip(&self) -> Result<String>;
 
     pub fn set_tooltip(&mut self, s: impl AsRef<str>) -> Result<()>;
@@ -17,9 +17,9 @@ use windows_sys::Win32::UI::{
 };
 use winio_callback::{Callback, SyncCallback};
 use winio_handle::{AsContainer, AsWindow, BorrowedContainer, BorrowedWindow};
-use winio_primitive::{Point, Size};
+use winio_primitive::{Cursor, Point, Size};
 use winio_ui_windows_common::{
-    Backdrop, get_current_module_handle, set_backdrop, syscall, window_use_dark_mode,
+    Backdrop, get_current_module_handle, not_supported, set_backdrop, syscall, window_use_dark_mode,
 };
 use winui3::{
     IWindowNative,
@@ -174,6 +174,10 @@ impl Window {
         Ok(())
     }
 
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(not_supported())
+    }
+
     pub fn client_size(&self) -> Result<Size> {
         let size = match self.app_window.ClientSize() {
             Ok(s) => Size::from_native(s),
@@ -409,6 +413,10 @@ impl View {
     pub fn size(&self) -> Result<Size>;
 
     pub fn set_size(&mut self, v: Size) -> Result<()>;
+
+    pub fn set_cursor(&mut self, _cursor: Cursor) -> Result<()> {
+        Err(not_supported())
+    }
 }
 
 winio_handle::impl_as_widget!(View, handle);
@@ -4,5 +4,7 @@ fn main() {
     cfg_aliases! {
         win32: { all(windows, feature = "win32") },
         winui: { all(windows, feature = "winui") },
+        gtk: { all(not(any(windows, target_vendor = "apple", target_os = "android")), feature = "gtk", not(feature = "qt")) },
+        qt: { all(not(any(windows, target_vendor = "apple", target_os = "android")), feature = "qt") },
     }
 }
@@ -3,6 +3,7 @@ use std::future::Future;
 use compio_log::{error, warn};
 use futures_util::StreamExt;
 use winio_elm::{Component, Root, RunEvent};
+use winio_primitive::LayoutDirection;
 
 use crate::{sys, sys::App as SysApp};
 
@@ -100,12 +101,19 @@ impl App {
     pub fn name(&self) -> &str {
         &self.name
     }
+
+    /// The layout direction, which is the default direction of the layout
+    /// containers.
+    pub fn layout_direction(&self) -> LayoutDirection {
+        winio_layout::default_direction()
+    }
 }
 
 /// Builder for [`App`].
 #[derive(Default)]
 pub struct AppBuilder {
     name: String,
+    layout_direction: Option<LayoutDirection>,
     #[cfg(target_os = "android")]
     app: Option<android_activity::AndroidApp>,
 }
@@ -119,6 +127,21 @@ impl AppBuilder {
         self
     }
 
+    /// Set the layout direction. It is the default direction of the layout
+    /// containers, and the text direction of the native widgets.
+    ///
+    /// The default direction of the layout containers can only be set once in
+    /// the process, see [`winio_layout::set_default_direction`]. The direction
+    /// of the app built first is used.
+    ///
+    /// ## Platform specific
+    /// * Win32, WinUI, AppKit, UIKit and Android: Only the layout containers
+    ///   are affected.
+    pub fn layout_direction(mut self, direction: LayoutDirection) -> Self {
+        self.layout_direction = Some(direction);
+        self
+    }
+
     /// Set the Android application. This is required on Android platform.
     #[cfg(target_os = "android")]
     pub fn android_app(mut self, app: android_activity::AndroidApp) -> Self {
@@ -136,6 +159,12 @@ impl AppBuilder {
         )?;
         #[cfg(not(any(windows, target_vendor = "apple", target_os = "android")))]
         app.set_app_id(&self.name)?;
+        if let Some(direction) = self.layout_direction {
+            #[cfg(not(any(windows, target_vendor = "apple", target_os = "android")))]
+            app.set_layout_direction(direction)?;
+            // The direction of the first app is kept.
+            winio_layout::set_default_direction(direction).ok();
+        }
         Ok(App {
             app,
             name: self.name,
@@ -1,5 +1,8 @@
-use image::DynamicImage;
-use winio_primitive::{Font, Point, Rect, RelativePoint, Size, Transform};
+use image::{DynamicImage, RgbaImage};
+use winio_primitive::{
+    BlendMode, FillRule, Font, LineMetrics, Point, Rect, RelativePoint, Size, TextFormat,
+    TextHitTest, TextSpan, Transform,
+};
 
 use crate::{sys, sys::Result};
 
@@ -23,8 +26,26 @@ impl DrawingImage {
     }
 }
 
+/// Enables filling with the image by
+/// [`ImageBrush`](winio_primitive::ImageBrush).
+impl AsRef<sys::DrawingImage> for DrawingImage {
+    fn as_ref(&self) -> &sys::DrawingImage {
+        &self.0
+    }
+}
+
+/// A state pushed to the stack of [`DrawingContext`].
+enum DrawingState {
+    Clip,
+    Layer,
+    Save(Transform),
+}
+
 /// Provides the drawing operations of a [`Canvas`](crate::widgets::Canvas).
-pub struct DrawingContext<'a>(sys::DrawingContext<'a>);
+pub struct DrawingContext<'a> {
+    ctx: sys::DrawingContext<'a>,
+    states: Vec<DrawingState>,
+}
 
 #[inline]
 fn fix_rect(mut rect: Rect) -> Rect {
@@ -50,82 +71,181 @@ fn fix_font(mut font: Font) -> Font {
 
 impl<'a> DrawingContext<'a> {
     pub(crate) fn new(ctx: sys::DrawingContext<'a>) -> Self {
-        Self(ctx)
+        Self {
+            ctx,
+            states: vec![],
+        }
     }
 
     /// Close the context manually.
     pub fn close(self) -> Result<()> {
-        self.0.close()
+        self.ctx.close()
     }
 
     /// Set the transform matrix.
     pub fn set_transform(&mut self, transform: Transform) -> Result<()> {
-        self.0.set_transform(transform)
+        self.ctx.set_transform(transform)
     }
 
     /// Get the transform matrix.
     pub fn transform(&self) -> Result<Transform> {
-        self.0.transform()
+        self.ctx.transform()
+    }
+
+    /// Intersect the clip region with a rectangle, under the current
+    /// transform. Pop it with [`DrawingContext::pop_clip`].
+    ///
+    /// ## Platform specific
+    /// * Windows, GTK & Qt: Supported.
+    /// * Others: Not supported.
+    pub fn push_clip_rect(&mut self, rect: Rect) -> Result<()> {
+        self.ctx.push_clip_rect(rect)?;
+        self.states.push(DrawingState::Clip);
+        Ok(())
+    }
+
+    /// Intersect the clip region with a path, under the current transform.
+    /// Pop it with [`DrawingContext::pop_clip`].
+    pub fn push_clip_path(&mut self, path: &DrawingPath) -> Result<()> {
+        self.ctx.push_clip_path(&path.0)?;
+        self.states.push(DrawingState::Clip);
+        Ok(())
+    }
+
+    /// Pop the last clip. It does nothing if no clip has been pushed since the
+    /// last [`DrawingContext::save`].
+    pub fn pop_clip(&mut self) -> Result<()> {
+        if let Some(DrawingState::Clip) = self.states.last() {
+            self.states.pop();
+            self.ctx.pop_clip()?;
+        }
+        Ok(())
+    }
+
+    /// Start a layer. The following drawing operations are composited
+    /// together onto the content below with `opacity` and `blend`, when
+    /// [`DrawingContext::pop_layer`] is called.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Supported.
+    /// * Windows: Only [`BlendMode::Normal`] is supported.
+    /// * Others: Not supported.
+    pub fn push_layer(&mut self, opacity: f64, blend: BlendMode) -> Result<()> {
+        self.ctx.push_layer(opacity.clamp(0.0, 1.0), blend)?;
+        self.states.push(DrawingState::Layer);
+        Ok(())
+    }
+
+    /// Pop the last layer and composite it. It does nothing if the last
+    /// pushed state is not a layer.
+    pub fn pop_layer(&mut self) -> Result<()> {
+        if let Some(DrawingState::Layer) = self.states.last() {
+            self.states.pop();
+            self.ctx.pop_layer()?;
+        }
+        Ok(())
+    }
+
+    /// Save the transform and the clip region, which could be restored with
+    /// [`DrawingContext::restore`].
+    pub fn save(&mut self) -> Result<()> {
+        let transform = self.ctx.transform()?;
+        self.states.push(DrawingState::Save(transform));
+        Ok(())
+    }
+
+    /// Restore the transform and the clip region of the last
+    /// [`DrawingContext::save`]. The clips and layers pushed after it are
+    /// popped. It does nothing if there is no saved state.
+    pub fn restore(&mut self) -> Result<()> {
+        if !self
+            .states
+            .iter()
+            .any(|s| matches!(s, DrawingState::Save(_)))
+        {
+            return Ok(());
+        }
+        while let Some(state) = self.states.pop() {
+            match state {
+                DrawingState::Clip => self.ctx.pop_clip()?,
+                DrawingState::Layer => self.ctx.pop_layer()?,
+                DrawingState::Save(transform) => {
+                    self.ctx.set_transform(transform)?;
+                    break;
+                }
+            }
+        }
+        Ok(())
     }
 
     /// Draw a path.
     pub fn draw_path(&mut self, pen: impl Pen, path: &DrawingPath) -> Result<()> {
-        self.0.draw_path(pen, &path.0)
+        self.ctx.draw_path(pen, &path.0)
     }
 
-    /// Fill a path.
+    /// Fill a path with [`FillRule::NonZero`].
     pub fn fill_path(&mut self, brush: impl Brush, path: &DrawingPath) -> Result<()> {
-        self.0.fill_path(brush, &path.0)
+        self.fill_path_with_rule(brush, path, FillRule::NonZero)
+    }
+
+    /// Fill a path with the fill rule.
+    pub fn fill_path_with_rule(
+        &mut self,
+        brush: impl Brush,
+        path: &DrawingPath,
+        rule: FillRule,
+    ) -> Result<()> {
+        self.ctx.fill_path(brush, &path.0, rule)
     }
 
     /// Draw an arc.
     pub fn draw_arc(&mut self, pen: impl Pen, rect: Rect, start: f64, end: f64) -> Result<()> {
-        self.0.draw_arc(pen, fix_rect(rect), start, end)
+        self.ctx.draw_arc(pen, fix_rect(rect), start, end)
     }
 
     /// Draw an arc.
     pub fn draw_pie(&mut self, pen: impl Pen, rect: Rect, start: f64, end: f64) -> Result<()> {
-        self.0.draw_pie(pen, fix_rect(rect), start, end)
+        self.ctx.draw_pie(pen, fix_rect(rect), start, end)
     }
 
     /// Fill a pie.
     pub fn fill_pie(&mut self, brush: impl Brush, rect: Rect, start: f64, end: f64) -> Result<()> {
-        self.0.fill_pie(brush, fix_rect(rect), start, end)
+        self.ctx.fill_pie(brush, fix_rect(rect), start, end)
     }
 
     /// Draw an ellipse.
     pub fn draw_ellipse(&mut self, pen: impl Pen, rect: Rect) -> Result<()> {
-        self.0.draw_ellipse(pen, fix_rect(rect))
+        self.ctx.draw_ellipse(pen, fix_rect(rect))
     }
 
     /// Fill an ellipse.
     pub fn fill_ellipse(&mut self, brush: impl Brush, rect: Rect) -> Result<()> {
-        self.0.fill_ellipse(brush, fix_rect(rect))
+        self.ctx.fill_ellipse(brush, fix_rect(rect))
     }
 
     /// Draw a line.
     pub fn draw_line(&mut self, pen: impl Pen, start: Point, end: Point) -> Result<()> {
-        self.0.draw_line(pen, start, end)
+        self.ctx.draw_line(pen, start, end)
     }
 
     /// Draw a rectangle.
     pub fn draw_rect(&mut self, pen: impl Pen, rect: Rect) -> Result<()> {
-        self.0.draw_rect(pen, fix_rect(rect))
+        self.ctx.draw_rect(pen, fix_rect(rect))
     }
 
     /// Fill a rectangle.
     pub fn fill_rect(&mut self, brush: impl Brush, rect: Rect) -> Result<()> {
-        self.0.fill_rect(brush, fix_rect(rect))
+        self.ctx.fill_rect(brush, fix_rect(rect))
     }
 
     /// Draw a rounded rectangle.
     pub fn draw_round_rect(&mut self, pen: impl Pen, rect: Rect, round: Size) -> Result<()> {
-        self.0.draw_round_rect(pen, fix_rect(rect), round)
+        self.ctx.draw_round_rect(pen, fix_rect(rect), round)
     }
 
     /// Fill a rounded rectangle.
     pub fn fill_round_rect(&mut self, brush: impl Brush, rect: Rect, round: Size) -> Result<()> {
-        self.0.fill_round_rect(brush, fix_rect(rect), round)
+        self.ctx.fill_round_rect(brush, fix_rect(rect), round)
     }
 
     /// Draw a string.
@@ -137,18 +257,58 @@ impl<'a> DrawingContext<'a> {
         pos: Point,
         text: impl AsRef<str>,
     ) -> Result<()> {
-        self.0
+        self.ctx
             .draw_str(brush, fix_font(font), anchor, pos, text.as_ref())
     }
 
     /// Measure string size.
     pub fn measure_str(&self, font: Font, text: &str) -> Result<Size> {
-        self.0.measure_str(fix_font(font), text)
+        self.ctx.measure_str(fix_font(font), text)
+    }
+
+    /// Create a [`TextLayout`] of a string. The spans override the styles of
+    /// the ranges of the text.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Supported.
+    /// * Others: Not supported.
+    pub fn create_text_layout(
+        &self,
+        text: impl AsRef<str>,
+        font: Font,
+        spans: &[TextSpan],
+        format: &TextFormat,
+    ) -> Result<TextLayout> {
+        let spans = spans
+            .iter()
+            .cloned()
+            .map(|mut span| {
+                span.font = span.font.map(fix_font);
+                span
+            })
+            .collect::<Vec<_>>();
+        Ok(TextLayout(self.ctx.create_text_layout(
+            text.as_ref(),
+            fix_font(font),
+            &spans,
+            format,
+        )?))
+    }
+
+    /// Draw a [`TextLayout`] with its top-left corner at `pos`. The brush
+    /// fills the text without a color span.
+    pub fn draw_text_layout(
+        &mut self,
+        brush: impl Brush,
+        layout: &TextLayout,
+        pos: Point,
+    ) -> Result<()> {
+        self.ctx.draw_text_layout(brush, &layout.0, pos)
     }
 
     /// Create a [`DrawingContext`]-compatible image from [`DynamicImage`].
     pub fn create_image(&self, image: DynamicImage) -> Result<DrawingImage> {
-        Ok(DrawingImage(self.0.create_image(image)?))
+        Ok(DrawingImage(self.ctx.create_image(image)?))
     }
 
     /// Draw a [`DrawingImage`].
@@ -163,12 +323,83 @@ impl<'a> DrawingContext<'a> {
         rect: Rect,
         clip: Option<Rect>,
     ) -> Result<()> {
-        self.0.draw_image(&image.0, fix_rect(rect), clip)
+        self.ctx.draw_image(&image.0, fix_rect(rect), clip)
     }
 
     /// Create [`DrawingPathBuilder`].
     pub fn create_path_builder(&self, start: Point) -> Result<DrawingPathBuilder> {
-        Ok(DrawingPathBuilder(self.0.create_path_builder(start)?))
+        Ok(DrawingPathBuilder(self.ctx.create_path_builder(start)?))
+    }
+
+    /// Create a transparent [`DrawingBitmap`] with the logical size, at the
+    /// pixel density of the current context.
+    pub fn create_bitmap(&self, size: Size) -> Result<DrawingBitmap> {
+        Ok(DrawingBitmap(self.ctx.create_bitmap(fix_size(size))?))
+    }
+}
+
+/// An offscreen drawing target. The content is kept between contexts.
+///
+/// ## Platform specific
+/// * GTK: Cairo image surface.
+/// * Qt: `QImage`.
+/// * Others: Not supported.
+pub struct DrawingBitmap(sys::DrawingBitmap);
+
+impl DrawingBitmap {
+    /// Logical size of the bitmap.
+    pub fn size(&self) -> Result<Size> {
+        self.0.size()
+    }
+
+    /// Create the [`DrawingContext`] of the bitmap.
+    pub fn context(&mut self) -> Result<DrawingContext<'_>> {
+        Ok(DrawingContext::new(self.0.context()?))
+    }
+
+    /// Copy the content into a [`DrawingImage`]. The image is measured in
+    /// pixels, so draw it with an explicit destination rectangle.
+    pub fn to_image(&self) -> Result<DrawingImage> {
+        Ok(DrawingImage(self.0.to_image()?))
+    }
+
+    /// Read back the content in pixels, with straight alpha.
+    pub fn to_rgba(&self) -> Result<RgbaImage> {
+        self.0.to_rgba()
+    }
+}
+
+/// A paragraph of styled text, laid out with wrapping and alignment.
+///
+/// The indices are byte indices into the text.
+///
+/// ## Platform specific
+/// * GTK: `PangoLayout`.
+/// * Qt: `QTextLayout`.
+/// * Others: Not supported.
+pub struct TextLayout(sys::TextLayout);
+
+impl TextLayout {
+    /// The size of the laid out text.
+    pub fn size(&self) -> Result<Size> {
+        self.0.size()
+    }
+
+    /// The metrics of the lines.
+    pub fn line_metrics(&self) -> Result<Vec<LineMetrics>> {
+        self.0.line_metrics()
+    }
+
+    /// Find the caret nearest to a point, relative to the origin of the
+    /// layout.
+    pub fn hit_test(&self, p: Point) -> Result<TextHitTest> {
+        self.0.hit_test(p)
+    }
+
+    /// The rectangle of the caret before the byte index, relative to the
+    /// origin of the layout. The width is zero.
+    pub fn caret_rect(&self, index: usize) -> Result<Rect> {
+        self.0.caret_rect(index)
     }
 }
 
@@ -176,6 +407,39 @@ impl<'a> DrawingContext<'a> {
 /// filling or stroking.
 pub struct DrawingPath(sys::DrawingPath);
 
+/// Geometry queries of the path. The coordinates are the ones used when
+/// building the path, regardless of the transform of the context.
+///
+/// ## Platform specific
+/// * Windows, GTK & Qt: Supported.
+/// * Others: Not supported.
+impl DrawingPath {
+    /// The bounding rectangle of the path, without the stroke.
+    pub fn bounds(&self) -> Result<Rect> {
+        self.0.bounds()
+    }
+
+    /// Whether the point is inside the filled area with the fill rule.
+    pub fn contains(&self, p: Point, rule: FillRule) -> Result<bool> {
+        self.0.contains(p, rule)
+    }
+
+    /// Whether the point is on the stroke with the width.
+    pub fn stroke_contains(&self, p: Point, width: f64) -> Result<bool> {
+        self.0.stroke_contains(p, width)
+    }
+
+    /// The total length of the segments.
+    pub fn length(&self) -> Result<f64> {
+        self.0.length()
+    }
+
+    /// Create a new path with all points transformed.
+    pub fn transform(&self, transform: &Transform) -> Result<DrawingPath> {
+        Ok(DrawingPath(self.0.transform(transform)?))
+    }
+}
+
 /// Builder for [`DrawingPath`].
 pub struct DrawingPathBuilder(sys::DrawingPathBuilder);
 
@@ -186,6 +450,19 @@ impl DrawingPathBuilder {
         Ok(())
     }
 
+    /// Start a new figure at the point. The current figure is left open.
+    pub fn move_to(&mut self, p: Point) -> Result<()> {
+        self.0.move_to(p)?;
+        Ok(())
+    }
+
+    /// Close the current figure with a line to its start point, which becomes
+    /// the current point.
+    pub fn close_figure(&mut self) -> Result<()> {
+        self.0.close_figure()?;
+        Ok(())
+    }
+
     /// Add arc. A line will be created implicitly if the start point is not the
     /// current point.
     pub fn add_arc(
@@ -207,6 +484,24 @@ impl DrawingPathBuilder {
         Ok(())
     }
 
+    /// Add a quadratic Bezier curve.
+    pub fn add_quad(&mut self, p1: Point, p2: Point) -> Result<()> {
+        self.0.add_quad(p1, p2)?;
+        Ok(())
+    }
+
+    /// Add a rectangle as a new closed figure.
+    pub fn add_rect(&mut self, rect: Rect) -> Result<()> {
+        self.0.add_rect(fix_rect(rect))?;
+        Ok(())
+    }
+
+    /// Add an ellipse inscribed in the rectangle as a new closed figure.
+    pub fn add_ellipse(&mut self, rect: Rect) -> Result<()> {
+        self.0.add_ellipse(fix_rect(rect))?;
+        Ok(())
+    }
+
     /// Build [`DrawingPath`].
     pub fn build(self, close: bool) -> Result<DrawingPath> {
         Ok(DrawingPath(self.0.build(close)?))
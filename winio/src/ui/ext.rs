@@ -1,4 +1,4 @@
-use winio_primitive::{Color, ColorTheme, Monitor};
+use winio_primitive::{Color, ColorTheme, Monitor, SystemPalette};
 
 use crate::sys::Result;
 
@@ -41,3 +41,30 @@ impl ColorExt for Color {
         crate::sys::accent_color()
     }
 }
+
+/// Extension trait for [`SystemPalette`].
+pub trait SystemPaletteExt: Sized {
+    /// Get the colors of current system theme.
+    ///
+    /// ## Platform specific
+    /// * GTK: The named colors of the theme, read when a window is created or
+    ///   the theme changes. Returns an error before any window is created. The
+    ///   tooltip colors are derived.
+    /// * Qt: The colors of the application palette.
+    /// * Others: Derived from [`ColorThemeExt::current`] and
+    ///   [`ColorExt::accent`].
+    fn current() -> Result<Self>;
+}
+
+impl SystemPaletteExt for SystemPalette {
+    fn current() -> Result<Self> {
+        cfg_if::cfg_if! {
+            if #[cfg(any(gtk, qt))] {
+                crate::sys::system_palette()
+            } else {
+                let accent = Color::accent().unwrap_or(Color::new(0, 120, 212, 255));
+                Ok(SystemPalette::from_theme(ColorTheme::current()?, accent))
+            }
+        }
+    }
+}
@@ -17,6 +17,11 @@ mod plotters;
 #[cfg(feature = "plotters")]
 pub use plotters::*;
 
+#[cfg(feature = "svg")]
+mod svg;
+#[cfg(feature = "svg")]
+pub use svg::*;
+
 #[cfg(feature = "compio-compat")]
 mod file;
 #[cfg(feature = "compio-compat")]
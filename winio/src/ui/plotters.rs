@@ -1,11 +1,11 @@
 use plotters_backend::{
     BackendColor, BackendCoord, BackendStyle, BackendTextStyle, DrawingBackend, DrawingErrorKind,
-    FontStyle, FontTransform,
+    FontFamily, FontStyle, FontTransform,
     text_anchor::{HPos, VPos},
 };
 use winio_primitive::{
-    Angle, BrushPen, Color, Font, FontBuilder, Layoutable, Point, Rect, RectBox, RelativePoint,
-    Rotation, Size, SolidColorBrush, Transform,
+    Angle, BrushPen, Color, Font, FontBuilder, FontWeight, Layoutable, LineCap, LineJoin, Point,
+    Rect, RectBox, RelativePoint, Rotation, Size, SolidColorBrush, StrokeStyle, Transform,
 };
 
 use crate::{Error, ui::DrawingContext, widgets::Canvas};
@@ -14,6 +14,7 @@ use crate::{Error, ui::DrawingContext, widgets::Canvas};
 pub struct WinioCanvasBackend<'a> {
     canvas: &'a mut Canvas,
     size: (u32, u32),
+    clip: Option<Rect>,
     inner: Option<DrawingContext<'a>>,
 }
 
@@ -24,10 +25,22 @@ impl<'a> WinioCanvasBackend<'a> {
         Ok(Self {
             canvas,
             size: (size.width as u32, size.height as u32),
+            clip: None,
             inner: None,
         })
     }
 
+    /// Clip all the drawing into a rectangle of the canvas, e.g. the plotting
+    /// area, so that the series don't overflow it.
+    ///
+    /// ## Platform specific
+    /// * Windows, GTK & Qt: Supported.
+    /// * Others: Not supported, the drawing fails.
+    pub fn with_clip(mut self, clip: Rect) -> Self {
+        self.clip = Some(clip);
+        self
+    }
+
     fn context(&self) -> &DrawingContext<'a> {
         self.inner.as_ref().expect("Context is not prepared")
     }
@@ -58,13 +71,32 @@ fn bpen(style: &impl BackendStyle) -> BrushPen<SolidColorBrush> {
     BrushPen::new(bbrush(style.color()), style.stroke_width() as f64)
 }
 
+/// The pen for the lines and the series. Plotters draws them with round ends
+/// and corners, so that the wide polylines are continuous.
+fn bline(style: &impl BackendStyle) -> BrushPen<SolidColorBrush> {
+    bpen(style).with_stroke(StrokeStyle {
+        cap: LineCap::Round,
+        join: LineJoin::Round,
+        ..StrokeStyle::solid()
+    })
+}
+
 fn bfont(style: &impl BackendTextStyle) -> (Font, RelativePoint) {
+    let family = style.family();
+    // The generic families are not recognized by all backends.
+    let fallback: &[&str] = match family {
+        FontFamily::Serif => &["Times New Roman"],
+        FontFamily::SansSerif => &["Arial"],
+        FontFamily::Monospace => &["Courier New"],
+        FontFamily::Name(_) => &[],
+    };
     let mut builder = FontBuilder::new()
         .size(style.size())
-        .family(style.family().as_str());
+        .family(family.as_str())
+        .fallback(fallback);
     builder = match style.style() {
         FontStyle::Normal => builder,
-        FontStyle::Bold => builder.bold(true),
+        FontStyle::Bold => builder.weight(FontWeight::BOLD),
         FontStyle::Italic => builder.italic(true),
         FontStyle::Oblique => builder.italic(true),
     };
@@ -105,6 +137,11 @@ impl<'a> DrawingBackend for WinioCanvasBackend<'a> {
                         .map_err(DrawingErrorKind::DrawingError)?,
                 )
             });
+            if let Some(clip) = self.clip {
+                self.context_mut()
+                    .push_clip_rect(clip)
+                    .map_err(DrawingErrorKind::DrawingError)?;
+            }
         }
         Ok(())
     }
@@ -133,7 +170,7 @@ impl<'a> DrawingBackend for WinioCanvasBackend<'a> {
         style: &S,
     ) -> Result<(), DrawingErrorKind<Self::ErrorType>> {
         self.context_mut()
-            .draw_line(bpen(style), bpoint(from), bpoint(to))
+            .draw_line(bline(style), bpoint(from), bpoint(to))
             .map_err(DrawingErrorKind::DrawingError)
     }
 
@@ -170,7 +207,7 @@ impl<'a> DrawingBackend for WinioCanvasBackend<'a> {
                 builder.add_line(bpoint(p))?;
             }
             let path = builder.build(false)?;
-            context.draw_path(bpen(style), &path)
+            context.draw_path(bline(style), &path)
         })()
         .map_err(DrawingErrorKind::DrawingError)
     }
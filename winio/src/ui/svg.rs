@@ -0,0 +1,656 @@
+use compio_log::warn;
+use svgtypes::{SimplePathSegment, SimplifyingPathParser};
+use usvg::tiny_skia_path::{self, PathSegment};
+#[cfg(any(windows, gtk, qt))]
+use winio_primitive::BlendMode;
+use winio_primitive::{
+    BrushPen, Color, FillRule, GradientStop, LineCap, LineJoin, LinearGradientBrush, Point,
+    RadialGradientBrush, Rect, RelativePoint, RelativeSize, Size, SolidColorBrush, SpreadMode,
+    StrokeStyle, Transform, Vector,
+};
+
+use crate::{
+    sys::{Error, Result},
+    ui::{DrawingContext, DrawingPath, DrawingPathBuilder},
+};
+
+fn invalid_data(e: impl Into<Box<dyn std::error::Error + Send + Sync>>) -> Error {
+    std::io::Error::new(std::io::ErrorKind::InvalidData, e).into()
+}
+
+/// A parsed SVG document, which could be drawn with
+/// [`DrawingContext::draw_svg`] at any scale without blurring.
+pub struct SvgDocument(usvg::Tree);
+
+impl SvgDocument {
+    /// Parse an SVG document from the content of an `.svg` or `.svgz` file.
+    pub fn from_data(data: &[u8]) -> Result<Self> {
+        usvg::Tree::from_data(data, &usvg::Options::default())
+            .map(Self)
+            .map_err(invalid_data)
+    }
+
+    /// The size of the document.
+    pub fn size(&self) -> Size {
+        let size = self.0.size();
+        Size::new(size.width() as f64, size.height() as f64)
+    }
+}
+
+impl DrawingPath {
+    /// Parse SVG path data, i.e., the `d` attribute of a `<path>` element.
+    pub fn from_svg_path_data(ctx: &DrawingContext, data: &str) -> Result<Self> {
+        let mut writer = PathWriter::new(ctx, Transform::identity());
+        write_path_data(data, &mut writer)?;
+        writer
+            .finish()?
+            .ok_or_else(|| invalid_data("empty path data"))
+    }
+}
+
+/// Receives the segments of a path, in absolute coordinates.
+trait PathSink {
+    fn move_to(&mut self, p: Point) -> Result<()>;
+
+    fn line_to(&mut self, p: Point) -> Result<()>;
+
+    fn quad_to(&mut self, p1: Point, p2: Point) -> Result<()>;
+
+    fn cubic_to(&mut self, p1: Point, p2: Point, p3: Point) -> Result<()>;
+
+    fn close(&mut self) -> Result<()>;
+}
+
+/// Parse SVG path data. The relative, shorthand and arc commands are
+/// converted to the absolute lines and curves.
+fn parse_path_data(data: &str) -> std::result::Result<Vec<SimplePathSegment>, svgtypes::Error> {
+    SimplifyingPathParser::from(data).collect()
+}
+
+/// Parse SVG path data into the sink.
+fn write_path_data(data: &str, sink: &mut impl PathSink) -> Result<()> {
+    for segment in parse_path_data(data).map_err(invalid_data)? {
+        match segment {
+            SimplePathSegment::MoveTo { x, y } => sink.move_to(Point::new(x, y))?,
+            SimplePathSegment::LineTo { x, y } => sink.line_to(Point::new(x, y))?,
+            SimplePathSegment::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => sink.cubic_to(Point::new(x1, y1), Point::new(x2, y2), Point::new(x, y))?,
+            SimplePathSegment::Quadratic { x1, y1, x, y } => {
+                sink.quad_to(Point::new(x1, y1), Point::new(x, y))?
+            }
+            SimplePathSegment::ClosePath => sink.close()?,
+        }
+    }
+    Ok(())
+}
+
+impl DrawingContext<'_> {
+    /// Draw an SVG document, stretched to the rectangle.
+    ///
+    /// The shapes are drawn with the paths and brushes of the context. Pattern
+    /// paints, masks and filters are ignored. The text is drawn only if it has
+    /// been converted to paths. The raster images are decoded with the enabled
+    /// formats of `image`.
+    ///
+    /// ## Platform specific
+    /// * Windows, GTK & Qt: The group opacity, blend modes and clip paths are
+    ///   supported.
+    /// * Others: The group opacity, blend modes and clip paths are ignored.
+    pub fn draw_svg(&mut self, svg: &SvgDocument, rect: Rect) -> Result<()> {
+        let base = self.transform()?;
+        let size = svg.size();
+        let view = Transform::scale(rect.width() / size.width, rect.height() / size.height)
+            .then_translate(rect.origin.to_vector())
+            .then(&base);
+        let res = self.draw_svg_group(svg.0.root(), view);
+        self.set_transform(base)?;
+        res
+    }
+
+    fn draw_svg_group(&mut self, group: &usvg::Group, transform: Transform) -> Result<()> {
+        let transform = svg_transform(group.transform()).then(&transform);
+        #[cfg(any(windows, gtk, qt))]
+        if group.clip_path().is_some()
+            || group.opacity().get() < 1.0
+            || group.blend_mode() != usvg::BlendMode::Normal
+        {
+            self.save()?;
+            let res = self.draw_svg_isolated(group, transform);
+            self.restore()?;
+            return res;
+        }
+        self.draw_svg_children(group, transform)
+    }
+
+    #[cfg(any(windows, gtk, qt))]
+    fn draw_svg_isolated(&mut self, group: &usvg::Group, transform: Transform) -> Result<()> {
+        if let Some(clip) = group.clip_path() {
+            self.push_svg_clip(clip, transform)?;
+        }
+        if group.opacity().get() < 1.0 || group.blend_mode() != usvg::BlendMode::Normal {
+            let opacity = group.opacity().get() as f64;
+            // Fall back to the normal blending if the backend doesn't support
+            // the blend mode.
+            self.push_layer(opacity, svg_blend_mode(group.blend_mode()))
+                .or_else(|_| self.push_layer(opacity, BlendMode::Normal))?;
+        }
+        self.draw_svg_children(group, transform)
+    }
+
+    #[cfg(any(windows, gtk, qt))]
+    fn push_svg_clip(&mut self, clip: &usvg::ClipPath, transform: Transform) -> Result<()> {
+        self.set_transform(transform)?;
+        let mut writer = PathWriter::new(self, svg_transform(clip.transform()));
+        writer.add_group(clip.root())?;
+        match writer.finish()? {
+            Some(path) => self.push_clip_path(&path)?,
+            None => self.push_clip_rect(Rect::zero())?,
+        }
+        if let Some(clip) = clip.clip_path() {
+            self.push_svg_clip(clip, transform)?;
+        }
+        Ok(())
+    }
+
+    fn draw_svg_children(&mut self, group: &usvg::Group, transform: Transform) -> Result<()> {
+        for child in group.children() {
+            match child {
+                usvg::Node::Group(group) => self.draw_svg_group(group, transform)?,
+                usvg::Node::Path(path) => self.draw_svg_path(path, transform)?,
+                usvg::Node::Image(image) => self.draw_svg_image(image, transform)?,
+                usvg::Node::Text(text) => self.draw_svg_group(text.flattened(), transform)?,
+            }
+        }
+        Ok(())
+    }
+
+    fn draw_svg_path(&mut self, path: &usvg::Path, transform: Transform) -> Result<()> {
+        if !path.is_visible() {
+            return Ok(());
+        }
+        self.set_transform(transform)?;
+        let mut writer = PathWriter::new(self, Transform::identity());
+        writer.add_path(path.data())?;
+        let Some(data) = writer.finish()? else {
+            return Ok(());
+        };
+        let bounds = path.bounding_box();
+        let bounds = Rect::new(
+            Point::new(bounds.x() as f64, bounds.y() as f64),
+            Size::new(bounds.width() as f64, bounds.height() as f64),
+        );
+        if path.paint_order() == usvg::PaintOrder::FillAndStroke {
+            self.fill_svg_path(path.fill(), &data, bounds)?;
+            self.stroke_svg_path(path.stroke(), &data, bounds)
+        } else {
+            self.stroke_svg_path(path.stroke(), &data, bounds)?;
+            self.fill_svg_path(path.fill(), &data, bounds)
+        }
+    }
+
+    fn fill_svg_path(
+        &mut self,
+        fill: Option<&usvg::Fill>,
+        path: &DrawingPath,
+        bounds: Rect,
+    ) -> Result<()> {
+        let Some(fill) = fill else {
+            return Ok(());
+        };
+        let rule = match fill.rule() {
+            usvg::FillRule::NonZero => FillRule::NonZero,
+            usvg::FillRule::EvenOdd => FillRule::EvenOdd,
+        };
+        match SvgBrush::new(fill.paint(), fill.opacity(), bounds) {
+            Some(SvgBrush::Solid(brush)) => self.fill_path_with_rule(brush, path, rule),
+            Some(SvgBrush::Linear(brush)) => self.fill_path_with_rule(brush, path, rule),
+            Some(SvgBrush::Radial(brush)) => self.fill_path_with_rule(brush, path, rule),
+            None => Ok(()),
+        }
+    }
+
+    fn stroke_svg_path(
+        &mut self,
+        stroke: Option<&usvg::Stroke>,
+        path: &DrawingPath,
+        bounds: Rect,
+    ) -> Result<()> {
+        let Some(stroke) = stroke else {
+            return Ok(());
+        };
+        let width = stroke.width().get() as f64;
+        let mut style = StrokeStyle::solid();
+        style.cap = match stroke.linecap() {
+            usvg::LineCap::Butt => LineCap::Butt,
+            usvg::LineCap::Round => LineCap::Round,
+            usvg::LineCap::Square => LineCap::Square,
+        };
+        style.join = match stroke.linejoin() {
+            usvg::LineJoin::Miter | usvg::LineJoin::MiterClip => LineJoin::Miter,
+            usvg::LineJoin::Round => LineJoin::Round,
+            usvg::LineJoin::Bevel => LineJoin::Bevel,
+        };
+        style.miter_limit = stroke.miterlimit().get() as f64;
+        // The dashes of SVG are absolute lengths.
+        if let Some(dashes) = stroke.dasharray() {
+            style.dashes = dashes.iter().map(|d| *d as f64 / width).collect();
+            style.dash_offset = stroke.dashoffset() as f64 / width;
+        }
+        match SvgBrush::new(stroke.paint(), stroke.opacity(), bounds) {
+            Some(SvgBrush::Solid(brush)) => {
+                self.draw_path(BrushPen::new(brush, width).with_stroke(style), path)
+            }
+            Some(SvgBrush::Linear(brush)) => {
+                self.draw_path(BrushPen::new(brush, width).with_stroke(style), path)
+            }
+            Some(SvgBrush::Radial(brush)) => {
+                self.draw_path(BrushPen::new(brush, width).with_stroke(style), path)
+            }
+            None => Ok(()),
+        }
+    }
+
+    fn draw_svg_image(&mut self, image: &usvg::Image, transform: Transform) -> Result<()> {
+        if !image.is_visible() {
+            return Ok(());
+        }
+        let data = match image.kind() {
+            usvg::ImageKind::SVG(tree) => return self.draw_svg_group(tree.root(), transform),
+            usvg::ImageKind::JPEG(data)
+            | usvg::ImageKind::PNG(data)
+            | usvg::ImageKind::GIF(data)
+            | usvg::ImageKind::WEBP(data) => data,
+        };
+        match image::load_from_memory(data) {
+            Ok(decoded) => {
+                let size = image.size();
+                let decoded = self.create_image(decoded)?;
+                self.set_transform(transform)?;
+                self.draw_image(
+                    &decoded,
+                    Rect::new(
+                        Point::zero(),
+                        Size::new(size.width() as f64, size.height() as f64),
+                    ),
+                    None,
+                )
+            }
+            Err(_e) => {
+                warn!("Cannot decode the image in SVG: {_e:?}");
+                Ok(())
+            }
+        }
+    }
+}
+
+/// Feeds the segments into a [`DrawingPathBuilder`], which is created from the
+/// first point.
+struct PathWriter<'a, 'b> {
+    ctx: &'a DrawingContext<'b>,
+    transform: Transform,
+    builder: Option<DrawingPathBuilder>,
+}
+
+impl<'a, 'b> PathWriter<'a, 'b> {
+    fn new(ctx: &'a DrawingContext<'b>, transform: Transform) -> Self {
+        Self {
+            ctx,
+            transform,
+            builder: None,
+        }
+    }
+
+    fn builder(&mut self) -> Result<&mut DrawingPathBuilder> {
+        self.builder
+            .as_mut()
+            .ok_or_else(|| invalid_data("the path doesn't start with a move"))
+    }
+
+    fn add_path(&mut self, path: &tiny_skia_path::Path) -> Result<()> {
+        let point = |p: tiny_skia_path::Point| Point::new(p.x as f64, p.y as f64);
+        for segment in path.segments() {
+            match segment {
+                PathSegment::MoveTo(p) => self.move_to(point(p))?,
+                PathSegment::LineTo(p) => self.line_to(point(p))?,
+                PathSegment::QuadTo(p1, p2) => self.quad_to(point(p1), point(p2))?,
+                PathSegment::CubicTo(p1, p2, p3) => {
+                    self.cubic_to(point(p1), point(p2), point(p3))?
+                }
+                PathSegment::Close => self.close()?,
+            }
+        }
+        Ok(())
+    }
+
+    /// Add all paths in the group, e.g., the content of a clip path.
+    #[cfg(any(windows, gtk, qt))]
+    fn add_group(&mut self, group: &usvg::Group) -> Result<()> {
+        let parent = self.transform;
+        self.transform = svg_transform(group.transform()).then(&parent);
+        for child in group.children() {
+            match child {
+                usvg::Node::Group(group) => self.add_group(group)?,
+                usvg::Node::Path(path) if path.is_visible() => self.add_path(path.data())?,
+                usvg::Node::Text(text) => self.add_group(text.flattened())?,
+                _ => {}
+            }
+        }
+        self.transform = parent;
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Option<DrawingPath>> {
+        self.builder.map(|builder| builder.build(false)).transpose()
+    }
+}
+
+impl PathSink for PathWriter<'_, '_> {
+    fn move_to(&mut self, p: Point) -> Result<()> {
+        let p = self.transform.transform_point(p);
+        match &mut self.builder {
+            Some(builder) => builder.move_to(p),
+            None => {
+                self.builder = Some(self.ctx.create_path_builder(p)?);
+                Ok(())
+            }
+        }
+    }
+
+    fn line_to(&mut self, p: Point) -> Result<()> {
+        let p = self.transform.transform_point(p);
+        self.builder()?.add_line(p)
+    }
+
+    fn quad_to(&mut self, p1: Point, p2: Point) -> Result<()> {
+        let p1 = self.transform.transform_point(p1);
+        let p2 = self.transform.transform_point(p2);
+        self.builder()?.add_quad(p1, p2)
+    }
+
+    fn cubic_to(&mut self, p1: Point, p2: Point, p3: Point) -> Result<()> {
+        let p1 = self.transform.transform_point(p1);
+        let p2 = self.transform.transform_point(p2);
+        let p3 = self.transform.transform_point(p3);
+        self.builder()?.add_bezier(p1, p2, p3)
+    }
+
+    fn close(&mut self) -> Result<()> {
+        self.builder()?.close_figure()
+    }
+}
+
+enum SvgBrush {
+    Solid(SolidColorBrush),
+    Linear(LinearGradientBrush),
+    Radial(RadialGradientBrush),
+}
+
+impl SvgBrush {
+    /// Create the brush of a paint. The gradients are positioned relative to
+    /// the bounds of the path.
+    fn new(paint: &usvg::Paint, opacity: usvg::Opacity, bounds: Rect) -> Option<Self> {
+        let opacity = opacity.get() as f64;
+        let relative = |v: f64, len: f64| if len > 0.0 { v / len } else { 0.0 };
+        let relative_point = |p: Point| {
+            RelativePoint::new(
+                relative(p.x - bounds.origin.x, bounds.width()),
+                relative(p.y - bounds.origin.y, bounds.height()),
+            )
+        };
+        match paint {
+            usvg::Paint::Color(c) => Some(Self::Solid(
+                SolidColorBrush::new(Color::new(c.red, c.green, c.blue, 255)).with_opacity(opacity),
+            )),
+            usvg::Paint::LinearGradient(g) => {
+                let transform = svg_transform(g.transform());
+                let start = transform.transform_point(Point::new(g.x1() as f64, g.y1() as f64));
+                let end = transform.transform_point(Point::new(g.x2() as f64, g.y2() as f64));
+                Some(Self::Linear(
+                    LinearGradientBrush::new(
+                        svg_stops(g.stops()),
+                        relative_point(start),
+                        relative_point(end),
+                    )
+                    .with_spread(svg_spread(g.spread_method()))
+                    .with_opacity(opacity),
+                ))
+            }
+            usvg::Paint::RadialGradient(g) => {
+                let transform = svg_transform(g.transform());
+                let origin = transform.transform_point(Point::new(g.fx() as f64, g.fy() as f64));
+                let center = transform.transform_point(Point::new(g.cx() as f64, g.cy() as f64));
+                // Only the scale of the transform is kept for the radius.
+                let r = g.r().get() as f64;
+                let rx = r * Vector::new(transform.m11, transform.m12).length();
+                let ry = r * Vector::new(transform.m21, transform.m22).length();
+                Some(Self::Radial(
+                    RadialGradientBrush::new(
+                        svg_stops(g.stops()),
+                        relative_point(origin),
+                        relative_point(center),
+                        RelativeSize::new(
+                            relative(rx, bounds.width()),
+                            relative(ry, bounds.height()),
+                        ),
+                    )
+                    .with_spread(svg_spread(g.spread_method()))
+                    .with_opacity(opacity),
+                ))
+            }
+            usvg::Paint::Pattern(_) => None,
+        }
+    }
+}
+
+fn svg_transform(t: tiny_skia_path::Transform) -> Transform {
+    Transform::new(
+        t.sx as f64,
+        t.ky as f64,
+        t.kx as f64,
+        t.sy as f64,
+        t.tx as f64,
+        t.ty as f64,
+    )
+}
+
+fn svg_stops(stops: &[usvg::Stop]) -> Vec<GradientStop> {
+    stops
+        .iter()
+        .map(|stop| {
+            let c = stop.color();
+            let alpha = (stop.opacity().get() * 255.0).round() as u8;
+            GradientStop::new(
+                Color::new(c.red, c.green, c.blue, alpha),
+                stop.offset().get() as f64,
+            )
+        })
+        .collect()
+}
+
+fn svg_spread(spread: usvg::SpreadMethod) -> SpreadMode {
+    match spread {
+        usvg::SpreadMethod::Pad => SpreadMode::Pad,
+        usvg::SpreadMethod::Reflect => SpreadMode::Reflect,
+        usvg::SpreadMethod::Repeat => SpreadMode::Repeat,
+    }
+}
+
+#[cfg(any(windows, gtk, qt))]
+fn svg_blend_mode(mode: usvg::BlendMode) -> BlendMode {
+    match mode {
+        usvg::BlendMode::Multiply => BlendMode::Multiply,
+        usvg::BlendMode::Screen => BlendMode::Screen,
+        usvg::BlendMode::Overlay => BlendMode::Overlay,
+        usvg::BlendMode::Darken => BlendMode::Darken,
+        usvg::BlendMode::Lighten => BlendMode::Lighten,
+        usvg::BlendMode::ColorDodge => BlendMode::ColorDodge,
+        usvg::BlendMode::ColorBurn => BlendMode::ColorBurn,
+        usvg::BlendMode::HardLight => BlendMode::HardLight,
+        usvg::BlendMode::SoftLight => BlendMode::SoftLight,
+        usvg::BlendMode::Difference => BlendMode::Difference,
+        usvg::BlendMode::Exclusion => BlendMode::Exclusion,
+        // The non-separable modes are not supported.
+        _ => BlendMode::Normal,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    enum Segment {
+        Move(Point),
+        Line(Point),
+        Quad(Point, Point),
+        Cubic(Point, Point, Point),
+        Close,
+    }
+
+    #[derive(Default)]
+    struct Recorder(Vec<Segment>);
+
+    impl PathSink for Recorder {
+        fn move_to(&mut self, p: Point) -> Result<()> {
+            self.0.push(Segment::Move(p));
+            Ok(())
+        }
+
+        fn line_to(&mut self, p: Point) -> Result<()> {
+            self.0.push(Segment::Line(p));
+            Ok(())
+        }
+
+        fn quad_to(&mut self, p1: Point, p2: Point) -> Result<()> {
+            self.0.push(Segment::Quad(p1, p2));
+            Ok(())
+        }
+
+        fn cubic_to(&mut self, p1: Point, p2: Point, p3: Point) -> Result<()> {
+            self.0.push(Segment::Cubic(p1, p2, p3));
+            Ok(())
+        }
+
+        fn close(&mut self) -> Result<()> {
+            self.0.push(Segment::Close);
+            Ok(())
+        }
+    }
+
+    fn parse(data: &str) -> Result<Vec<Segment>> {
+        let mut recorder = Recorder::default();
+        write_path_data(data, &mut recorder)?;
+        Ok(recorder.0)
+    }
+
+    fn p(x: f64, y: f64) -> Point {
+        Point::new(x, y)
+    }
+
+    #[test]
+    fn lines() {
+        let expected = vec![
+            Segment::Move(p(10.0, 20.0)),
+            Segment::Line(p(30.0, 40.0)),
+            Segment::Line(p(50.0, 40.0)),
+            Segment::Line(p(50.0, 60.0)),
+            Segment::Close,
+        ];
+        assert_eq!(parse("M10 20 L30 40 H50 V60 Z").unwrap(), expected);
+        assert_eq!(parse("m10 20 l20 20 h20 v20 z").unwrap(), expected);
+        // The implicit lines after a move.
+        assert_eq!(
+            parse("m10 20 20 20").unwrap(),
+            vec![Segment::Move(p(10.0, 20.0)), Segment::Line(p(30.0, 40.0))]
+        );
+    }
+
+    #[test]
+    fn cubic() {
+        let expected = vec![
+            Segment::Move(p(0.0, 0.0)),
+            Segment::Cubic(p(10.0, 0.0), p(20.0, 10.0), p(20.0, 20.0)),
+            // The first control point is reflected.
+            Segment::Cubic(p(20.0, 30.0), p(30.0, 40.0), p(40.0, 40.0)),
+        ];
+        assert_eq!(
+            parse("M0 0 C10 0 20 10 20 20 S30 40 40 40").unwrap(),
+            expected
+        );
+        assert_eq!(
+            parse("m0 0 c10 0 20 10 20 20 s10 20 20 20").unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn quad() {
+        let expected = vec![
+            Segment::Move(p(0.0, 0.0)),
+            Segment::Quad(p(10.0, 0.0), p(10.0, 10.0)),
+            // The control point is reflected.
+            Segment::Quad(p(10.0, 20.0), p(20.0, 20.0)),
+        ];
+        assert_eq!(parse("M0 0 Q10 0 10 10 T20 20").unwrap(), expected);
+        assert_eq!(parse("m0 0 q10 0 10 10 t10 10").unwrap(), expected);
+    }
+
+    #[test]
+    fn arc() {
+        let near = |a: Point, b: Point| (a - b).length() < 1e-6;
+        for data in ["M0 0 A10 10 0 0 1 20 0", "m0 0 a10 10 0 0 1 20 0"] {
+            let segments = parse(data).unwrap();
+            assert_eq!(segments[0], Segment::Move(p(0.0, 0.0)));
+            // The arc is converted to cubic curves through the top of the
+            // circle.
+            let ends = segments[1..]
+                .iter()
+                .map(|s| match s {
+                    Segment::Cubic(_, _, end) => *end,
+                    s => panic!("unexpected segment {s:?}"),
+                })
+                .collect::<Vec<_>>();
+            assert!(ends.iter().any(|&end| near(end, p(10.0, -10.0))));
+            assert!(near(*ends.last().unwrap(), p(20.0, 0.0)));
+        }
+    }
+
+    #[test]
+    fn close_then_line() {
+        // A command after closing starts from the start of the subpath.
+        assert_eq!(
+            parse("M10 10 Z L20 20").unwrap(),
+            vec![
+                Segment::Move(p(10.0, 10.0)),
+                Segment::Close,
+                Segment::Move(p(10.0, 10.0)),
+                Segment::Line(p(20.0, 20.0)),
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed() {
+        // The parse errors are checked before converting to the backend
+        // error, which can't be constructed on the stub backend.
+        assert!(parse_path_data("").unwrap().is_empty());
+        for data in [
+            "M10",
+            "L10 10",
+            "M10 10 L20",
+            "M10 10 X20",
+            "M10 10 A5 5 0 2 0 20 20",
+        ] {
+            assert!(
+                parse_path_data(data).is_err(),
+                "{data:?} should be rejected"
+            );
+        }
+    }
+}
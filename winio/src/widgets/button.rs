@@ -50,6 +50,8 @@ impl Enable for Button {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for Button {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -59,6 +61,9 @@ impl Layoutable for Button {
     fn set_size(&mut self, s: Size) -> Result<()>;
 
     fn preferred_size(&self) -> Result<Size>;
+
+    #[cfg(any(gtk, qt))]
+    fn baseline(&self) -> Result<Option<f64>>;
 }
 
 /// Events of [`Button`].
@@ -1,8 +1,11 @@
+use std::cell::Cell;
+
 use inherit_methods_macro::inherit_methods;
 use winio_elm::{Component, ComponentSender};
 use winio_handle::BorrowedContainer;
 use winio_primitive::{
-    Enable, Failable, Layoutable, MouseButton, Point, Rect, Size, ToolTip, Vector, Visible,
+    Cursor, Enable, Failable, Layoutable, MouseButton, MouseButtonEvent, Point, Rect, Size,
+    ToolTip, Vector, Visible,
 };
 
 use crate::{
@@ -17,15 +20,61 @@ use crate::{
 /// It doesn't ensure hardware acceleration, so it might be slow on some
 /// platforms. [`WgpuCanvas`](crate::widgets::WgpuCanvas) is a better choice if
 /// you need hardware acceleration.
+///
+/// The content is double buffered by the backend. Listen to
+/// [`CanvasEvent::Paint`] to redraw when the canvas is exposed, resized or
+/// invalidated.
 #[derive(Debug)]
 pub struct Canvas {
     widget: sys::Canvas,
+    dirty: Cell<Option<Rect>>,
 }
 
 impl Canvas {
     /// Create the [`DrawingContext`] of the current canvas.
+    ///
+    /// If the canvas has been invalidated, the context is clipped to the dirty
+    /// rectangle, which is then cleared.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: The content outside the dirty rectangle is kept.
+    /// * Others: The whole canvas should be redrawn.
     pub fn context(&mut self) -> Result<DrawingContext<'_>> {
-        Ok(DrawingContext::new(self.widget.context()?))
+        let dirty = self.dirty.take();
+        Ok(DrawingContext::new(self.widget.context(dirty)?))
+    }
+
+    /// Mark a rectangle of the canvas as dirty, and request a
+    /// [`CanvasEvent::Paint`]. `None` means the whole canvas.
+    pub fn invalidate(&mut self, rect: Option<Rect>) -> Result<()> {
+        let bounds = Rect::from_size(self.widget.size()?);
+        let rect = match rect {
+            Some(rect) => match rect.intersection(&bounds) {
+                Some(rect) => rect,
+                None => return Ok(()),
+            },
+            None => bounds,
+        };
+        self.add_dirty(rect);
+        self.widget.invalidate(rect)
+    }
+
+    fn add_dirty(&self, rect: Rect) -> Rect {
+        let rect = match self.dirty.get() {
+            Some(dirty) => dirty.union(&rect),
+            None => rect,
+        };
+        self.dirty.set(Some(rect));
+        rect
+    }
+
+    /// Set the cursor shape when the pointer is over the canvas.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Supported.
+    /// * Others: Not supported.
+    pub fn set_cursor(&mut self, cursor: Cursor) -> Result<()> {
+        self.widget.set_cursor(cursor)
     }
 }
 
@@ -56,13 +105,22 @@ impl Enable for Canvas {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for Canvas {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
 
     fn size(&self) -> Result<Size>;
 
-    fn set_size(&mut self, s: Size) -> Result<()>;
+    fn set_size(&mut self, s: Size) -> Result<()> {
+        let changed = self.widget.size()? != s;
+        self.widget.set_size(s)?;
+        if changed {
+            self.invalidate(None)?;
+        }
+        Ok(())
+    }
 }
 
 /// Events of [`Canvas`].
@@ -71,14 +129,52 @@ impl Layoutable for Canvas {
 pub enum CanvasEvent {
     /// The mouse moves.
     MouseMove(Point),
-    /// The mouse button pressed down.
+    /// The mouse button pressed down. Prefer [`Self::MouseButtonDown`] where
+    /// it is emitted, which also carries the position, the modifiers and the
+    /// click count.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Emitted together with [`Self::MouseButtonDown`].
     MouseDown(MouseButton),
-    /// The mouse button released.
+    /// The mouse button released. Prefer [`Self::MouseButtonUp`] where it is
+    /// emitted.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Emitted together with [`Self::MouseButtonUp`].
     MouseUp(MouseButton),
     /// The mouse wheel rotated.
     /// * `x`: Positive is right.
     /// * `y`: Positive is up/forward.
     MouseWheel(Vector),
+    /// The mouse button pressed down, with the position, the keyboard
+    /// modifiers and the click count. A double click reports `clicks == 2`.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Supported.
+    /// * Others: Never emitted.
+    MouseButtonDown(MouseButtonEvent),
+    /// The mouse button released, with the same details as
+    /// [`Self::MouseButtonDown`].
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Supported.
+    /// * Others: Never emitted.
+    MouseButtonUp(MouseButtonEvent),
+    /// The pointer enters the widget at the position.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Supported.
+    /// * Others: Never emitted.
+    MouseEnter(Point),
+    /// The pointer leaves the widget.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Supported.
+    /// * Others: Never emitted.
+    MouseLeave,
+    /// The canvas needs to be redrawn. The rectangle is the dirty area
+    /// accumulated since the last [`Canvas::context`].
+    Paint(Rect),
 }
 
 /// Messages of [`Canvas`].
@@ -103,7 +199,10 @@ impl Component for Canvas {
 
     async fn init(init: Self::Init<'_>, _sender: &ComponentSender<Self>) -> Result<Self> {
         let widget = sys::Canvas::new(init)?;
-        Ok(Self { widget })
+        Ok(Self {
+            widget,
+            dirty: Cell::new(None),
+        })
     }
 
     async fn start(&mut self, sender: &ComponentSender<Self>) -> ! {
@@ -131,9 +230,51 @@ impl Component for Canvas {
                 sender.output(CanvasEvent::MouseWheel(w));
             }
         };
-        futures_util::future::join4(fut_move, fut_down, fut_up, fut_wheel)
-            .await
-            .0
+        let fut_paint = async {
+            loop {
+                let rect = self.widget.wait_paint().await;
+                sender.output(CanvasEvent::Paint(self.add_dirty(rect)));
+            }
+        };
+        #[cfg(any(gtk, qt))]
+        let fut_ext = async {
+            let fut_button_down = async {
+                loop {
+                    let e = self.widget.wait_mouse_button_down().await;
+                    sender.output(CanvasEvent::MouseButtonDown(e));
+                }
+            };
+            let fut_button_up = async {
+                loop {
+                    let e = self.widget.wait_mouse_button_up().await;
+                    sender.output(CanvasEvent::MouseButtonUp(e));
+                }
+            };
+            let fut_enter = async {
+                loop {
+                    let p = self.widget.wait_mouse_enter().await;
+                    sender.output(CanvasEvent::MouseEnter(p));
+                }
+            };
+            let fut_leave = async {
+                loop {
+                    self.widget.wait_mouse_leave().await;
+                    sender.output(CanvasEvent::MouseLeave);
+                }
+            };
+            futures_util::future::join4(fut_button_down, fut_button_up, fut_enter, fut_leave)
+                .await
+                .0
+        };
+        #[cfg(not(any(gtk, qt)))]
+        let fut_ext = std::future::pending::<()>();
+        futures_util::future::join(
+            futures_util::future::join5(fut_move, fut_down, fut_up, fut_wheel, fut_paint),
+            fut_ext,
+        )
+        .await
+        .0
+        .0
     }
 
     async fn update(
@@ -73,6 +73,8 @@ impl Enable for CheckBox {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for CheckBox {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -82,6 +84,9 @@ impl Layoutable for CheckBox {
     fn set_size(&mut self, s: Size) -> Result<()>;
 
     fn preferred_size(&self) -> Result<Size>;
+
+    #[cfg(any(gtk, qt))]
+    fn baseline(&self) -> Result<Option<f64>>;
 }
 
 /// Events of [`CheckBox`].
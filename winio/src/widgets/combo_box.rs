@@ -132,6 +132,8 @@ impl Enable for ComboBox {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for ComboBox {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -86,6 +86,8 @@ impl Enable for Edit {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for Edit {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -95,6 +97,9 @@ impl Layoutable for Edit {
     fn set_size(&mut self, s: Size) -> Result<()>;
 
     fn preferred_size(&self) -> Result<Size>;
+
+    #[cfg(any(gtk, qt))]
+    fn baseline(&self) -> Result<Option<f64>>;
 }
 
 /// Events of [`Edit`].
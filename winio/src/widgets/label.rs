@@ -75,6 +75,8 @@ impl Enable for Label {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for Label {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -84,6 +86,9 @@ impl Layoutable for Label {
     fn set_size(&mut self, s: Size) -> Result<()>;
 
     fn preferred_size(&self) -> Result<Size>;
+
+    #[cfg(any(gtk, qt))]
+    fn baseline(&self) -> Result<Option<f64>>;
 }
 
 /// Events of [`Label`].
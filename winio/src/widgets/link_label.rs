@@ -79,6 +79,8 @@ impl Enable for LinkLabel {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for LinkLabel {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -88,6 +90,9 @@ impl Layoutable for LinkLabel {
     fn set_size(&mut self, s: Size) -> Result<()>;
 
     fn preferred_size(&self) -> Result<Size>;
+
+    #[cfg(any(gtk, qt))]
+    fn baseline(&self) -> Result<Option<f64>>;
 }
 
 /// Events of [`LinkLabel`].
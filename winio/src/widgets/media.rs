@@ -104,6 +104,8 @@ impl Enable for Media {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for Media {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
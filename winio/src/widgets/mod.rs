@@ -61,6 +61,9 @@ pub use webview::*;
 mod tab_view;
 pub use tab_view::*;
 
+mod splitter;
+pub use splitter::*;
+
 #[cfg(feature = "wgpu")]
 mod wgpu;
 #[cfg(feature = "wgpu")]
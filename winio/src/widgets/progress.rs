@@ -68,6 +68,8 @@ impl Enable for Progress {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for Progress {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -74,6 +74,8 @@ impl Enable for RadioButton {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for RadioButton {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -83,6 +85,9 @@ impl Layoutable for RadioButton {
     fn set_size(&mut self, s: Size) -> Result<()>;
 
     fn preferred_size(&self) -> Result<Size>;
+
+    #[cfg(any(gtk, qt))]
+    fn baseline(&self) -> Result<Option<f64>>;
 }
 
 /// Events of [`RadioButton`].
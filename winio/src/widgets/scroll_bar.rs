@@ -86,6 +86,8 @@ impl Enable for ScrollBar {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for ScrollBar {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -49,6 +49,8 @@ impl Enable for ScrollView {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for ScrollView {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
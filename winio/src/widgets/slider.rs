@@ -94,6 +94,8 @@ impl Enable for Slider {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for Slider {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -0,0 +1,231 @@
+use inherit_methods_macro::inherit_methods;
+use winio_elm::{Component, ComponentSender, Prop, PropSource};
+use winio_handle::BorrowedContainer;
+use winio_primitive::{Enable, Failable, Layoutable, Orient, Point, Rect, Size, Visible};
+
+use crate::sys::{Error, Result};
+
+#[cfg(any(test, not(any(gtk, qt))))]
+mod geometry;
+
+cfg_if::cfg_if! {
+    if #[cfg(any(gtk, qt))] {
+        use crate::sys::Splitter as SplitterImpl;
+    } else {
+        mod fallback;
+        use fallback::Splitter as SplitterImpl;
+    }
+}
+
+/// Divides the space into panes, which can be resized by dragging the
+/// dividers between them.
+///
+/// Each pane is a container, see [`Splitter::pane`].
+///
+/// ## Platform specific
+/// * GTK: The panes are held by nested `GtkPaned`s.
+/// * Qt: `QSplitter`.
+/// * Others: The dividers are drawn with canvases.
+#[derive(Debug)]
+pub struct Splitter {
+    widget: SplitterImpl,
+    pos_prop: PropSource<Vec<f64>>,
+}
+
+impl Failable for Splitter {
+    type Error = Error;
+}
+
+#[inherit_methods(from = "self.widget")]
+impl Splitter {
+    /// The orientation.
+    pub fn orient(&self) -> Result<Orient>;
+
+    /// Set the orientation.
+    pub fn set_orient(&mut self, v: Orient) -> Result<()>;
+
+    /// The count of the panes.
+    pub fn len(&self) -> Result<usize>;
+
+    /// If there is no pane.
+    pub fn is_empty(&self) -> Result<bool>;
+
+    /// Insert a new pane. Panics if `i` is greater than the count of the
+    /// panes.
+    pub fn insert(&mut self, i: usize) -> Result<()> {
+        let len = self.len()?;
+        assert!(i <= len, "pane index {i} out of range for {len} panes");
+        self.widget.insert(i)
+    }
+
+    /// Push a new pane to the end.
+    pub fn push(&mut self) -> Result<()> {
+        self.insert(self.len()?)
+    }
+
+    /// Remove a pane by index. The widgets in the pane are destroyed. Panics
+    /// if the index is out of range.
+    pub fn remove(&mut self, i: usize) -> Result<()> {
+        let len = self.len()?;
+        assert!(i < len, "pane index {i} out of range for {len} panes");
+        self.widget.remove(i)
+    }
+
+    /// Remove all the panes.
+    pub fn clear(&mut self) -> Result<()> {
+        while !self.is_empty()? {
+            self.remove(0)?;
+        }
+        Ok(())
+    }
+
+    /// The container of a pane. Panics if the index is out of range.
+    pub fn pane(&self, i: usize) -> BorrowedContainer<'_>;
+
+    /// The size of a pane. Panics if the index is out of range.
+    pub fn pane_size(&self, i: usize) -> Result<Size>;
+
+    /// The min size of a pane. The dividers can't be dragged to make the pane
+    /// smaller.
+    pub fn pane_min_size(&self, i: usize) -> Result<Size>;
+
+    /// Set the min size of a pane.
+    pub fn set_pane_min_size(&mut self, i: usize, s: Size) -> Result<()>;
+
+    /// The positions of the dividers, from the start of the splitter along
+    /// the orientation.
+    pub fn pos(&self) -> Result<Vec<f64>>;
+
+    /// Set the positions of the dividers. Panics if the count of the
+    /// positions is not the count of the dividers, i.e., one less than the
+    /// count of the panes.
+    pub fn set_pos(&mut self, pos: &[f64]) -> Result<()> {
+        let len = self.len()?;
+        assert_eq!(
+            pos.len(),
+            len.saturating_sub(1),
+            "wrong count of divider positions"
+        );
+        if pos != self.pos()? {
+            self.widget.set_pos(pos)?;
+            self.pos_prop.notify(self.pos()?);
+        }
+        Ok(())
+    }
+
+    /// Property for [`Splitter::pos`].
+    pub fn pos_prop(&mut self) -> Result<Prop<'_, Vec<f64>>> {
+        Ok(self.pos_prop.as_prop(self.pos()?))
+    }
+}
+
+#[inherit_methods(from = "self.widget")]
+impl Visible for Splitter {
+    fn is_visible(&self) -> Result<bool>;
+
+    fn set_visible(&mut self, v: bool) -> Result<()>;
+}
+
+#[inherit_methods(from = "self.widget")]
+impl Enable for Splitter {
+    fn is_enabled(&self) -> Result<bool>;
+
+    fn set_enabled(&mut self, v: bool) -> Result<()>;
+}
+
+#[inherit_methods(from = "self.widget")]
+impl Layoutable for Splitter {
+    winio_primitive::impl_layout_visible!();
+
+    fn loc(&self) -> Result<Point>;
+
+    fn set_loc(&mut self, p: Point) -> Result<()>;
+
+    fn size(&self) -> Result<Size>;
+
+    fn set_size(&mut self, s: Size) -> Result<()>;
+}
+
+/// Events of [`Splitter`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SplitterEvent {
+    /// The dividers have been dragged.
+    Change,
+}
+
+/// Messages of [`Splitter`].
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum SplitterMessage {
+    /// The dividers have been dragged by user input.
+    #[doc(hidden)]
+    ChangeInputPos,
+    /// Set the positions of the dividers.
+    SetPos(Vec<f64>),
+    /// Set the orientation.
+    SetOrient(Orient),
+    /// Set the rect.
+    SetRect(Rect),
+    /// Set the enabled state.
+    SetEnabled(bool),
+    /// Set the visible state.
+    SetVisible(bool),
+}
+
+impl Component for Splitter {
+    type Error = Error;
+    type Event = SplitterEvent;
+    type Init<'a> = BorrowedContainer<'a>;
+    type Message = SplitterMessage;
+
+    async fn init(init: Self::Init<'_>, _sender: &ComponentSender<Self>) -> Result<Self> {
+        let widget = SplitterImpl::new(init)?;
+        let pos_prop = PropSource::new();
+        Ok(Self { widget, pos_prop })
+    }
+
+    async fn start(&mut self, sender: &ComponentSender<Self>) -> ! {
+        loop {
+            self.widget.wait_change().await;
+            sender.post(SplitterMessage::ChangeInputPos);
+        }
+    }
+
+    async fn update(
+        &mut self,
+        message: Self::Message,
+        sender: &ComponentSender<Self>,
+    ) -> Result<bool> {
+        match message {
+            SplitterMessage::ChangeInputPos => {
+                let pos = self.widget.pos()?;
+                self.pos_prop.notify(pos);
+                sender.output(SplitterEvent::Change);
+                Ok(false)
+            }
+            SplitterMessage::SetPos(pos) => {
+                self.set_pos(&pos)?;
+                Ok(true)
+            }
+            SplitterMessage::SetOrient(orient) => {
+                self.set_orient(orient)?;
+                Ok(true)
+            }
+            SplitterMessage::SetRect(rect) => {
+                self.set_rect(rect)?;
+                Ok(true)
+            }
+            SplitterMessage::SetEnabled(enabled) => {
+                self.set_enabled(enabled)?;
+                Ok(false)
+            }
+            SplitterMessage::SetVisible(visible) => {
+                self.set_visible(visible)?;
+                Ok(true)
+            }
+        }
+    }
+}
+
+winio_handle::impl_as_widget!(Splitter, widget);
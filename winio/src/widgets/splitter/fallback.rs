@@ -0,0 +1,304 @@
+use std::pin::pin;
+
+use compio_log::error;
+use futures_util::future::{Either, select, select_all};
+use winio_handle::{AsContainer, BorrowedContainer};
+use winio_primitive::{Color, MouseButton, Orient, Point, Rect, Size, SolidColorBrush};
+
+use super::geometry::{along, clamp_pos, even_pos, pane_spans, scale_pos, slot};
+use crate::{sys, sys::Result, ui::DrawingContext};
+
+/// The thickness of the dividers.
+const HANDLE: f64 = 6.0;
+
+#[derive(Debug)]
+struct Pane {
+    view: sys::View,
+    min_size: Size,
+}
+
+/// A splitter built with views and canvases, for the platforms without a
+/// native one. The dividers are canvases between the panes.
+#[derive(Debug)]
+pub struct Splitter {
+    view: sys::View,
+    orient: Orient,
+    enabled: bool,
+    panes: Vec<Pane>,
+    dividers: Vec<sys::Canvas>,
+    pos: Vec<f64>,
+    drag: Option<usize>,
+}
+
+impl Splitter {
+    pub fn new(parent: impl AsContainer) -> Result<Self> {
+        let view = sys::View::new(parent)?;
+        Ok(Self {
+            view,
+            orient: Orient::Horizontal,
+            enabled: true,
+            panes: vec![],
+            dividers: vec![],
+            pos: vec![],
+            drag: None,
+        })
+    }
+
+    pub fn is_visible(&self) -> Result<bool> {
+        self.view.is_visible()
+    }
+
+    pub fn set_visible(&mut self, v: bool) -> Result<()> {
+        self.view.set_visible(v)
+    }
+
+    pub fn is_enabled(&self) -> Result<bool> {
+        Ok(self.enabled)
+    }
+
+    pub fn set_enabled(&mut self, v: bool) -> Result<()> {
+        for divider in &mut self.dividers {
+            divider.set_enabled(v)?;
+        }
+        self.enabled = v;
+        Ok(())
+    }
+
+    pub fn loc(&self) -> Result<Point> {
+        self.view.loc()
+    }
+
+    pub fn set_loc(&mut self, p: Point) -> Result<()> {
+        self.view.set_loc(p)
+    }
+
+    pub fn size(&self) -> Result<Size> {
+        self.view.size()
+    }
+
+    pub fn set_size(&mut self, s: Size) -> Result<()> {
+        let old_length = self.length()?;
+        self.view.set_size(s)?;
+        let length = self.length()?;
+        // Keep the proportions of the panes.
+        if old_length > 0.0 {
+            scale_pos(&mut self.pos, old_length, length);
+        } else {
+            self.reset_pos()?;
+        }
+        self.relayout()
+    }
+
+    pub fn orient(&self) -> Result<Orient> {
+        Ok(self.orient)
+    }
+
+    pub fn set_orient(&mut self, v: Orient) -> Result<()> {
+        self.orient = v;
+        self.reset_pos()?;
+        self.relayout()
+    }
+
+    pub fn len(&self) -> Result<usize> {
+        Ok(self.panes.len())
+    }
+
+    pub fn is_empty(&self) -> Result<bool> {
+        Ok(self.len()? == 0)
+    }
+
+    pub fn insert(&mut self, i: usize) -> Result<()> {
+        // Check before creating the widgets.
+        assert!(i <= self.panes.len(), "pane index {i} out of range");
+        let view = sys::View::new(&self.view)?;
+        if !self.panes.is_empty() {
+            let mut divider = sys::Canvas::new(&self.view)?;
+            divider.set_enabled(self.enabled)?;
+            self.dividers.push(divider);
+        }
+        self.panes.insert(
+            i,
+            Pane {
+                view,
+                min_size: Size::zero(),
+            },
+        );
+        self.drag = None;
+        self.reset_pos()?;
+        self.relayout()
+    }
+
+    pub fn remove(&mut self, i: usize) -> Result<()> {
+        assert!(i < self.panes.len(), "pane index {i} out of range");
+        self.panes.remove(i);
+        self.dividers.pop();
+        self.drag = None;
+        self.reset_pos()?;
+        self.relayout()
+    }
+
+    pub fn pane(&self, i: usize) -> BorrowedContainer<'_> {
+        self.panes[i].view.as_container()
+    }
+
+    pub fn pane_size(&self, i: usize) -> Result<Size> {
+        self.panes[i].view.size()
+    }
+
+    pub fn pane_min_size(&self, i: usize) -> Result<Size> {
+        Ok(self.panes[i].min_size)
+    }
+
+    pub fn set_pane_min_size(&mut self, i: usize, s: Size) -> Result<()> {
+        self.panes[i].min_size = s;
+        self.relayout()
+    }
+
+    pub fn pos(&self) -> Result<Vec<f64>> {
+        Ok(self.pos.clone())
+    }
+
+    pub fn set_pos(&mut self, pos: &[f64]) -> Result<()> {
+        // Extra positions are ignored, and the missing ones are kept.
+        let len = pos.len().min(self.pos.len());
+        self.pos[..len].copy_from_slice(&pos[..len]);
+        self.relayout()
+    }
+
+    /// Wait for the dividers being dragged. The dragging state is kept in the
+    /// splitter, so that the future could be cancelled between the moves.
+    ///
+    /// The dividers are also repainted here when the system asks for it.
+    pub async fn wait_change(&mut self) {
+        loop {
+            let wake = match self.drag {
+                None => {
+                    if self.dividers.is_empty() {
+                        std::future::pending::<()>().await;
+                    }
+                    let down = select_all(
+                        self.dividers
+                            .iter()
+                            .map(|divider| Box::pin(divider.wait_mouse_down())),
+                    );
+                    match select(down, pin!(self.wait_paint())).await {
+                        Either::Left(((button, i, _), _)) => Wake::Down(button, i),
+                        Either::Right((i, _)) => Wake::Paint(i),
+                    }
+                }
+                Some(i) => {
+                    let divider = &self.dividers[i];
+                    let mv = pin!(divider.wait_mouse_move());
+                    let up = pin!(divider.wait_mouse_up());
+                    let drag = select(mv, up);
+                    match select(drag, pin!(self.wait_paint())).await {
+                        Either::Left((Either::Left((p, _)), _)) => Wake::Move(i, p),
+                        Either::Left((Either::Right(_), _)) => Wake::Up,
+                        Either::Right((i, _)) => Wake::Paint(i),
+                    }
+                }
+            };
+            match wake {
+                Wake::Down(button, i) => {
+                    if button == MouseButton::Left && self.enabled {
+                        self.drag = Some(i);
+                    }
+                }
+                Wake::Move(i, p) => {
+                    let offset = match self.orient {
+                        Orient::Horizontal => p.x,
+                        Orient::Vertical => p.y,
+                    };
+                    self.pos[i] += offset - HANDLE / 2.0;
+                    if let Err(_e) = self.relayout() {
+                        error!("Failed to layout the splitter: {_e:?}");
+                    }
+                    break;
+                }
+                Wake::Up => self.drag = None,
+                Wake::Paint(i) => {
+                    if let Err(_e) = self.paint_divider(i) {
+                        error!("Failed to paint the splitter divider: {_e:?}");
+                    }
+                }
+            }
+        }
+    }
+
+    /// Wait for any divider to be repainted, and return its index.
+    async fn wait_paint(&self) -> usize {
+        if self.dividers.is_empty() {
+            std::future::pending::<()>().await;
+        }
+        select_all(
+            self.dividers
+                .iter()
+                .map(|divider| Box::pin(divider.wait_paint())),
+        )
+        .await
+        .1
+    }
+
+    /// The length of the splitter along the orientation.
+    fn length(&self) -> Result<f64> {
+        Ok(along(self.orient, self.view.size()?))
+    }
+
+    /// Split the space evenly.
+    fn reset_pos(&mut self) -> Result<()> {
+        self.pos = even_pos(self.panes.len(), self.length()?, HANDLE);
+        Ok(())
+    }
+
+    fn paint_divider(&mut self, i: usize) -> Result<()> {
+        let divider = &mut self.dividers[i];
+        let size = divider.size()?;
+        let mut ctx = DrawingContext::new(divider.context(None)?);
+        ctx.fill_rect(
+            SolidColorBrush::new(Color::new(128, 128, 128, 96)),
+            Rect::new(Point::zero(), size),
+        )
+    }
+
+    fn relayout(&mut self) -> Result<()> {
+        let size = self.view.size()?;
+        let length = along(self.orient, size);
+        let mins = self
+            .panes
+            .iter()
+            .map(|pane| along(self.orient, pane.min_size))
+            .collect::<Vec<_>>();
+        clamp_pos(&mut self.pos, &mins, length, HANDLE);
+        let pane_rects = pane_spans(&self.pos, length, HANDLE)
+            .into_iter()
+            .map(|(start, end)| slot(self.orient, start, end, size))
+            .collect::<Vec<_>>();
+        let divider_rects = self
+            .pos
+            .iter()
+            .map(|p| slot(self.orient, *p, p + HANDLE, size))
+            .collect::<Vec<_>>();
+        for (pane, rect) in self.panes.iter_mut().zip(pane_rects) {
+            pane.view.set_loc(rect.origin)?;
+            pane.view.set_size(rect.size)?;
+        }
+        for (divider, rect) in self.dividers.iter_mut().zip(divider_rects) {
+            divider.set_loc(rect.origin)?;
+            divider.set_size(rect.size)?;
+        }
+        for i in 0..self.dividers.len() {
+            self.paint_divider(i)?;
+        }
+        Ok(())
+    }
+}
+
+/// What wakes up [`Splitter::wait_change`].
+enum Wake {
+    Down(MouseButton, usize),
+    Move(usize, Point),
+    Up,
+    Paint(usize),
+}
+
+winio_handle::impl_as_widget!(Splitter, view);
@@ -0,0 +1,153 @@
+//! The geometry of the fallback splitter. All values are measured along the
+//! orientation, from the start of the splitter.
+
+use winio_primitive::{Orient, Point, Rect, Size};
+
+/// The length of a size along the orientation.
+pub fn along(orient: Orient, size: Size) -> f64 {
+    match orient {
+        Orient::Horizontal => size.width,
+        Orient::Vertical => size.height,
+    }
+}
+
+/// Split the length evenly into `count` panes.
+pub fn even_pos(count: usize, length: f64, handle: f64) -> Vec<f64> {
+    let dividers = count.saturating_sub(1);
+    let pane = ((length - handle * dividers as f64) / count as f64).max(0.0);
+    (0..dividers)
+        .map(|k| (k + 1) as f64 * pane + k as f64 * handle)
+        .collect()
+}
+
+/// Scale the positions to the new length, keeping the proportions of the
+/// panes.
+pub fn scale_pos(pos: &mut [f64], old_length: f64, length: f64) {
+    for p in pos {
+        *p *= length / old_length;
+    }
+}
+
+/// Keep the dividers inside the length and the panes not smaller than their
+/// min lengths. `mins` has one more item than `pos`.
+///
+/// If the length is not enough for all the min lengths, the panes at the end
+/// are shrunk first.
+pub fn clamp_pos(pos: &mut [f64], mins: &[f64], length: f64, handle: f64) {
+    debug_assert_eq!(pos.len() + 1, mins.len());
+    for k in 0..pos.len() {
+        let start = if k == 0 { 0.0 } else { pos[k - 1] + handle };
+        pos[k] = pos[k].max(start + mins[k]);
+    }
+    for k in (0..pos.len()).rev() {
+        let end = pos.get(k + 1).copied().unwrap_or(length);
+        pos[k] = pos[k].min(end - handle - mins[k + 1]).max(0.0);
+    }
+}
+
+/// The start and end of each pane.
+pub fn pane_spans(pos: &[f64], length: f64, handle: f64) -> Vec<(f64, f64)> {
+    (0..=pos.len())
+        .map(|k| {
+            let start = if k == 0 { 0.0 } else { pos[k - 1] + handle };
+            let end = pos.get(k).copied().unwrap_or(length);
+            (start, end)
+        })
+        .collect()
+}
+
+/// The rect between `start` and `end`, filling the cross axis of `size`.
+pub fn slot(orient: Orient, start: f64, end: f64, size: Size) -> Rect {
+    let length = (end - start).max(0.0);
+    match orient {
+        Orient::Horizontal => Rect::new(Point::new(start, 0.0), Size::new(length, size.height)),
+        Orient::Vertical => Rect::new(Point::new(0.0, start), Size::new(size.width, length)),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const HANDLE: f64 = 6.0;
+
+    #[test]
+    fn even() {
+        assert_eq!(even_pos(0, 100.0, HANDLE), Vec::<f64>::new());
+        assert_eq!(even_pos(1, 100.0, HANDLE), Vec::<f64>::new());
+        assert_eq!(even_pos(2, 106.0, HANDLE), vec![50.0]);
+        assert_eq!(even_pos(3, 312.0, HANDLE), vec![100.0, 206.0]);
+        // Not enough space for the dividers.
+        assert_eq!(even_pos(3, 6.0, HANDLE), vec![0.0, 6.0]);
+    }
+
+    #[test]
+    fn scale() {
+        let mut pos = vec![50.0, 150.0];
+        scale_pos(&mut pos, 200.0, 400.0);
+        assert_eq!(pos, vec![100.0, 300.0]);
+        scale_pos(&mut pos, 400.0, 100.0);
+        assert_eq!(pos, vec![25.0, 75.0]);
+    }
+
+    #[test]
+    fn clamp_inside() {
+        let mut pos = vec![-10.0, 200.0];
+        clamp_pos(&mut pos, &[0.0; 3], 100.0, HANDLE);
+        assert_eq!(pos, vec![0.0, 94.0]);
+
+        // Dividers can't cross each other.
+        let mut pos = vec![60.0, 40.0];
+        clamp_pos(&mut pos, &[0.0; 3], 100.0, HANDLE);
+        assert_eq!(pos, vec![60.0, 66.0]);
+    }
+
+    #[test]
+    fn clamp_min() {
+        let mut pos = vec![10.0];
+        clamp_pos(&mut pos, &[30.0, 0.0], 100.0, HANDLE);
+        assert_eq!(pos, vec![30.0]);
+
+        let mut pos = vec![90.0];
+        clamp_pos(&mut pos, &[0.0, 30.0], 100.0, HANDLE);
+        assert_eq!(pos, vec![64.0]);
+
+        // The middle pane pushes the second divider.
+        let mut pos = vec![40.0, 50.0];
+        clamp_pos(&mut pos, &[0.0, 20.0, 0.0], 200.0, HANDLE);
+        assert_eq!(pos, vec![40.0, 66.0]);
+
+        // Not enough space: the panes at the end are shrunk.
+        let mut pos = vec![50.0];
+        clamp_pos(&mut pos, &[60.0, 60.0], 100.0, HANDLE);
+        assert_eq!(pos, vec![34.0]);
+    }
+
+    #[test]
+    fn resize_then_clamp() {
+        let mut pos = vec![100.0];
+        scale_pos(&mut pos, 200.0, 100.0);
+        assert_eq!(pos, vec![50.0]);
+        clamp_pos(&mut pos, &[70.0, 0.0], 100.0, HANDLE);
+        assert_eq!(pos, vec![70.0]);
+    }
+
+    #[test]
+    fn spans() {
+        assert_eq!(along(Orient::Horizontal, Size::new(100.0, 20.0)), 100.0);
+        assert_eq!(along(Orient::Vertical, Size::new(100.0, 20.0)), 20.0);
+        assert_eq!(pane_spans(&[], 100.0, HANDLE), vec![(0.0, 100.0)]);
+        assert_eq!(
+            pane_spans(&[30.0, 60.0], 100.0, HANDLE),
+            vec![(0.0, 30.0), (36.0, 60.0), (66.0, 100.0)]
+        );
+        assert_eq!(
+            slot(Orient::Horizontal, 36.0, 60.0, Size::new(100.0, 20.0)),
+            Rect::new(Point::new(36.0, 0.0), Size::new(24.0, 20.0))
+        );
+        assert_eq!(
+            slot(Orient::Vertical, 60.0, 36.0, Size::new(20.0, 100.0)),
+            Rect::new(Point::new(0.0, 60.0), Size::new(20.0, 0.0))
+        );
+    }
+}
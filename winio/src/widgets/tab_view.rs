@@ -78,6 +78,8 @@ impl Enable for TabView {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for TabView {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
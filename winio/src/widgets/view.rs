@@ -1,7 +1,7 @@
 use inherit_methods_macro::inherit_methods;
 use winio_elm::{Component, ComponentSender};
 use winio_handle::BorrowedContainer;
-use winio_primitive::{Failable, Layoutable, Point, Rect, Size, Visible};
+use winio_primitive::{Cursor, Failable, Layoutable, Point, Rect, Size, Visible};
 
 use crate::{
     sys,
@@ -18,6 +18,16 @@ impl Failable for View {
     type Error = Error;
 }
 
+#[inherit_methods(from = "self.widget")]
+impl View {
+    /// Set the cursor shape when the pointer is over the view.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Supported.
+    /// * Others: Not supported.
+    pub fn set_cursor(&mut self, cursor: Cursor) -> Result<()>;
+}
+
 #[inherit_methods(from = "self.widget")]
 impl Visible for View {
     fn is_visible(&self) -> Result<bool>;
@@ -27,6 +37,8 @@ impl Visible for View {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for View {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -122,6 +122,8 @@ impl Enable for WebView {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for WebView {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -3,7 +3,8 @@ use wgpu::{CreateSurfaceError, Instance, Surface};
 use winio_elm::{Component, ComponentSender};
 use winio_handle::BorrowedContainer;
 use winio_primitive::{
-    Enable, Failable, Layoutable, MouseButton, Point, Rect, Size, ToolTip, Vector, Visible,
+    Cursor, Enable, Failable, Layoutable, MouseButton, MouseButtonEvent, Point, Rect, Size,
+    ToolTip, Vector, Visible,
 };
 
 use crate::{
@@ -38,6 +39,13 @@ impl WgpuCanvas {
         &self,
         instance: &Instance,
     ) -> std::result::Result<Surface<'static>, CreateSurfaceError>;
+
+    /// Set the cursor shape when the pointer is over the canvas.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Supported.
+    /// * Others: Not supported.
+    pub fn set_cursor(&mut self, cursor: Cursor) -> Result<()>;
 }
 
 impl Failable for WgpuCanvas {
@@ -67,6 +75,8 @@ impl Enable for WgpuCanvas {
 
 #[inherit_methods(from = "self.widget")]
 impl Layoutable for WgpuCanvas {
+    winio_primitive::impl_layout_visible!();
+
     fn loc(&self) -> Result<Point>;
 
     fn set_loc(&mut self, p: Point) -> Result<()>;
@@ -82,14 +92,49 @@ impl Layoutable for WgpuCanvas {
 pub enum WgpuCanvasEvent {
     /// The mouse moves.
     MouseMove(Point),
-    /// The mouse button pressed down.
+    /// The mouse button pressed down. Prefer [`Self::MouseButtonDown`] where
+    /// it is emitted, which also carries the position, the modifiers and the
+    /// click count.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Emitted together with [`Self::MouseButtonDown`].
     MouseDown(MouseButton),
-    /// The mouse button released.
+    /// The mouse button released. Prefer [`Self::MouseButtonUp`] where it is
+    /// emitted.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Emitted together with [`Self::MouseButtonUp`].
     MouseUp(MouseButton),
     /// The mouse wheel rotated.
     /// * `x`: Positive is right.
     /// * `y`: Positive is up/forward.
     MouseWheel(Vector),
+    /// The mouse button pressed down, with the position, the keyboard
+    /// modifiers and the click count. A double click reports `clicks == 2`.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Supported.
+    /// * Others: Never emitted.
+    MouseButtonDown(MouseButtonEvent),
+    /// The mouse button released, with the same details as
+    /// [`Self::MouseButtonDown`].
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Supported.
+    /// * Others: Never emitted.
+    MouseButtonUp(MouseButtonEvent),
+    /// The pointer enters the widget at the position.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Supported.
+    /// * Others: Never emitted.
+    MouseEnter(Point),
+    /// The pointer leaves the widget.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Supported.
+    /// * Others: Never emitted.
+    MouseLeave,
 }
 
 /// Messages of [`WgpuCanvas`].
@@ -142,9 +187,45 @@ impl Component for WgpuCanvas {
                 sender.output(WgpuCanvasEvent::MouseWheel(w));
             }
         };
-        futures_util::future::join4(fut_move, fut_down, fut_up, fut_wheel)
-            .await
-            .0
+        #[cfg(any(gtk, qt))]
+        let fut_ext = async {
+            let fut_button_down = async {
+                loop {
+                    let e = self.widget.wait_mouse_button_down().await;
+                    sender.output(WgpuCanvasEvent::MouseButtonDown(e));
+                }
+            };
+            let fut_button_up = async {
+                loop {
+                    let e = self.widget.wait_mouse_button_up().await;
+                    sender.output(WgpuCanvasEvent::MouseButtonUp(e));
+                }
+            };
+            let fut_enter = async {
+                loop {
+                    let p = self.widget.wait_mouse_enter().await;
+                    sender.output(WgpuCanvasEvent::MouseEnter(p));
+                }
+            };
+            let fut_leave = async {
+                loop {
+                    self.widget.wait_mouse_leave().await;
+                    sender.output(WgpuCanvasEvent::MouseLeave);
+                }
+            };
+            futures_util::future::join4(fut_button_down, fut_button_up, fut_enter, fut_leave)
+                .await
+                .0
+        };
+        #[cfg(not(any(gtk, qt)))]
+        let fut_ext = std::future::pending::<()>();
+        futures_util::future::join(
+            futures_util::future::join4(fut_move, fut_down, fut_up, fut_wheel),
+            fut_ext,
+        )
+        .await
+        .0
+        .0
     }
 
     async fn update(
@@ -1,7 +1,7 @@
 use inherit_methods_macro::inherit_methods;
 use winio_elm::{Component, ComponentSender};
 use winio_layout::Layoutable;
-use winio_primitive::{Failable, Point, Rect, Size, TextWidget, Visible};
+use winio_primitive::{Cursor, Failable, Point, Rect, Size, TextWidget, Visible};
 
 #[cfg(windows)]
 pub use crate::sys::Backdrop;
@@ -84,6 +84,13 @@ impl Window {
     /// * macOS: Supported on macOS 10.10 and later.
     #[cfg(target_os = "macos")]
     pub fn set_vibrancy(&mut self, v: Option<Vibrancy>) -> Result<()>;
+
+    /// Set the cursor shape when the pointer is over the window.
+    ///
+    /// ## Platform specific
+    /// * GTK & Qt: Supported.
+    /// * Others: Not supported.
+    pub fn set_cursor(&mut self, cursor: Cursor) -> Result<()>;
 }
 
 #[inherit_methods(from = "self.widget")]